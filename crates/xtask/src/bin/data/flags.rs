@@ -0,0 +1,98 @@
+//! Declarative descriptions of flag types, from which `xtask gen` generates `src/um/{header}/flags/*.rs` and `hwnd.natvis` entries.
+//!
+//! Values are written out as literals (per the Windows SDK headers) so generation doesn't require winapi, and runs on any host.
+//! The generated modules assert said literals match winapi's constants at compile time.
+
+/// A flag type, generated as `src/um/{header}/flags/{module}.rs`
+pub struct Flags {
+    /// e.g. `"winuser"` - the `um/*.h` header (and winapi module) defining the flags
+    pub header:     &'static str,
+    /// e.g. `"SWP"` - also the C prefix (`SWP_`)
+    pub module:     &'static str,
+    /// e.g. `"SetWindowPosFlags"`
+    pub ty:         &'static str,
+    /// learn.microsoft.com documentation
+    pub url:        &'static str,
+    /// e.g. `"SWP_\\* flags for [set_window_pos]"`
+    pub summary:    &'static str,
+    /// In [`Debug`](std::fmt::Debug) order: aggregates (e.g. `WS::OVERLAPPEDWINDOW`) should precede their parts.
+    pub values:     &'static [Flag],
+    /// Documented-invalid combinations, checked by the generated `validate()`
+    pub invalid:    &'static [Invalid],
+}
+
+pub struct Flag {
+    /// e.g. `"NOSIZE"` for `SWP_NOSIZE`
+    pub name:       &'static str,
+    pub value:      u32,
+    pub debug:      Debug,
+    pub docs:       &'static [&'static str],
+}
+
+/// How a [Flag] participates in [`Debug`](std::fmt::Debug) formatting
+#[derive(Clone, Copy, PartialEq, Eq)] pub enum Debug {
+    /// Formatted by name
+    Show,
+    /// Same value and meaning as another flag, which is formatted instead
+    Alias(&'static str),
+    /// Same bits as another flag, but a different meaning (e.g. `WS::GROUP` vs `WS::MINIMIZEBOX`), which is formatted instead
+    Overlaps(&'static str),
+    /// `0`: the default, implied by the absence of other flags
+    Zero,
+}
+
+/// A combination of flags which are invalid when all set, e.g. `WS::CHILD | WS::POPUP`
+pub struct Invalid {
+    pub all:        &'static [&'static str],
+    pub reason:     &'static str,
+}
+
+pub const fn invalid(all: &'static [&'static str], reason: &'static str) -> Invalid { Invalid { all, reason } }
+
+pub const fn flag(name: &'static str, value: u32) -> Flag { Flag { name, value, debug: Debug::Show, docs: &[] } }
+
+impl Flag {
+    pub const fn alias(self, of: &'static str) -> Self { Self { debug: Debug::Alias(of), ..self } }
+    pub const fn overlaps(self, with: &'static str) -> Self { Self { debug: Debug::Overlaps(with), ..self } }
+    pub const fn zero(self) -> Self { Self { debug: Debug::Zero, ..self } }
+    pub const fn docs(self, docs: &'static [&'static str]) -> Self { Self { docs, ..self } }
+}
+
+impl Flags {
+    /// `(cpp, rust, value)`, e.g. `("SWP_NOSIZE", "NOSIZE", 0x0001)`
+    pub fn cpp_rust_values(&self) -> impl Iterator<Item = (String, &'static str, u32)> + '_ {
+        self.values.iter().map(|f| (format!("{}_{}", self.module, f.name), f.name, f.value))
+    }
+
+    /// Panic if annotations are missing or inconsistent with values
+    pub fn validate(&self) {
+        let m = self.module;
+        let find = |name: &str| self.values.iter().find(|f| f.name == name).unwrap_or_else(|| panic!("{m}::{name}: referenced, but not defined"));
+        for (i, f) in self.values.iter().enumerate() {
+            let n = f.name;
+            assert!(self.values[..i].iter().all(|prev| prev.name != n), "{m}::{n}: defined multiple times");
+            match f.debug {
+                Debug::Show => {
+                    if let Some(dupe) = self.values[..i].iter().find(|prev| prev.debug == Debug::Show && prev.value == f.value) {
+                        panic!("{m}::{n}: same value as {m}::{}: annotate with .alias(..) or .overlaps(..)", dupe.name);
+                    }
+                },
+                Debug::Alias(of) => {
+                    let of = find(of);
+                    assert!(of.debug == Debug::Show, "{m}::{n}: alias of {m}::{}, which isn't shown by Debug", of.name);
+                    assert!(of.value == f.value, "{m}::{n}: alias of {m}::{}, but their values differ", of.name);
+                },
+                Debug::Overlaps(with) => {
+                    let with = find(with);
+                    assert!(with.debug == Debug::Show, "{m}::{n}: overlaps {m}::{}, which isn't shown by Debug", with.name);
+                    assert!(with.value & f.value != 0, "{m}::{n}: annotated as overlapping {m}::{}, but they share no bits", with.name);
+                },
+                Debug::Zero => assert!(f.value == 0, "{m}::{n}: annotated as zero, but is 0x{:08X}", f.value),
+            }
+        }
+        for i in self.invalid.iter() {
+            assert!(i.all.len() >= 2, "{m}: invalid combination {:?} should list at least two flags", i.all);
+            for n in i.all.iter() { assert!(find(n).value != 0, "{m}::{n}: 0 can't be part of an invalid combination"); }
+        }
+    }
+}
@@ -1,16 +1,20 @@
-macro_rules! flags { ( $($cpp:path),* $(,)? ) => {
-    pub fn cpp_rust_values() -> impl Iterator<Item = (&'static str, &'static str, u32)> {
-        use winapi::um::winuser::*;
-        vec![$(
-            (stringify!($cpp), stringify!($cpp).strip_prefix("ISMEX_").unwrap(), $cpp),
-        )*].into_iter()
-    }
-}}
+use super::flags::*;
 
-flags! {
-    ISMEX_NOSEND,
-    ISMEX_CALLBACK,
-    ISMEX_NOTIFY,
-    ISMEX_REPLIED,
-    ISMEX_SEND,
-}
+pub const FLAGS : Flags = Flags {
+    header:     "winuser",
+    module:     "ISMEX",
+    ty:         "InSendMessageExFlags",
+    url:        "https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-insendmessageex",
+    summary:    "InSendMessageEx return value flags",
+    values:     &[
+        flag("NOSEND",      0x00000000),
+        flag("CALLBACK",    0x00000004).docs(&["The message was sent using the [send_message_callback](crate::send_message_callback_w) function. The thread that sent the message is not blocked."]),
+        flag("NOTIFY",      0x00000002).docs(&["The message was sent using the [send_notify_message](crate::send_notify_message_w) function. The thread that sent the message is not blocked."]),
+        flag("REPLIED",     0x00000008).docs(&["The window procedure has processed the message. The thread that sent the message is no longer blocked."]),
+        flag("SEND",        0x00000001).docs(&[
+            "The message was sent using the [send_message](crate::send_message_w) or [send_message_timeout](crate::send_message_timeout_w) function.",
+            "If [ISMEX::REPLIED] is not set, the thread that sent the message is blocked.",
+        ]),
+    ],
+    invalid:    &[],
+};
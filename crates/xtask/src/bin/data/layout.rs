@@ -0,0 +1,132 @@
+//! Expected `#[repr(C)]` layouts of hwnd structures, per Windows architecture.
+//!
+//! The `expected` tables are written out by hand (per the Windows SDK headers), and double checked against C layout rules by `xtask gen`.
+//! No winapi here: these tables are what `src/_layout.rs` checks the crate against, for every architecture, from any host.
+
+#[derive(Clone, Copy)] pub enum Ty {
+    U32,
+    I32,
+    /// Pointers, handles, `WPARAM`, `LPARAM`, function pointers, etc.
+    Ptr,
+    Struct(&'static [(&'static str, Ty)]),
+}
+
+/// (rust `target_arch`, display name, pointer size)
+pub const ARCHES : &[(&str, &str, usize)] = &[
+    ("x86",     "i686",     4),
+    ("x86_64",  "x86_64",   8),
+    ("aarch64", "aarch64",  8),
+];
+
+pub struct Struct {
+    pub rust:       &'static str,
+    pub fields:     &'static [(&'static str, Ty)],
+    /// (display arch name, size, align, field offsets)
+    pub expected:   &'static [(&'static str, usize, usize, &'static [usize])],
+}
+
+const POINT : &[(&str, Ty)] = &[("x", Ty::I32), ("y", Ty::I32)];
+const RECT  : &[(&str, Ty)] = &[("left", Ty::I32), ("top", Ty::I32), ("right", Ty::I32), ("bottom", Ty::I32)];
+
+const WNDCLASS : &[(&str, Ty)] = &[
+    ("style",       Ty::U32),
+    ("wnd_proc",    Ty::Ptr),
+    ("cls_extra",   Ty::I32),
+    ("wnd_extra",   Ty::I32),
+    ("hinstance",   Ty::Ptr),
+    ("hicon",       Ty::Ptr),
+    ("hcursor",     Ty::Ptr),
+    ("background",  Ty::Ptr),
+    ("menu_name",   Ty::Ptr),
+    ("class_name",  Ty::Ptr),
+];
+const WNDCLASS_OFFSETS_32 : &[usize] = &[0, 4, 8, 12, 16, 20, 24, 28, 32, 36];
+const WNDCLASS_OFFSETS_64 : &[usize] = &[0, 8, 16, 20, 24, 32, 40, 48, 56, 64];
+
+const WNDCLASSEX : &[(&str, Ty)] = &[
+    ("size",        Ty::U32),
+    ("style",       Ty::U32),
+    ("wnd_proc",    Ty::Ptr),
+    ("cls_extra",   Ty::I32),
+    ("wnd_extra",   Ty::I32),
+    ("hinstance",   Ty::Ptr),
+    ("hicon",       Ty::Ptr),
+    ("hcursor",     Ty::Ptr),
+    ("background",  Ty::Ptr),
+    ("menu_name",   Ty::Ptr),
+    ("class_name",  Ty::Ptr),
+    ("hicon_sm",    Ty::Ptr),
+];
+const WNDCLASSEX_OFFSETS_32 : &[usize] = &[0, 4, 8, 12, 16, 20, 24, 28, 32, 36, 40, 44];
+const WNDCLASSEX_OFFSETS_64 : &[usize] = &[0, 4, 8, 16, 20, 24, 32, 40, 48, 56, 64, 72];
+
+pub const STRUCTS : &[Struct] = &[
+    Struct {
+        rust:       "Point",
+        fields:     POINT,
+        expected:   &[("i686", 8, 4, &[0, 4]), ("x86_64", 8, 4, &[0, 4]), ("aarch64", 8, 4, &[0, 4])],
+    },
+    Struct {
+        rust:       "Rect",
+        fields:     RECT,
+        expected:   &[("i686", 16, 4, &[0, 4, 8, 12]), ("x86_64", 16, 4, &[0, 4, 8, 12]), ("aarch64", 16, 4, &[0, 4, 8, 12])],
+    },
+    Struct {
+        rust:       "Msg",
+        fields:     &[("hwnd", Ty::Ptr), ("message", Ty::U32), ("wparam", Ty::Ptr), ("lparam", Ty::Ptr), ("time", Ty::U32), ("pt", Ty::Struct(POINT))],
+        expected:   &[
+            ("i686",    28, 4, &[0, 4,  8, 12, 16, 20]),
+            ("x86_64",  48, 8, &[0, 8, 16, 24, 32, 36]), // 32 bits of padding after `message`, and after `pt`
+            ("aarch64", 48, 8, &[0, 8, 16, 24, 32, 36]),
+        ],
+    },
+    Struct {
+        rust:       "WindowPlacement",
+        fields:     &[("length", Ty::U32), ("flags", Ty::U32), ("show_cmd", Ty::I32), ("min_position", Ty::Struct(POINT)), ("max_position", Ty::Struct(POINT)), ("normal_position", Ty::Struct(RECT))],
+        expected:   &[
+            ("i686",    44, 4, &[0, 4, 8, 12, 20, 28]),
+            ("x86_64",  44, 4, &[0, 4, 8, 12, 20, 28]),
+            ("aarch64", 44, 4, &[0, 4, 8, 12, 20, 28]),
+        ],
+    },
+    Struct {
+        rust:       "WndClassA<'static>",
+        fields:     WNDCLASS,
+        expected:   &[("i686", 40, 4, WNDCLASS_OFFSETS_32), ("x86_64", 72, 8, WNDCLASS_OFFSETS_64), ("aarch64", 72, 8, WNDCLASS_OFFSETS_64)],
+    },
+    Struct {
+        rust:       "WndClassW<'static>",
+        fields:     WNDCLASS,
+        expected:   &[("i686", 40, 4, WNDCLASS_OFFSETS_32), ("x86_64", 72, 8, WNDCLASS_OFFSETS_64), ("aarch64", 72, 8, WNDCLASS_OFFSETS_64)],
+    },
+    Struct {
+        rust:       "WndClassExA<'static>",
+        fields:     WNDCLASSEX,
+        expected:   &[("i686", 48, 4, WNDCLASSEX_OFFSETS_32), ("x86_64", 80, 8, WNDCLASSEX_OFFSETS_64), ("aarch64", 80, 8, WNDCLASSEX_OFFSETS_64)],
+    },
+    Struct {
+        rust:       "WndClassExW<'static>",
+        fields:     WNDCLASSEX,
+        expected:   &[("i686", 48, 4, WNDCLASSEX_OFFSETS_32), ("x86_64", 80, 8, WNDCLASSEX_OFFSETS_64), ("aarch64", 80, 8, WNDCLASSEX_OFFSETS_64)],
+    },
+];
+
+/// Compute the C layout of `fields` given `ptr_size`: (size, align, offsets)
+pub fn c_layout(fields: &[(&str, Ty)], ptr_size: usize) -> (usize, usize, Vec<usize>) {
+    let mut offset = 0_usize;
+    let mut align = 1;
+    let mut offsets = Vec::new();
+    for (_, ty) in fields {
+        let (f_size, f_align) = match ty {
+            Ty::U32 | Ty::I32   => (4, 4),
+            Ty::Ptr             => (ptr_size, ptr_size),
+            Ty::Struct(fields)  => { let (s, a, _) = c_layout(fields, ptr_size); (s, a) },
+        };
+        offset = offset.next_multiple_of(f_align);
+        offsets.push(offset);
+        offset += f_size;
+        align = align.max(f_align);
+    }
+    let size = offset.next_multiple_of(align);
+    (size, align, offsets)
+}
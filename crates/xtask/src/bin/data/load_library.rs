@@ -0,0 +1,24 @@
+use super::flags::*;
+
+pub const FLAGS : Flags = Flags {
+    header:     "libloaderapi",
+    module:     "LOAD_LIBRARY",
+    ty:         "LoadLibraryFlags",
+    url:        "https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryexw",
+    summary:    "LOAD_LIBRARY_\\* flags for [load_library_ex_w]",
+    values:     &[
+        flag("AS_DATAFILE",                  0x00000002).docs(&["Map the module as a data file: resources are accessible, but code can't be executed (nor [get_proc_address](crate::get_proc_address)ed.)"]),
+        flag("AS_IMAGE_RESOURCE",            0x00000020).docs(&["Map the module as an image file: resources are accessible (with relative virtual addresses fixed up), but code can't be executed."]),
+        flag("AS_DATAFILE_EXCLUSIVE",        0x00000040).docs(&["Like [AS_DATAFILE], but opens the file with exclusive write access."]),
+        flag("REQUIRE_SIGNED_TARGET",        0x00000080),
+        flag("SEARCH_DEFAULT_DIRS",          0x00001000).docs(&["Equivalent to `SEARCH_APPLICATION_DIR | SEARCH_SYSTEM32 | SEARCH_USER_DIRS`"]),
+        flag("SEARCH_DLL_LOAD_DIR",          0x00000100),
+        flag("SEARCH_APPLICATION_DIR",       0x00000200),
+        flag("SEARCH_USER_DIRS",             0x00000400),
+        flag("SEARCH_SYSTEM32",              0x00000800),
+        flag("SAFE_CURRENT_DIRS",            0x00002000),
+        flag("SEARCH_SYSTEM32_NO_FORWARDER", 0x00004000),
+        flag("OS_INTEGRITY_CONTINUITY",      0x00008000),
+    ],
+    invalid:    &[],
+};
@@ -0,0 +1,27 @@
+use super::flags::*;
+
+pub const FLAGS : Flags = Flags {
+    header:     "winuser",
+    module:     "LR",
+    ty:         "LoadImageFlags",
+    url:        "https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadimagew",
+    summary:    "LR_\\* flags for [load_image_icon_w], [create_icon_from_resource_ex], etc.",
+    values:     &[
+        flag("DEFAULTCOLOR",        0x0000).zero(),
+        flag("MONOCHROME",          0x0001),
+        flag("COLOR",               0x0002),
+        flag("COPYRETURNORG",       0x0004),
+        flag("COPYDELETEORG",       0x0008),
+        flag("LOADFROMFILE",        0x0010),
+        flag("LOADTRANSPARENT",     0x0020),
+        flag("DEFAULTSIZE",         0x0040),
+        flag("VGACOLOR",            0x0080),
+        flag("LOADMAP3DCOLORS",     0x1000),
+        flag("CREATEDIBSECTION",    0x2000),
+        flag("COPYFROMRESOURCE",    0x4000),
+        flag("SHARED",              0x8000),
+    ],
+    invalid:    &[
+        invalid(&["LOADFROMFILE", "SHARED"], "Do not use LR_SHARED for images that are loaded from a file"),
+    ],
+};
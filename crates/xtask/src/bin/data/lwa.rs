@@ -0,0 +1,14 @@
+use super::flags::*;
+
+pub const FLAGS : Flags = Flags {
+    header:     "winuser",
+    module:     "LWA",
+    ty:         "LayeredWindowAttributesFlags",
+    url:        "https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setlayeredwindowattributes",
+    summary:    "LWA_\\* flags for [set_layered_window_attributes]",
+    values:     &[
+        flag("COLORKEY",    0x00000001).docs(&["Pixels matching `key` are fully transparent (and click-through.)"]),
+        flag("ALPHA",       0x00000002).docs(&["The whole window is blended with opacity `alpha`."]),
+    ],
+    invalid:    &[],
+};
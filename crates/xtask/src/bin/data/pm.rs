@@ -1,18 +1,19 @@
-macro_rules! flags { ( $($cpp:path),* $(,)? ) => {
-    pub fn cpp_rust_values() -> impl Iterator<Item = (&'static str, &'static str, u32)> {
-        use winapi::um::winuser::*;
-        vec![$(
-            (stringify!($cpp), stringify!($cpp).strip_prefix("PM_").unwrap(), $cpp),
-        )*].into_iter()
-    }
-}}
+use super::flags::*;
 
-flags! {
-    PM_NOREMOVE,
-    PM_REMOVE,
-    PM_NOYIELD,
-    PM_QS_INPUT,
-    PM_QS_PAINT,
-    PM_QS_POSTMESSAGE,
-    PM_QS_SENDMESSAGE,
-}
+pub const FLAGS : Flags = Flags {
+    header:     "winuser",
+    module:     "PM",
+    ty:         "PeekMessageFlags",
+    url:        "https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-peekmessagew",
+    summary:    "PM_\\* flags for [peek_message](peek_message_w)",
+    values:     &[
+        flag("NOREMOVE",        0x00000000),
+        flag("REMOVE",          0x00000001),
+        flag("NOYIELD",         0x00000002),
+        flag("QS_INPUT",        0x1C070000), // QS_INPUT << 16
+        flag("QS_PAINT",        0x00200000), // QS_PAINT << 16
+        flag("QS_POSTMESSAGE",  0x00980000), // (QS_POSTMESSAGE | QS_HOTKEY | QS_TIMER) << 16
+        flag("QS_SENDMESSAGE",  0x00400000), // QS_SENDMESSAGE << 16
+    ],
+    invalid:    &[],
+};
@@ -0,0 +1,30 @@
+use super::flags::*;
+
+pub const FLAGS : Flags = Flags {
+    header:     "winuser",
+    module:     "RDW",
+    ty:         "RedrawWindowFlags",
+    url:        "https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-redrawwindow",
+    summary:    "RDW_\\* flags for [redraw_window]",
+    values:     &[
+        flag("INVALIDATE",      0x0001).docs(&["Invalidate the update region (or the whole window, if none is specified.)"]),
+        flag("INTERNALPAINT",   0x0002).docs(&["Post [WM::PAINT] even if the window isn't invalid."]),
+        flag("ERASE",           0x0004).docs(&["Send [WM::ERASEBKGND] when the window is repainted. Requires [INVALIDATE]."]),
+        flag("VALIDATE",        0x0008).docs(&["Validate the update region (or the whole window, if none is specified.)"]),
+        flag("NOINTERNALPAINT", 0x0010).docs(&["Suppress pending internal [WM::PAINT]s."]),
+        flag("NOERASE",         0x0020).docs(&["Suppress pending [WM::ERASEBKGND]s."]),
+        flag("NOCHILDREN",      0x0040).docs(&["Exclude child windows."]),
+        flag("ALLCHILDREN",     0x0080).docs(&["Include child windows, even those with [WS::CLIPCHILDREN] parents."]),
+        flag("UPDATENOW",       0x0100).docs(&["Send [WM::NCPAINT], [WM::ERASEBKGND], and [WM::PAINT] before returning, as needed."]),
+        flag("ERASENOW",        0x0200).docs(&["Send [WM::NCPAINT] and [WM::ERASEBKGND] before returning, as needed."]),
+        flag("FRAME",           0x0400).docs(&["Include the nonclient area (sending [WM::NCPAINT].)"]),
+        flag("NOFRAME",         0x0800).docs(&["Exclude the nonclient area."]),
+    ],
+    invalid:    &[
+        invalid(&["INVALIDATE", "VALIDATE"],            "RDW_INVALIDATE and RDW_VALIDATE contradict each other"),
+        invalid(&["INTERNALPAINT", "NOINTERNALPAINT"],  "RDW_INTERNALPAINT and RDW_NOINTERNALPAINT contradict each other"),
+        invalid(&["ERASE", "NOERASE"],                  "RDW_ERASE and RDW_NOERASE contradict each other"),
+        invalid(&["NOCHILDREN", "ALLCHILDREN"],         "RDW_NOCHILDREN and RDW_ALLCHILDREN contradict each other"),
+        invalid(&["FRAME", "NOFRAME"],                  "RDW_FRAME and RDW_NOFRAME contradict each other"),
+    ],
+};
@@ -1,16 +1,17 @@
-macro_rules! flags { ( $($cpp:path),* $(,)? ) => {
-    pub fn cpp_rust_values() -> impl Iterator<Item = (&'static str, &'static str, u32)> {
-        use winapi::um::winuser::*;
-        vec![$(
-            (stringify!($cpp), stringify!($cpp).strip_prefix("SMTO_").unwrap(), $cpp),
-        )*].into_iter()
-    }
-}}
+use super::flags::*;
 
-flags! {
-    SMTO_ABORTIFHUNG,
-    SMTO_BLOCK,
-    SMTO_NORMAL,
-    SMTO_NOTIMEOUTIFNOTHUNG,
-    SMTO_ERRORONEXIT,
-}
+pub const FLAGS : Flags = Flags {
+    header:     "winuser",
+    module:     "SMTO",
+    ty:         "SendMessageTimeOutFlags",
+    url:        "https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-sendmessagetimeoutw",
+    summary:    "SMTO_\\* window style flags for [send_message_timeout](send_message_timeout_w)",
+    values:     &[
+        flag("ABORTIFHUNG",         0x0002),
+        flag("BLOCK",               0x0001),
+        flag("NORMAL",              0x0000),
+        flag("NOTIMEOUTIFNOTHUNG",  0x0008),
+        flag("ERRORONEXIT",         0x0020),
+    ],
+    invalid:    &[],
+};
@@ -1,26 +1,29 @@
-macro_rules! flags { ( $($cpp:path),* $(,)? ) => {
-    pub fn cpp_rust_values() -> impl Iterator<Item = (&'static str, &'static str, u32)> {
-        use winapi::um::winuser::*;
-        vec![$(
-            (stringify!($cpp), stringify!($cpp).strip_prefix("SWP_").unwrap(), $cpp),
-        )*].into_iter()
-    }
-}}
+use super::flags::*;
 
-flags! {
-    SWP_NOSIZE,
-    SWP_NOMOVE,
-    SWP_NOZORDER,
-    SWP_NOREDRAW,
-    SWP_NOACTIVATE,
-    SWP_FRAMECHANGED,
-    SWP_SHOWWINDOW,
-    SWP_HIDEWINDOW,
-    SWP_NOCOPYBITS,
-    SWP_NOOWNERZORDER,
-    SWP_NOSENDCHANGING,
-    SWP_DRAWFRAME,
-    SWP_NOREPOSITION,
-    SWP_DEFERERASE,
-    SWP_ASYNCWINDOWPOS,
-}
+pub const FLAGS : Flags = Flags {
+    header:     "winuser",
+    module:     "SWP",
+    ty:         "SetWindowPosFlags",
+    url:        "https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowpos",
+    summary:    "SWP_\\* flags for [set_window_pos]",
+    values:     &[
+        flag("NOSIZE",          0x0001),
+        flag("NOMOVE",          0x0002),
+        flag("NOZORDER",        0x0004),
+        flag("NOREDRAW",        0x0008),
+        flag("NOACTIVATE",      0x0010),
+        flag("FRAMECHANGED",    0x0020),
+        flag("SHOWWINDOW",      0x0040),
+        flag("HIDEWINDOW",      0x0080),
+        flag("NOCOPYBITS",      0x0100),
+        flag("NOOWNERZORDER",   0x0200),
+        flag("NOSENDCHANGING",  0x0400),
+        flag("DRAWFRAME",       0x0020).alias("FRAMECHANGED"),
+        flag("NOREPOSITION",    0x0200).alias("NOOWNERZORDER"),
+        flag("DEFERERASE",      0x2000),
+        flag("ASYNCWINDOWPOS",  0x4000),
+    ],
+    invalid:    &[
+        invalid(&["SHOWWINDOW", "HIDEWINDOW"], "SWP_SHOWWINDOW and SWP_HIDEWINDOW contradict each other"),
+    ],
+};
@@ -1,310 +1,384 @@
+//! Declarative description of WM_\* messages, from which `xtask gen` generates `src/_messages.rs` and `hwnd.natvis` entries.
+//!
+//! Values are written out as literals (per the Windows SDK headers) so generation doesn't require winapi, and runs on any host.
+//! The generated `src/_messages.rs` asserts said literals match the constants of `src/um/winuser/enums/WM.rs` at compile time.
+//!
+//! Parameter and result metadata follow learn.microsoft.com's documentation of each message.
+//! Messages with pointer parameters must be annotated with whether Windows marshals them when sent to another process.
+
 use std::borrow::Cow;
 
-macro_rules! enums { ( $($cpp:path),* $(,)? ) => {
-    pub fn cpp_rust_values() -> impl Iterator<Item = (Cow<'static, str>, Cow<'static, str>, i32)> {
-        use winapi::um::winuser::*;
-        vec![$(
-            (stringify!($cpp).into(), stringify!($cpp).replace("WM_", "WM::").into(), $cpp as _),
-        )*].into_iter()
+pub struct Message {
+    /// e.g. `"CREATE"` for `WM::CREATE`
+    pub name:       &'static str,
+    /// C name, if not `WM_{name}` (e.g. `"MN_GETHMENU"`)
+    pub cpp:        Option<&'static str>,
+    pub value:      u32,
+    pub wparam:     Param,
+    pub lparam:     Param,
+    pub result:     Ret,
+    /// `Some(true)` if Windows marshals pointer parameters between processes.  Must be `Some(..)` iff the message has pointer parameters.
+    pub marshaled:  Option<bool>,
+}
+
+/// How a message treats `wparam` or `lparam`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)] pub enum Param {
+    /// Not used: should be `0`
+    Unused,
+    /// An integer, flags, handle, packed coordinates, etc.
+    Value,
+    /// A value the receiver may dereference, which Windows neither validates nor marshals (e.g. an `HGLOBAL`, a `TIMERPROC`, or app data that's usually a pointer)
+    Opaque,
+    /// A pointer to the named C type (`"TCHAR"` for strings), or `""` if the type varies
+    Ptr(&'static str),
+}
+
+/// What a window procedure should return for a message
+#[derive(Clone, Copy, PartialEq, Eq, Debug)] pub enum Ret {
+    /// Not meaningful
+    Ignored,
+    /// `0` if processed
+    Zero,
+    /// `TRUE` / `FALSE`
+    Bool,
+    /// A message-specific value (a length, handle, `HT*` code, etc.)
+    Value,
+}
+
+use Param::*;
+
+pub const fn ptr(ty: &'static str) -> Param { Ptr(ty) }
+
+pub const fn msg(name: &'static str, value: u32, wparam: Param, lparam: Param, result: Ret) -> Message {
+    Message { name, cpp: None, value, wparam, lparam, result, marshaled: None }
+}
+
+impl Message {
+    pub const fn cpp(self, cpp: &'static str) -> Self { Self { cpp: Some(cpp), ..self } }
+    /// Pointer parameters are marshaled by Windows when sent to another process
+    pub const fn marshaled(self) -> Self { Self { marshaled: Some(true), ..self } }
+    /// Pointer parameters are only meaningful within the sending process
+    pub const fn local(self) -> Self { Self { marshaled: Some(false), ..self } }
+
+    /// e.g. `"WM_CREATE"`
+    pub fn cpp_name(&self) -> Cow<'static, str> { self.cpp.map_or_else(|| format!("WM_{}", self.name).into(), Cow::Borrowed) }
+
+    /// `true` if either parameter is a pointer
+    pub fn has_pointers(&self) -> bool { matches!(self.wparam, Ptr(_)) || matches!(self.lparam, Ptr(_)) }
+
+    /// Asynchronous message functions ([PostMessage](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-postmessagew#remarks) etc.)
+    /// fail with `ERROR_MESSAGE_SYNC_ONLY` for system messages with pointer parameters.
+    pub fn send_only(&self) -> bool { self.has_pointers() && self.value < 0x0400 }
+}
+
+/// Panic if annotations are missing or inconsistent
+pub fn validate() {
+    for (i, m) in MESSAGES.iter().enumerate() {
+        let n = m.name;
+        assert!(MESSAGES[..i].iter().all(|prev| prev.name != n), "WM::{n}: defined multiple times");
+        assert!(MESSAGES[..i].iter().all(|prev| prev.cpp_name() != m.cpp_name()), "WM::{n}: {} defined multiple times", m.cpp_name());
+        assert!(m.value < 0x0400, "WM::{n}: only system messages (below WM_USER) belong here");
+        assert!(m.has_pointers() == m.marshaled.is_some(), "WM::{n}: messages with pointer parameters (only) must be annotated with .marshaled() or .local()");
+        if let Some(prev) = MESSAGES[..i].iter().find(|prev| prev.value == m.value) {
+            assert!((prev.wparam, prev.lparam, prev.result, prev.marshaled) == (m.wparam, m.lparam, m.result, m.marshaled), "WM::{n}: same value as WM::{}, but different metadata", prev.name);
+        }
     }
-}}
-
-// https://social.msdn.microsoft.com/Forums/windowsapps/en-US/f677f319-9f02-4438-92fb-6e776924425d/windowproc-and-messages-0x90-0x91-0x92-0x93?forum=windowsuidevelopment
-const WM_UAHDESTROYWINDOW       : u32 = 0x0090;
-const WM_UAHDRAWMENU            : u32 = 0x0091;
-const WM_UAHDRAWMENUITEM        : u32 = 0x0092;
-const WM_UAHINITMENU            : u32 = 0x0093;
-const WM_UAHMEASUREMENUITEM     : u32 = 0x0094;
-const WM_UAHNCPAINTMENUPOPUP    : u32 = 0x0095;
-
-enums! {
-    WM_NULL,
-    WM_CREATE,
-    WM_DESTROY,
-    WM_MOVE,
-    WM_SIZE,
-
-    WM_ACTIVATE,
-
-    WM_SETFOCUS,
-    WM_KILLFOCUS,
-    WM_ENABLE,
-    WM_SETREDRAW,
-    WM_SETTEXT,
-    WM_GETTEXT,
-    WM_GETTEXTLENGTH,
-    WM_PAINT,
-    WM_CLOSE,
-    WM_QUERYENDSESSION,
-    WM_QUERYOPEN,
-    WM_ENDSESSION,
-    WM_QUIT,
-    WM_ERASEBKGND,
-    WM_SYSCOLORCHANGE,
-    WM_SHOWWINDOW,
-    WM_WININICHANGE,
-    WM_SETTINGCHANGE,
-
-    WM_DEVMODECHANGE,
-    WM_ACTIVATEAPP,
-    WM_FONTCHANGE,
-    WM_TIMECHANGE,
-    WM_CANCELMODE,
-    WM_SETCURSOR,
-    WM_MOUSEACTIVATE,
-    WM_CHILDACTIVATE,
-    WM_QUEUESYNC,
-
-    WM_GETMINMAXINFO,
-
-    WM_PAINTICON,
-    WM_ICONERASEBKGND,
-    WM_NEXTDLGCTL,
-    WM_SPOOLERSTATUS,
-    WM_DRAWITEM,
-    WM_MEASUREITEM,
-    WM_DELETEITEM,
-    WM_VKEYTOITEM,
-    WM_CHARTOITEM,
-    WM_SETFONT,
-    WM_GETFONT,
-    WM_SETHOTKEY,
-    WM_GETHOTKEY,
-    WM_QUERYDRAGICON,
-    WM_COMPAREITEM,
-    WM_GETOBJECT,
-    WM_COMPACTING,
-    WM_COMMNOTIFY,
-    WM_WINDOWPOSCHANGING,
-    WM_WINDOWPOSCHANGED,
-
-    WM_POWER,
-
-    WM_COPYDATA,
-    WM_CANCELJOURNAL,
-
-    WM_NOTIFY,
-    WM_INPUTLANGCHANGEREQUEST,
-    WM_INPUTLANGCHANGE,
-    WM_TCARD,
-    WM_HELP,
-    WM_USERCHANGED,
-    WM_NOTIFYFORMAT,
-
-    WM_CONTEXTMENU,
-    WM_STYLECHANGING,
-    WM_STYLECHANGED,
-    WM_DISPLAYCHANGE,
-    WM_GETICON,
-    WM_SETICON,
-
-    WM_NCCREATE,
-    WM_NCDESTROY,
-    WM_NCCALCSIZE,
-    WM_NCHITTEST,
-    WM_NCPAINT,
-    WM_NCACTIVATE,
-    WM_GETDLGCODE,
-    WM_SYNCPAINT,
-    WM_NCMOUSEMOVE,
-    WM_NCLBUTTONDOWN,
-    WM_NCLBUTTONUP,
-    WM_NCLBUTTONDBLCLK,
-    WM_NCRBUTTONDOWN,
-    WM_NCRBUTTONUP,
-    WM_NCRBUTTONDBLCLK,
-    WM_NCMBUTTONDOWN,
-    WM_NCMBUTTONUP,
-    WM_NCMBUTTONDBLCLK,
-
-    WM_NCXBUTTONDOWN,
-    WM_NCXBUTTONUP,
-    WM_NCXBUTTONDBLCLK,
-
-    WM_INPUT_DEVICE_CHANGE,
-    WM_INPUT,
-
-    WM_KEYDOWN,
-    WM_KEYUP,
-    WM_CHAR,
-    WM_DEADCHAR,
-    WM_SYSKEYDOWN,
-    WM_SYSKEYUP,
-    WM_SYSCHAR,
-    WM_SYSDEADCHAR,
-    WM_UNICHAR,
-
-    WM_IME_STARTCOMPOSITION,
-    WM_IME_ENDCOMPOSITION,
-    WM_IME_COMPOSITION,
-
-    WM_INITDIALOG,
-    WM_COMMAND,
-    WM_SYSCOMMAND,
-    WM_TIMER,
-    WM_HSCROLL,
-    WM_VSCROLL,
-    WM_INITMENU,
-    WM_INITMENUPOPUP,
-    WM_GESTURE,
-    WM_GESTURENOTIFY,
-    WM_MENUSELECT,
-    WM_MENUCHAR,
-    WM_ENTERIDLE,
-    WM_MENURBUTTONUP,
-    WM_MENUDRAG,
-    WM_MENUGETOBJECT,
-    WM_UNINITMENUPOPUP,
-    WM_MENUCOMMAND,
-
-    WM_CHANGEUISTATE,
-    WM_UPDATEUISTATE,
-    WM_QUERYUISTATE,
-
-    WM_CTLCOLORMSGBOX,
-    WM_CTLCOLOREDIT,
-    WM_CTLCOLORLISTBOX,
-    WM_CTLCOLORBTN,
-    WM_CTLCOLORDLG,
-    WM_CTLCOLORSCROLLBAR,
-    WM_CTLCOLORSTATIC,
-    MN_GETHMENU,
-
-    WM_MOUSEMOVE,
-    WM_LBUTTONDOWN,
-    WM_LBUTTONUP,
-    WM_LBUTTONDBLCLK,
-    WM_RBUTTONDOWN,
-    WM_RBUTTONUP,
-    WM_RBUTTONDBLCLK,
-    WM_MBUTTONDOWN,
-    WM_MBUTTONUP,
-    WM_MBUTTONDBLCLK,
-    WM_MOUSEWHEEL,
-    WM_XBUTTONDOWN,
-    WM_XBUTTONUP,
-    WM_XBUTTONDBLCLK,
-    WM_MOUSEHWHEEL,
-
-    WM_PARENTNOTIFY,
-    WM_ENTERMENULOOP,
-    WM_EXITMENULOOP,
-
-    WM_NEXTMENU,
-    WM_SIZING,
-    WM_CAPTURECHANGED,
-    WM_MOVING,
-
-    WM_POWERBROADCAST,
-
-    WM_DEVICECHANGE,
-
-    WM_MDICREATE,
-    WM_MDIDESTROY,
-    WM_MDIACTIVATE,
-    WM_MDIRESTORE,
-    WM_MDINEXT,
-    WM_MDIMAXIMIZE,
-    WM_MDITILE,
-    WM_MDICASCADE,
-    WM_MDIICONARRANGE,
-    WM_MDIGETACTIVE,
-
-    WM_MDISETMENU,
-    WM_ENTERSIZEMOVE,
-    WM_EXITSIZEMOVE,
-    WM_DROPFILES,
-    WM_MDIREFRESHMENU,
-
-    WM_POINTERDEVICECHANGE,
-    WM_POINTERDEVICEINRANGE,
-    WM_POINTERDEVICEOUTOFRANGE,
-
-    WM_TOUCH,
-
-    WM_NCPOINTERUPDATE,
-    WM_NCPOINTERDOWN,
-    WM_NCPOINTERUP,
-    WM_POINTERUPDATE,
-    WM_POINTERDOWN,
-    WM_POINTERUP,
-    WM_POINTERENTER,
-    WM_POINTERLEAVE,
-    WM_POINTERACTIVATE,
-    WM_POINTERCAPTURECHANGED,
-    WM_TOUCHHITTESTING,
-    WM_POINTERWHEEL,
-    WM_POINTERHWHEEL,
-    DM_POINTERHITTEST, // XXX: funky!
-    WM_POINTERROUTEDTO,
-    WM_POINTERROUTEDAWAY,
-    WM_POINTERROUTEDRELEASED,
-
-    WM_IME_SETCONTEXT,
-    WM_IME_NOTIFY,
-    WM_IME_CONTROL,
-    WM_IME_COMPOSITIONFULL,
-    WM_IME_SELECT,
-    WM_IME_CHAR,
-    WM_IME_REQUEST,
-    WM_IME_KEYDOWN,
-    WM_IME_KEYUP,
-
-    WM_MOUSEHOVER,
-    WM_MOUSELEAVE,
-    WM_NCMOUSEHOVER,
-    WM_NCMOUSELEAVE,
-
-    WM_WTSSESSION_CHANGE,
-
-    WM_DPICHANGED,
-    WM_DPICHANGED_BEFOREPARENT,
-    WM_DPICHANGED_AFTERPARENT,
-    WM_GETDPISCALEDSIZE,
-
-    WM_CUT,
-    WM_COPY,
-    WM_PASTE,
-    WM_CLEAR,
-    WM_UNDO,
-    WM_RENDERFORMAT,
-    WM_RENDERALLFORMATS,
-    WM_DESTROYCLIPBOARD,
-    WM_DRAWCLIPBOARD,
-    WM_PAINTCLIPBOARD,
-    WM_VSCROLLCLIPBOARD,
-    WM_SIZECLIPBOARD,
-    WM_ASKCBFORMATNAME,
-    WM_CHANGECBCHAIN,
-    WM_HSCROLLCLIPBOARD,
-    WM_QUERYNEWPALETTE,
-    WM_PALETTEISCHANGING,
-    WM_PALETTECHANGED,
-    WM_HOTKEY,
-
-    WM_PRINT,
-    WM_PRINTCLIENT,
-
-    WM_APPCOMMAND,
-
-    WM_THEMECHANGED,
-
-    WM_CLIPBOARDUPDATE,
-
-    WM_DWMCOMPOSITIONCHANGED,
-    WM_DWMNCRENDERINGCHANGED,
-    WM_DWMCOLORIZATIONCOLORCHANGED,
-    WM_DWMWINDOWMAXIMIZEDCHANGE,
-
-    WM_DWMSENDICONICTHUMBNAIL,
-    WM_DWMSENDICONICLIVEPREVIEWBITMAP,
-
-    WM_GETTITLEBARINFOEX,
-
-    WM_UAHDESTROYWINDOW,
-    WM_UAHDRAWMENU,
-    WM_UAHDRAWMENUITEM,
-    WM_UAHINITMENU,
-    WM_UAHMEASUREMENUITEM,
-    WM_UAHNCPAINTMENUPOPUP,
 }
+
+/// `(cpp, rust, value)`, e.g. `("WM_CREATE", "WM::CREATE", 1)`
+#[cfg(windows)] pub fn cpp_rust_values() -> impl Iterator<Item = (Cow<'static, str>, Cow<'static, str>, i32)> {
+    MESSAGES.iter().map(|m| { let cpp = m.cpp_name(); let rust = cpp.replace("WM_", "WM::").into(); (cpp, rust, m.value as _) })
+}
+
+pub const MESSAGES : &[Message] = &[
+    msg("NULL",                          0x0000, Unused, Unused,                             Ret::Ignored),
+    msg("CREATE",                        0x0001, Unused, ptr("CREATESTRUCT"),                Ret::Value).marshaled(),
+    msg("DESTROY",                       0x0002, Unused, Unused,                             Ret::Zero),
+    msg("MOVE",                          0x0003, Unused, Value,                              Ret::Zero),
+    msg("SIZE",                          0x0005, Value,  Value,                              Ret::Zero),
+
+    msg("ACTIVATE",                      0x0006, Value,  Value,                              Ret::Zero),
+
+    msg("SETFOCUS",                      0x0007, Value,  Unused,                             Ret::Zero),
+    msg("KILLFOCUS",                     0x0008, Value,  Unused,                             Ret::Zero),
+    msg("ENABLE",                        0x000A, Value,  Unused,                             Ret::Zero),
+    msg("SETREDRAW",                     0x000B, Value,  Unused,                             Ret::Zero),
+    msg("SETTEXT",                       0x000C, Unused, ptr("TCHAR"),                       Ret::Bool).marshaled(),
+    msg("GETTEXT",                       0x000D, Value,  ptr("TCHAR"),                       Ret::Value).marshaled(),
+    msg("GETTEXTLENGTH",                 0x000E, Unused, Unused,                             Ret::Value),
+    msg("PAINT",                         0x000F, Unused, Unused,                             Ret::Zero),
+    msg("CLOSE",                         0x0010, Unused, Unused,                             Ret::Zero),
+    msg("QUERYENDSESSION",               0x0011, Unused, Value,                              Ret::Bool),
+    msg("QUERYOPEN",                     0x0013, Unused, Unused,                             Ret::Bool),
+    msg("ENDSESSION",                    0x0016, Value,  Value,                              Ret::Zero),
+    msg("QUIT",                          0x0012, Value,  Unused,                             Ret::Ignored),
+    msg("ERASEBKGND",                    0x0014, Value,  Unused,                             Ret::Bool),
+    msg("SYSCOLORCHANGE",                0x0015, Unused, Unused,                             Ret::Ignored),
+    msg("SHOWWINDOW",                    0x0018, Value,  Value,                              Ret::Zero),
+    msg("WININICHANGE",                  0x001A, Value,  ptr("TCHAR"),                       Ret::Zero).marshaled(),
+    msg("SETTINGCHANGE",                 0x001A, Value,  ptr("TCHAR"),                       Ret::Zero).marshaled(),
+
+    msg("DEVMODECHANGE",                 0x001B, Unused, ptr("TCHAR"),                       Ret::Zero).marshaled(),
+    msg("ACTIVATEAPP",                   0x001C, Value,  Value,                              Ret::Zero),
+    msg("FONTCHANGE",                    0x001D, Unused, Unused,                             Ret::Ignored),
+    msg("TIMECHANGE",                    0x001E, Unused, Unused,                             Ret::Zero),
+    msg("CANCELMODE",                    0x001F, Unused, Unused,                             Ret::Zero),
+    msg("SETCURSOR",                     0x0020, Value,  Value,                              Ret::Bool),
+    msg("MOUSEACTIVATE",                 0x0021, Value,  Value,                              Ret::Value),
+    msg("CHILDACTIVATE",                 0x0022, Unused, Unused,                             Ret::Zero),
+    msg("QUEUESYNC",                     0x0023, Unused, Unused,                             Ret::Zero),
+
+    msg("GETMINMAXINFO",                 0x0024, Unused, ptr("MINMAXINFO"),                  Ret::Zero).marshaled(),
+
+    msg("PAINTICON",                     0x0026, Unused, Unused,                             Ret::Ignored),
+    msg("ICONERASEBKGND",                0x0027, Value,  Unused,                             Ret::Ignored),
+    msg("NEXTDLGCTL",                    0x0028, Value,  Value,                              Ret::Zero),
+    msg("SPOOLERSTATUS",                 0x002A, Value,  Value,                              Ret::Zero),
+    msg("DRAWITEM",                      0x002B, Value,  ptr("DRAWITEMSTRUCT"),              Ret::Bool).marshaled(),
+    msg("MEASUREITEM",                   0x002C, Value,  ptr("MEASUREITEMSTRUCT"),           Ret::Bool).marshaled(),
+    msg("DELETEITEM",                    0x002D, Value,  ptr("DELETEITEMSTRUCT"),            Ret::Bool).marshaled(),
+    msg("VKEYTOITEM",                    0x002E, Value,  Value,                              Ret::Value),
+    msg("CHARTOITEM",                    0x002F, Value,  Value,                              Ret::Value),
+    msg("SETFONT",                       0x0030, Value,  Value,                              Ret::Ignored),
+    msg("GETFONT",                       0x0031, Unused, Unused,                             Ret::Value),
+    msg("SETHOTKEY",                     0x0032, Value,  Unused,                             Ret::Value),
+    msg("GETHOTKEY",                     0x0033, Unused, Unused,                             Ret::Value),
+    msg("QUERYDRAGICON",                 0x0037, Unused, Unused,                             Ret::Value),
+    msg("COMPAREITEM",                   0x0039, Value,  ptr("COMPAREITEMSTRUCT"),           Ret::Value).marshaled(),
+    msg("GETOBJECT",                     0x003D, Value,  Value,                              Ret::Value),
+    msg("COMPACTING",                    0x0041, Value,  Unused,                             Ret::Zero),
+    msg("COMMNOTIFY",                    0x0044, Value,  Value,                              Ret::Ignored),
+    msg("WINDOWPOSCHANGING",             0x0046, Unused, ptr("WINDOWPOS"),                   Ret::Zero).marshaled(),
+    msg("WINDOWPOSCHANGED",              0x0047, Unused, ptr("WINDOWPOS"),                   Ret::Zero).marshaled(),
+
+    msg("POWER",                         0x0048, Value,  Unused,                             Ret::Bool),
+
+    msg("COPYDATA",                      0x004A, Value,  ptr("COPYDATASTRUCT"),              Ret::Bool).marshaled(),
+    msg("CANCELJOURNAL",                 0x004B, Unused, Unused,                             Ret::Ignored),
+
+    msg("NOTIFY",                        0x004E, Value,  ptr("NMHDR"),                       Ret::Value).local(),
+    msg("INPUTLANGCHANGEREQUEST",        0x0050, Value,  Value,                              Ret::Zero),
+    msg("INPUTLANGCHANGE",               0x0051, Value,  Value,                              Ret::Bool),
+    msg("TCARD",                         0x0052, Value,  Value,                              Ret::Zero),
+    msg("HELP",                          0x0053, Unused, ptr("HELPINFO"),                    Ret::Bool).marshaled(),
+    msg("USERCHANGED",                   0x0054, Unused, Unused,                             Ret::Zero),
+    msg("NOTIFYFORMAT",                  0x0055, Value,  Value,                              Ret::Value),
+
+    msg("CONTEXTMENU",                   0x007B, Value,  Value,                              Ret::Ignored),
+    msg("STYLECHANGING",                 0x007C, Value,  ptr("STYLESTRUCT"),                 Ret::Zero).marshaled(),
+    msg("STYLECHANGED",                  0x007D, Value,  ptr("STYLESTRUCT"),                 Ret::Zero).marshaled(),
+    msg("DISPLAYCHANGE",                 0x007E, Value,  Value,                              Ret::Ignored),
+    msg("GETICON",                       0x007F, Value,  Value,                              Ret::Value),
+    msg("SETICON",                       0x0080, Value,  Value,                              Ret::Value),
+
+    msg("NCCREATE",                      0x0081, Unused, ptr("CREATESTRUCT"),                Ret::Bool).marshaled(),
+    msg("NCDESTROY",                     0x0082, Unused, Unused,                             Ret::Zero),
+    msg("NCCALCSIZE",                    0x0083, Value,  ptr("NCCALCSIZE_PARAMS"),           Ret::Value).marshaled(),
+    msg("NCHITTEST",                     0x0084, Unused, Value,                              Ret::Value),
+    msg("NCPAINT",                       0x0085, Value,  Unused,                             Ret::Zero),
+    msg("NCACTIVATE",                    0x0086, Value,  Value,                              Ret::Bool),
+    msg("GETDLGCODE",                    0x0087, Value,  ptr("MSG"),                         Ret::Value).marshaled(),
+    msg("SYNCPAINT",                     0x0088, Unused, Unused,                             Ret::Zero),
+    msg("NCMOUSEMOVE",                   0x00A0, Value,  Value,                              Ret::Zero),
+    msg("NCLBUTTONDOWN",                 0x00A1, Value,  Value,                              Ret::Zero),
+    msg("NCLBUTTONUP",                   0x00A2, Value,  Value,                              Ret::Zero),
+    msg("NCLBUTTONDBLCLK",               0x00A3, Value,  Value,                              Ret::Zero),
+    msg("NCRBUTTONDOWN",                 0x00A4, Value,  Value,                              Ret::Zero),
+    msg("NCRBUTTONUP",                   0x00A5, Value,  Value,                              Ret::Zero),
+    msg("NCRBUTTONDBLCLK",               0x00A6, Value,  Value,                              Ret::Zero),
+    msg("NCMBUTTONDOWN",                 0x00A7, Value,  Value,                              Ret::Zero),
+    msg("NCMBUTTONUP",                   0x00A8, Value,  Value,                              Ret::Zero),
+    msg("NCMBUTTONDBLCLK",               0x00A9, Value,  Value,                              Ret::Zero),
+
+    msg("NCXBUTTONDOWN",                 0x00AB, Value,  Value,                              Ret::Bool),
+    msg("NCXBUTTONUP",                   0x00AC, Value,  Value,                              Ret::Bool),
+    msg("NCXBUTTONDBLCLK",               0x00AD, Value,  Value,                              Ret::Bool),
+
+    msg("INPUT_DEVICE_CHANGE",           0x00FE, Value,  Value,                              Ret::Zero),
+    msg("INPUT",                         0x00FF, Value,  Value,                              Ret::Zero),
+
+    msg("KEYDOWN",                       0x0100, Value,  Value,                              Ret::Zero),
+    msg("KEYUP",                         0x0101, Value,  Value,                              Ret::Zero),
+    msg("CHAR",                          0x0102, Value,  Value,                              Ret::Zero),
+    msg("DEADCHAR",                      0x0103, Value,  Value,                              Ret::Zero),
+    msg("SYSKEYDOWN",                    0x0104, Value,  Value,                              Ret::Zero),
+    msg("SYSKEYUP",                      0x0105, Value,  Value,                              Ret::Zero),
+    msg("SYSCHAR",                       0x0106, Value,  Value,                              Ret::Zero),
+    msg("SYSDEADCHAR",                   0x0107, Value,  Value,                              Ret::Zero),
+    msg("UNICHAR",                       0x0109, Value,  Value,                              Ret::Bool),
+
+    msg("IME_STARTCOMPOSITION",          0x010D, Unused, Unused,                             Ret::Ignored),
+    msg("IME_ENDCOMPOSITION",            0x010E, Unused, Unused,                             Ret::Ignored),
+    msg("IME_COMPOSITION",               0x010F, Value,  Value,                              Ret::Ignored),
+
+    msg("INITDIALOG",                    0x0110, Value,  Opaque,                             Ret::Bool),
+    msg("COMMAND",                       0x0111, Value,  Value,                              Ret::Zero),
+    msg("SYSCOMMAND",                    0x0112, Value,  Value,                              Ret::Zero),
+    msg("TIMER",                         0x0113, Value,  Opaque,                             Ret::Zero),
+    msg("HSCROLL",                       0x0114, Value,  Value,                              Ret::Zero),
+    msg("VSCROLL",                       0x0115, Value,  Value,                              Ret::Zero),
+    msg("INITMENU",                      0x0116, Value,  Unused,                             Ret::Zero),
+    msg("INITMENUPOPUP",                 0x0117, Value,  Value,                              Ret::Zero),
+    msg("GESTURE",                       0x0119, Value,  Value,                              Ret::Zero),
+    msg("GESTURENOTIFY",                 0x011A, Unused, ptr("GESTURENOTIFYSTRUCT"),         Ret::Zero).local(),
+    msg("MENUSELECT",                    0x011F, Value,  Value,                              Ret::Zero),
+    msg("MENUCHAR",                      0x0120, Value,  Value,                              Ret::Value),
+    msg("ENTERIDLE",                     0x0121, Value,  Value,                              Ret::Zero),
+    msg("MENURBUTTONUP",                 0x0122, Value,  Value,                              Ret::Ignored),
+    msg("MENUDRAG",                      0x0123, Value,  Value,                              Ret::Value),
+    msg("MENUGETOBJECT",                 0x0124, Unused, ptr("MENUGETOBJECTINFO"),           Ret::Value).local(),
+    msg("UNINITMENUPOPUP",               0x0125, Value,  Value,                              Ret::Ignored),
+    msg("MENUCOMMAND",                   0x0126, Value,  Value,                              Ret::Ignored),
+
+    msg("CHANGEUISTATE",                 0x0127, Value,  Unused,                             Ret::Ignored),
+    msg("UPDATEUISTATE",                 0x0128, Value,  Unused,                             Ret::Ignored),
+    msg("QUERYUISTATE",                  0x0129, Unused, Unused,                             Ret::Value),
+
+    msg("CTLCOLORMSGBOX",                0x0132, Value,  Value,                              Ret::Value),
+    msg("CTLCOLOREDIT",                  0x0133, Value,  Value,                              Ret::Value),
+    msg("CTLCOLORLISTBOX",               0x0134, Value,  Value,                              Ret::Value),
+    msg("CTLCOLORBTN",                   0x0135, Value,  Value,                              Ret::Value),
+    msg("CTLCOLORDLG",                   0x0136, Value,  Value,                              Ret::Value),
+    msg("CTLCOLORSCROLLBAR",             0x0137, Value,  Value,                              Ret::Value),
+    msg("CTLCOLORSTATIC",                0x0138, Value,  Value,                              Ret::Value),
+    msg("MN_GETHMENU",                   0x01E1, Unused, Unused,                             Ret::Value).cpp("MN_GETHMENU"),
+
+    msg("MOUSEMOVE",                     0x0200, Value,  Value,                              Ret::Zero),
+    msg("LBUTTONDOWN",                   0x0201, Value,  Value,                              Ret::Zero),
+    msg("LBUTTONUP",                     0x0202, Value,  Value,                              Ret::Zero),
+    msg("LBUTTONDBLCLK",                 0x0203, Value,  Value,                              Ret::Zero),
+    msg("RBUTTONDOWN",                   0x0204, Value,  Value,                              Ret::Zero),
+    msg("RBUTTONUP",                     0x0205, Value,  Value,                              Ret::Zero),
+    msg("RBUTTONDBLCLK",                 0x0206, Value,  Value,                              Ret::Zero),
+    msg("MBUTTONDOWN",                   0x0207, Value,  Value,                              Ret::Zero),
+    msg("MBUTTONUP",                     0x0208, Value,  Value,                              Ret::Zero),
+    msg("MBUTTONDBLCLK",                 0x0209, Value,  Value,                              Ret::Zero),
+    msg("MOUSEWHEEL",                    0x020A, Value,  Value,                              Ret::Zero),
+    msg("XBUTTONDOWN",                   0x020B, Value,  Value,                              Ret::Bool),
+    msg("XBUTTONUP",                     0x020C, Value,  Value,                              Ret::Bool),
+    msg("XBUTTONDBLCLK",                 0x020D, Value,  Value,                              Ret::Bool),
+    msg("MOUSEHWHEEL",                   0x020E, Value,  Value,                              Ret::Zero),
+
+    msg("PARENTNOTIFY",                  0x0210, Value,  Value,                              Ret::Zero),
+    msg("ENTERMENULOOP",                 0x0211, Value,  Unused,                             Ret::Zero),
+    msg("EXITMENULOOP",                  0x0212, Value,  Unused,                             Ret::Zero),
+
+    msg("NEXTMENU",                      0x0213, Value,  ptr("MDINEXTMENU"),                 Ret::Zero).marshaled(),
+    msg("SIZING",                        0x0214, Value,  ptr("RECT"),                        Ret::Bool).marshaled(),
+    msg("CAPTURECHANGED",                0x0215, Unused, Value,                              Ret::Zero),
+    msg("MOVING",                        0x0216, Value,  ptr("RECT"),                        Ret::Bool).marshaled(),
+
+    msg("POWERBROADCAST",                0x0218, Value,  ptr("POWERBROADCAST_SETTING"),      Ret::Bool).marshaled(),
+
+    msg("DEVICECHANGE",                  0x0219, Value,  ptr("DEV_BROADCAST_HDR"),           Ret::Bool).marshaled(),
+
+    msg("MDICREATE",                     0x0220, Unused, ptr("MDICREATESTRUCT"),             Ret::Value).marshaled(),
+    msg("MDIDESTROY",                    0x0221, Value,  Unused,                             Ret::Zero),
+    msg("MDIACTIVATE",                   0x0222, Value,  Value,                              Ret::Zero),
+    msg("MDIRESTORE",                    0x0223, Value,  Unused,                             Ret::Zero),
+    msg("MDINEXT",                       0x0224, Value,  Value,                              Ret::Zero),
+    msg("MDIMAXIMIZE",                   0x0225, Value,  Unused,                             Ret::Zero),
+    msg("MDITILE",                       0x0226, Value,  Unused,                             Ret::Bool),
+    msg("MDICASCADE",                    0x0227, Value,  Unused,                             Ret::Bool),
+    msg("MDIICONARRANGE",                0x0228, Unused, Unused,                             Ret::Ignored),
+    msg("MDIGETACTIVE",                  0x0229, Unused, ptr("BOOL"),                        Ret::Value).local(),
+
+    msg("MDISETMENU",                    0x0230, Value,  Value,                              Ret::Value),
+    msg("ENTERSIZEMOVE",                 0x0231, Unused, Unused,                             Ret::Zero),
+    msg("EXITSIZEMOVE",                  0x0232, Unused, Unused,                             Ret::Zero),
+    msg("DROPFILES",                     0x0233, Value,  Unused,                             Ret::Zero),
+    msg("MDIREFRESHMENU",                0x0234, Unused, Unused,                             Ret::Value),
+
+    msg("POINTERDEVICECHANGE",           0x0238, Value,  Value,                              Ret::Zero),
+    msg("POINTERDEVICEINRANGE",          0x0239, Value,  Value,                              Ret::Zero),
+    msg("POINTERDEVICEOUTOFRANGE",       0x023A, Value,  Value,                              Ret::Zero),
+
+    msg("TOUCH",                         0x0240, Value,  Value,                              Ret::Zero),
+
+    msg("NCPOINTERUPDATE",               0x0241, Value,  Value,                              Ret::Zero),
+    msg("NCPOINTERDOWN",                 0x0242, Value,  Value,                              Ret::Zero),
+    msg("NCPOINTERUP",                   0x0243, Value,  Value,                              Ret::Zero),
+    msg("POINTERUPDATE",                 0x0245, Value,  Value,                              Ret::Zero),
+    msg("POINTERDOWN",                   0x0246, Value,  Value,                              Ret::Zero),
+    msg("POINTERUP",                     0x0247, Value,  Value,                              Ret::Zero),
+    msg("POINTERENTER",                  0x0249, Value,  Value,                              Ret::Zero),
+    msg("POINTERLEAVE",                  0x024A, Value,  Value,                              Ret::Zero),
+    msg("POINTERACTIVATE",               0x024B, Value,  Value,                              Ret::Value),
+    msg("POINTERCAPTURECHANGED",         0x024C, Value,  Value,                              Ret::Zero),
+    msg("TOUCHHITTESTING",               0x024D, Unused, ptr("TOUCH_HIT_TESTING_INPUT"),     Ret::Value).local(),
+    msg("POINTERWHEEL",                  0x024E, Value,  Value,                              Ret::Zero),
+    msg("POINTERHWHEEL",                 0x024F, Value,  Value,                              Ret::Zero),
+    msg("DM_POINTERHITTEST",             0x0250, Value,  Value,                              Ret::Zero).cpp("DM_POINTERHITTEST"), // XXX: funky!
+    msg("POINTERROUTEDTO",               0x0251, Value,  Value,                              Ret::Zero),
+    msg("POINTERROUTEDAWAY",             0x0252, Value,  Value,                              Ret::Zero),
+    msg("POINTERROUTEDRELEASED",         0x0253, Value,  Value,                              Ret::Zero),
+
+    msg("IME_SETCONTEXT",                0x0281, Value,  Value,                              Ret::Value),
+    msg("IME_NOTIFY",                    0x0282, Value,  Value,                              Ret::Value),
+    msg("IME_CONTROL",                   0x0283, Value,  Value,                              Ret::Value),
+    msg("IME_COMPOSITIONFULL",           0x0284, Unused, Unused,                             Ret::Ignored),
+    msg("IME_SELECT",                    0x0285, Value,  Value,                              Ret::Ignored),
+    msg("IME_CHAR",                      0x0286, Value,  Value,                              Ret::Zero),
+    msg("IME_REQUEST",                   0x0288, Value,  ptr(""),                            Ret::Value).local(),
+    msg("IME_KEYDOWN",                   0x0290, Value,  Value,                              Ret::Zero),
+    msg("IME_KEYUP",                     0x0291, Value,  Value,                              Ret::Zero),
+
+    msg("MOUSEHOVER",                    0x02A1, Value,  Value,                              Ret::Zero),
+    msg("MOUSELEAVE",                    0x02A3, Unused, Unused,                             Ret::Zero),
+    msg("NCMOUSEHOVER",                  0x02A0, Value,  Value,                              Ret::Zero),
+    msg("NCMOUSELEAVE",                  0x02A2, Unused, Unused,                             Ret::Zero),
+
+    msg("WTSSESSION_CHANGE",             0x02B1, Value,  Value,                              Ret::Ignored),
+
+    msg("DPICHANGED",                    0x02E0, Value,  ptr("RECT"),                        Ret::Zero).marshaled(),
+    msg("DPICHANGED_BEFOREPARENT",       0x02E2, Unused, Unused,                             Ret::Ignored),
+    msg("DPICHANGED_AFTERPARENT",        0x02E3, Unused, Unused,                             Ret::Ignored),
+    msg("GETDPISCALEDSIZE",              0x02E4, Value,  ptr("SIZE"),                        Ret::Bool).marshaled(),
+
+    msg("CUT",                           0x0300, Unused, Unused,                             Ret::Ignored),
+    msg("COPY",                          0x0301, Unused, Unused,                             Ret::Ignored),
+    msg("PASTE",                         0x0302, Unused, Unused,                             Ret::Ignored),
+    msg("CLEAR",                         0x0303, Unused, Unused,                             Ret::Ignored),
+    msg("UNDO",                          0x0304, Unused, Unused,                             Ret::Bool),
+    msg("RENDERFORMAT",                  0x0305, Value,  Unused,                             Ret::Zero),
+    msg("RENDERALLFORMATS",              0x0306, Unused, Unused,                             Ret::Zero),
+    msg("DESTROYCLIPBOARD",              0x0307, Unused, Unused,                             Ret::Zero),
+    msg("DRAWCLIPBOARD",                 0x0308, Unused, Unused,                             Ret::Ignored),
+    msg("PAINTCLIPBOARD",                0x0309, Value,  Opaque,                             Ret::Ignored),
+    msg("VSCROLLCLIPBOARD",              0x030A, Value,  Value,                              Ret::Zero),
+    msg("SIZECLIPBOARD",                 0x030B, Value,  Opaque,                             Ret::Ignored),
+    msg("ASKCBFORMATNAME",               0x030C, Value,  ptr("TCHAR"),                       Ret::Ignored).marshaled(),
+    msg("CHANGECBCHAIN",                 0x030D, Value,  Value,                              Ret::Zero),
+    msg("HSCROLLCLIPBOARD",              0x030E, Value,  Value,                              Ret::Zero),
+    msg("QUERYNEWPALETTE",               0x030F, Unused, Unused,                             Ret::Bool),
+    msg("PALETTEISCHANGING",             0x0310, Value,  Unused,                             Ret::Zero),
+    msg("PALETTECHANGED",                0x0311, Value,  Unused,                             Ret::Ignored),
+    msg("HOTKEY",                        0x0312, Value,  Value,                              Ret::Ignored),
+
+    msg("PRINT",                         0x0317, Value,  Value,                              Ret::Ignored),
+    msg("PRINTCLIENT",                   0x0318, Value,  Value,                              Ret::Ignored),
+
+    msg("APPCOMMAND",                    0x0319, Value,  Value,                              Ret::Bool),
+
+    msg("THEMECHANGED",                  0x031A, Unused, Unused,                             Ret::Zero),
+
+    msg("CLIPBOARDUPDATE",               0x031D, Unused, Unused,                             Ret::Zero),
+
+    msg("DWMCOMPOSITIONCHANGED",         0x031E, Unused, Unused,                             Ret::Zero),
+    msg("DWMNCRENDERINGCHANGED",         0x031F, Value,  Unused,                             Ret::Zero),
+    msg("DWMCOLORIZATIONCOLORCHANGED",   0x0320, Value,  Value,                              Ret::Zero),
+    msg("DWMWINDOWMAXIMIZEDCHANGE",      0x0321, Value,  Unused,                             Ret::Zero),
+
+    msg("DWMSENDICONICTHUMBNAIL",        0x0323, Unused, Value,                              Ret::Zero),
+    msg("DWMSENDICONICLIVEPREVIEWBITMAP", 0x0326, Unused, Unused,                             Ret::Zero),
+
+    msg("GETTITLEBARINFOEX",             0x033F, Unused, ptr("TITLEBARINFOEX"),              Ret::Ignored).marshaled(),
+
+    // undocumented: https://social.msdn.microsoft.com/Forums/windowsapps/en-US/f677f319-9f02-4438-92fb-6e776924425d/windowproc-and-messages-0x90-0x91-0x92-0x93?forum=windowsuidevelopment
+    msg("UAHDESTROYWINDOW",              0x0090, Unused, Unused,                             Ret::Ignored),
+    msg("UAHDRAWMENU",                   0x0091, Unused, ptr("UAHMENU"),                     Ret::Ignored).local(),
+    msg("UAHDRAWMENUITEM",               0x0092, Unused, ptr("UAHDRAWMENUITEM"),             Ret::Ignored).local(),
+    msg("UAHINITMENU",                   0x0093, Unused, ptr("UAHMENU"),                     Ret::Ignored).local(),
+    msg("UAHMEASUREMENUITEM",            0x0094, Unused, ptr("UAHMEASUREMENUITEM"),          Ret::Ignored).local(),
+    msg("UAHNCPAINTMENUPOPUP",           0x0095, Unused, ptr("UAHMENU"),                     Ret::Ignored).local(),
+];
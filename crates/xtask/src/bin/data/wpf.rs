@@ -1,14 +1,15 @@
-macro_rules! flags { ( $($cpp:path),* $(,)? ) => {
-    pub fn cpp_rust_values() -> impl Iterator<Item = (&'static str, &'static str, u32)> {
-        use winapi::um::winuser::*;
-        vec![$(
-            (stringify!($cpp), stringify!($cpp).strip_prefix("WPF_").unwrap(), $cpp),
-        )*].into_iter()
-    }
-}}
+use super::flags::*;
 
-flags! {
-    WPF_SETMINPOSITION,
-    WPF_RESTORETOMAXIMIZED,
-    WPF_ASYNCWINDOWPLACEMENT,
-}
+pub const FLAGS : Flags = Flags {
+    header:     "winuser",
+    module:     "WPF",
+    ty:         "WindowPlacementFlags",
+    url:        "https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-windowplacement",
+    summary:    "WPF_\\* flags for [WindowPlacement]",
+    values:     &[
+        flag("SETMINPOSITION",          0x0001),
+        flag("RESTORETOMAXIMIZED",      0x0002),
+        flag("ASYNCWINDOWPLACEMENT",    0x0004),
+    ],
+    invalid:    &[],
+};
@@ -1,38 +1,43 @@
-macro_rules! flags { ( $($cpp:path),* $(,)? ) => {
-    pub fn cpp_rust_values() -> impl Iterator<Item = (&'static str, &'static str, u32)> {
-        use winapi::um::winuser::*;
-        vec![$(
-            (stringify!($cpp), stringify!($cpp).strip_prefix("WS_").unwrap(), $cpp),
-        )*].into_iter()
-    }
-}}
+use super::flags::*;
 
-flags! {
-    WS_OVERLAPPED,
-    WS_POPUP,
-    WS_CHILD,
-    WS_MINIMIZE,
-    WS_VISIBLE,
-    WS_DISABLED,
-    WS_CLIPSIBLINGS,
-    WS_CLIPCHILDREN,
-    WS_MAXIMIZE,
-    WS_CAPTION,
-    WS_BORDER,
-    WS_DLGFRAME,
-    WS_VSCROLL,
-    WS_HSCROLL,
-    WS_SYSMENU,
-    WS_THICKFRAME,
-    WS_GROUP,
-    WS_TABSTOP,
-    WS_MINIMIZEBOX,
-    WS_MAXIMIZEBOX,
-    WS_TILED,
-    WS_ICONIC,
-    WS_SIZEBOX,
-    WS_TILEDWINDOW,
-    WS_OVERLAPPEDWINDOW,
-    WS_POPUPWINDOW,
-    WS_CHILDWINDOW,
-}
+pub const FLAGS : Flags = Flags {
+    header:     "winuser",
+    module:     "WS",
+    ty:         "WindowStyle",
+    url:        "https://learn.microsoft.com/en-us/windows/win32/winmsg/window-styles",
+    summary:    "WS_\\* window style flags for [create_window_a] etc.",
+    values:     &[
+        // Aggregate styles
+        flag("OVERLAPPEDWINDOW",    0x00CF0000),
+        flag("POPUPWINDOW",         0x80880000),
+        flag("CAPTION",             0x00C00000),
+
+        flag("OVERLAPPED",          0x00000000),
+        flag("POPUP",               0x80000000),
+        flag("CHILD",               0x40000000),
+        flag("MINIMIZE",            0x20000000),
+        flag("VISIBLE",             0x10000000),
+        flag("DISABLED",            0x08000000),
+        flag("CLIPSIBLINGS",        0x04000000),
+        flag("CLIPCHILDREN",        0x02000000),
+        flag("MAXIMIZE",            0x01000000),
+        flag("BORDER",              0x00800000),
+        flag("DLGFRAME",            0x00400000),
+        flag("VSCROLL",             0x00200000),
+        flag("HSCROLL",             0x00100000),
+        flag("SYSMENU",             0x00080000),
+        flag("THICKFRAME",          0x00040000),
+        flag("GROUP",               0x00020000).overlaps("MINIMIZEBOX"),
+        flag("TABSTOP",             0x00010000).overlaps("MAXIMIZEBOX"),
+        flag("MINIMIZEBOX",         0x00020000),
+        flag("MAXIMIZEBOX",         0x00010000),
+        flag("TILED",               0x00000000).alias("OVERLAPPED"),
+        flag("ICONIC",              0x20000000).alias("MINIMIZE"),
+        flag("SIZEBOX",             0x00040000).alias("THICKFRAME"),
+        flag("TILEDWINDOW",         0x00CF0000).alias("OVERLAPPEDWINDOW"),
+        flag("CHILDWINDOW",         0x40000000).alias("CHILD"),
+    ],
+    invalid:    &[
+        invalid(&["CHILD", "POPUP"], "WS_CHILD cannot be used with the WS_POPUP style"),
+    ],
+};
@@ -1,38 +1,39 @@
-macro_rules! flags { ( $($cpp:path),* $(,)? ) => {
-    pub fn cpp_rust_values() -> impl Iterator<Item = (&'static str, &'static str, u32)> {
-        use winapi::um::winuser::*;
-        vec![$(
-            (stringify!($cpp), stringify!($cpp).strip_prefix("WS_EX_").unwrap(), $cpp),
-        )*].into_iter()
-    }
-}}
+use super::flags::*;
 
-flags! {
-    WS_EX_DLGMODALFRAME,
-    WS_EX_NOPARENTNOTIFY,
-    WS_EX_TOPMOST,
-    WS_EX_ACCEPTFILES,
-    WS_EX_TRANSPARENT,
-    WS_EX_MDICHILD,
-    WS_EX_TOOLWINDOW,
-    WS_EX_WINDOWEDGE,
-    WS_EX_CLIENTEDGE,
-    WS_EX_CONTEXTHELP,
-    WS_EX_RIGHT,
-    WS_EX_LEFT,
-    WS_EX_RTLREADING,
-    WS_EX_LTRREADING,
-    WS_EX_LEFTSCROLLBAR,
-    WS_EX_RIGHTSCROLLBAR,
-    WS_EX_CONTROLPARENT,
-    WS_EX_STATICEDGE,
-    WS_EX_APPWINDOW,
-    WS_EX_OVERLAPPEDWINDOW,
-    WS_EX_PALETTEWINDOW,
-    WS_EX_LAYERED,
-    WS_EX_NOINHERITLAYOUT,
-    WS_EX_NOREDIRECTIONBITMAP,
-    WS_EX_LAYOUTRTL,
-    WS_EX_COMPOSITED,
-    WS_EX_NOACTIVATE,
-}
+pub const FLAGS : Flags = Flags {
+    header:     "winuser",
+    module:     "WS_EX",
+    ty:         "WindowStyleExtended",
+    url:        "https://learn.microsoft.com/en-us/windows/win32/winmsg/extended-window-styles",
+    summary:    "WS_EX_\\* extended window style flags for [create_window_ex_a] etc.",
+    values:     &[
+        flag("DLGMODALFRAME",       0x00000001),
+        flag("NOPARENTNOTIFY",      0x00000004),
+        flag("TOPMOST",             0x00000008),
+        flag("ACCEPTFILES",         0x00000010),
+        flag("TRANSPARENT",         0x00000020).docs(&["Paint after siblings beneath this window. Combined with [LAYERED], mouse input also passes through (see [set_click_through].)"]),
+        flag("MDICHILD",            0x00000040),
+        flag("TOOLWINDOW",          0x00000080),
+        flag("WINDOWEDGE",          0x00000100),
+        flag("CLIENTEDGE",          0x00000200),
+        flag("CONTEXTHELP",         0x00000400),
+        flag("RIGHT",               0x00001000),
+        flag("LEFT",                0x00000000).zero(),
+        flag("RTLREADING",          0x00002000),
+        flag("LTRREADING",          0x00000000).zero(),
+        flag("LEFTSCROLLBAR",       0x00004000),
+        flag("RIGHTSCROLLBAR",      0x00000000).zero(),
+        flag("CONTROLPARENT",       0x00010000),
+        flag("STATICEDGE",          0x00020000),
+        flag("APPWINDOW",           0x00040000),
+        flag("OVERLAPPEDWINDOW",    0x00000300),
+        flag("PALETTEWINDOW",       0x00000188),
+        flag("LAYERED",             0x00080000).docs(&["Invisible until [set_layered_window_attributes] or [update_layered_window] is called."]),
+        flag("NOINHERITLAYOUT",     0x00100000),
+        flag("NOREDIRECTIONBITMAP", 0x00200000),
+        flag("LAYOUTRTL",           0x00400000),
+        flag("COMPOSITED",          0x02000000),
+        flag("NOACTIVATE",          0x08000000),
+    ],
+    invalid:    &[],
+};
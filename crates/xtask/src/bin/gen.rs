@@ -1,16 +1,39 @@
+//! Usage: `cargo run --manifest-path crates/xtask/Cargo.toml --bin gen [-- --check]` (from the repository root)
+//!
+//! With `--check`, nothing is written: files that would've changed are reported, and gen exits with a nonzero status.
+
+use std::io::{self, Write};
+
 fn main() {
-    natvis::gen();
+    let mut out = Output { check: std::env::args().skip(1).any(|arg| arg == "--check"), drift: Vec::new() };
+    flags::gen(&mut out);
+    layout::gen(&mut out);
+    messages::gen(&mut out);
+    #[cfg(windows)] natvis::gen(&mut out);
+    #[cfg(not(windows))] mmrbi::warning!("skipping hwnd.natvis: enum data still relies on winapi, which requires a windows host");
+
+    if !out.drift.is_empty() {
+        for path in out.drift.iter() { mmrbi::error!(at: path, line: 1, "out of date: rerun `xtask gen`"); }
+        std::process::exit(1);
+    }
 }
 
 mod data {
-    pub mod gwl;
-    pub mod gwlp;
-    pub mod idc;
-    pub mod idi;
+    pub mod flags;
+
+    #[cfg(windows)] pub mod gwl;
+    #[cfg(windows)] pub mod gwlp;
+    #[cfg(windows)] pub mod idc;
+    #[cfg(windows)] pub mod idi;
     pub mod ismex;
+    pub mod layout;
+    pub mod load_library;
+    pub mod lr;
+    pub mod lwa;
     pub mod pm;
+    pub mod rdw;
     pub mod smto;
-    pub mod sw;
+    #[cfg(windows)] pub mod sw;
     pub mod swp;
     pub mod wm;
     pub mod wpf;
@@ -18,11 +41,31 @@ mod data {
     pub mod ws;
 }
 
-mod natvis {
-    pub fn gen() {
-        mmrbi::fs::write_if_modified_with("hwnd.natvis", |nv| {
+struct Output {
+    check:  bool,
+    drift:  Vec<String>,
+}
+
+impl Output {
+    /// Write the text output of `io` to `path` (with platform line endings) unless unchanged, or record drift if `--check`ing.
+    fn text(&mut self, path: &str, io: impl FnOnce(&mut Vec<u8>) -> io::Result<()>) {
+        let mut text = Vec::new();
+        io(&mut text).unwrap();
+        if self.check {
+            let mut existing = std::fs::read(path).unwrap_or_default();
+            existing.retain(|b| *b != b'\r');
+            if existing != text { self.drift.push(path.into()) }
+        } else {
+            if let Some(dir) = std::path::Path::new(path).parent() { std::fs::create_dir_all(dir).unwrap() }
+            mmrbi::fs::write_if_modified_with(path, |o| mmrbi::io::EolRewriter(o).write_all(&text)).unwrap();
+        }
+    }
+}
+
+#[cfg(windows)] mod natvis {
+    pub fn gen(out: &mut crate::Output) {
+        out.text("hwnd.natvis", |nv| {
             use std::io::{Write as _};
-            let mut nv = mmrbi::io::EolRewriter(nv);
 
             writeln!(nv, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
             writeln!(nv, r#"<!-- WARNING: this file is auto-generated by xtask gen and may be overwritten -->"#)?;
@@ -47,7 +90,7 @@ mod natvis {
             writeln!(nv, r#"    </Type>"#)?;
 
             writeln!(nv)?;
-            writeln!(nv, r#"    <Type Name="hwnd::um::winuser::enums::WM::WM16">"#)?; // doesn't exist yet
+            writeln!(nv, r#"    <Type Name="hwnd::um::winuser::enums::WM::WM16">"#)?;
             writeln!(nv, r#"        <DisplayString>{{__0,wm}}</DisplayString>"#)?;
             writeln!(nv, r#"    </Type>"#)?;
 
@@ -110,15 +153,9 @@ mod natvis {
 
 
             // flag-style enums
-            for (ty, pre, values) in vec![
-                ("hwnd::um::winuser::flags::ISMEX::Flags",                   "ISMEX",    crate::data::ismex  ::cpp_rust_values().collect::<Vec<_>>()),
-                ("hwnd::um::winuser::flags::PM::PeekMessageFlags",           "PM",       crate::data::pm     ::cpp_rust_values().collect::<Vec<_>>()),
-                ("hwnd::um::winuser::flags::SMTO::SendMessageTimeOutFlags",  "SMTO",     crate::data::smto   ::cpp_rust_values().collect::<Vec<_>>()),
-                ("hwnd::um::winuser::flags::SWP::SetWindowPosFlags",         "SWP",      crate::data::swp    ::cpp_rust_values().collect::<Vec<_>>()),
-                ("hwnd::um::winuser::flags::WPF::WindowPlacementFlags",      "WPF",      crate::data::wpf    ::cpp_rust_values().collect::<Vec<_>>()),
-                ("hwnd::um::winuser::flags::WS::WindowStyle",                "WS",       crate::data::ws     ::cpp_rust_values().collect::<Vec<_>>()),
-                ("hwnd::um::winuser::flags::WS_EX::WindowStyleExtended",     "WS_EX",    crate::data::ws_ex  ::cpp_rust_values().collect::<Vec<_>>()),
-            ].into_iter() {
+            for flags in crate::flags::ALL.iter() {
+                let (ty, pre) = (format!("hwnd::um::{}::flags::{}::{}", flags.header, flags.module, flags.ty), flags.module);
+                let values = flags.cpp_rust_values().collect::<Vec<_>>();
                 writeln!(nv)?;
                 writeln!(nv, r#"    <Type Name="{ty}">"#)?;
                 for (_cpp, rust, value) in values.iter() {
@@ -147,6 +184,387 @@ mod natvis {
 
             writeln!(nv)?;
             writeln!(nv, r#"</AutoVisualizer>"#)
-        }).unwrap();
+        });
+    }
+}
+
+mod messages {
+    use crate::data::wm::*;
+    use std::io::Write as _;
+
+    pub fn gen(out: &mut crate::Output) {
+        validate();
+
+        let mut sorted = MESSAGES.iter().collect::<Vec<_>>();
+        sorted.sort_by_key(|m| m.value); // stable: aliases (e.g. WM::SETTINGCHANGE) follow what Debug shows (e.g. WM::WININICHANGE)
+
+        out.text("src/_messages.rs", |rs| {
+            let w = MESSAGES.iter().map(|m| m.name.len()).max().unwrap_or(0);
+            writeln!(rs, "// WARNING: this file is auto-generated by xtask gen and may be overwritten: edit crates/xtask/src/bin/data/wm.rs instead")?;
+            writeln!(rs, "//! [MessageInfo] for every named system message, sorted by value for [WM32::info].")?;
+            writeln!(rs)?;
+            writeln!(rs, "use crate::*;")?;
+            writeln!(rs)?;
+            writeln!(rs)?;
+            writeln!(rs)?;
+            writeln!(rs, "pub(crate) const MESSAGES : &[MessageInfo] = &[")?;
+            for m in sorted.iter() {
+                let name = format!("WM::{}", m.name);
+                writeln!(rs,
+                    "    MessageInfo {{ wm: {name:<w2$} name: {qname:<w3$} wparam: {wparam:<36} lparam: {lparam:<36} result: {result:<32} send_only: {send_only:<6} marshaled: {marshaled:<5} }},",
+                    name        = format!("{name},"),
+                    qname       = format!("{name:?},"),
+                    wparam      = format!("{},", param(m.wparam)),
+                    lparam      = format!("{},", param(m.lparam)),
+                    result      = format!("{},", result(m.result)),
+                    send_only   = format!("{},", m.send_only()),
+                    marshaled   = m.marshaled.unwrap_or(true),
+                    w2          = w + 5,
+                    w3          = w + 7,
+                )?;
+            }
+            writeln!(rs, "];")?;
+            writeln!(rs)?;
+            writeln!(rs, "const _ : () = {{ // crates/xtask/src/bin/data/wm.rs must agree with src/um/winuser/enums/WM.rs")?;
+            for m in MESSAGES.iter() {
+                writeln!(rs, "    assert!(WM::{name:<w$}.to_u32() == 0x{value:04X});", name = m.name, value = m.value)?;
+            }
+            writeln!(rs, "}};")?;
+            Ok(())
+        });
+    }
+
+    fn param(p: Param) -> String {
+        match p {
+            Param::Unused   => "MessageParam::Unused".into(),
+            Param::Value    => "MessageParam::Value".into(),
+            Param::Opaque   => "MessageParam::Opaque".into(),
+            Param::Ptr("")  => "MessageParam::Ptr(None)".into(),
+            Param::Ptr(ty)  => format!("MessageParam::Ptr(Some({ty:?}))"),
+        }
+    }
+
+    fn result(r: Ret) -> &'static str {
+        match r {
+            Ret::Ignored    => "MessageResult::Ignored",
+            Ret::Zero       => "MessageResult::ZeroIfProcessed",
+            Ret::Bool       => "MessageResult::Bool",
+            Ret::Value      => "MessageResult::Value",
+        }
+    }
+}
+
+mod layout {
+    use crate::data::layout::*;
+
+    /// Sanity check the hand-written tables against C layout rules before trusting them
+    fn check_tables() {
+        for s in STRUCTS.iter() {
+            assert_eq!(s.expected.len(), ARCHES.len(), "{}: expected one layout per architecture", s.rust);
+            for ((_, arch, ptr_size), (expected_arch, size, align, offsets)) in ARCHES.iter().zip(s.expected.iter()) {
+                assert_eq!(arch, expected_arch, "{}: architectures out of order", s.rust);
+                assert_eq!(c_layout(s.fields, *ptr_size), (*size, *align, offsets.to_vec()), "{} ({arch}): expected layout doesn't follow C layout rules", s.rust);
+            }
+        }
+    }
+
+    pub fn gen(out: &mut crate::Output) {
+        check_tables();
+
+        out.text("src/_layout.rs", |rs| {
+            use std::io::{Write as _};
+
+            writeln!(rs, "// WARNING: this file is auto-generated by xtask gen and may be overwritten")?;
+            writeln!(rs, "//! Compile time checks of `#[repr(C)]` structure layouts against the tables in `crates/xtask/src/bin/data/layout.rs`.")?;
+            writeln!(rs, "//!")?;
+            writeln!(rs, "//! These don't rely on winapi's own structures, so a regression on any architecture can be caught from any host via e.g.:")?;
+            writeln!(rs, "//! `cargo check --all-targets --target aarch64-pc-windows-msvc`")?;
+            writeln!(rs, "//!")?;
+            writeln!(rs, "//! On non-Windows hosts, where these asserts are `cfg`ed out, `cargo test --manifest-path crates/xtask/Cargo.toml`")?;
+            writeln!(rs, "//! still checks the structures' field types in `src/` against the same tables.")?;
+
+            for (target_arch, arch, _) in ARCHES.iter() {
+                writeln!(rs)?;
+                writeln!(rs, "#[cfg(all(windows, target_arch = {target_arch:?}))] const _ : () = {{")?;
+                writeln!(rs, "    use crate::*;")?;
+                writeln!(rs, "    use core::mem::{{align_of, offset_of, size_of}};")?;
+                for s in STRUCTS.iter() {
+                    let (_, size, align, offsets) = s.expected.iter().find(|e| e.0 == *arch).unwrap();
+                    let name = s.rust.split('<').next().unwrap();
+                    let ty = s.rust;
+                    writeln!(rs)?;
+                    writeln!(rs, "    assert!(size_of ::<{ty}>() == {size}, \"size_of::<{name}>() != {size} ({arch})\");")?;
+                    writeln!(rs, "    assert!(align_of::<{ty}>() == {align}, \"align_of::<{name}>() != {align} ({arch})\");")?;
+                    for ((field, _), offset) in s.fields.iter().zip(offsets.iter()) {
+                        writeln!(rs, "    assert!(offset_of!({ty}, {field}) == {offset}, \"offset_of!({name}, {field}) != {offset} ({arch})\");")?;
+                    }
+                }
+                writeln!(rs, "}};")?;
+            }
+            Ok(())
+        });
+    }
+
+    #[test] fn tables() { check_tables() }
+
+    /// hwnd's own struct definitions should have the fields (and field types) the tables describe.
+    ///
+    /// hwnd only builds for Windows, so rather than `offset_of!` (see `src/_layout.rs`), this reads the definitions from `src/`,
+    /// resolving newtypes, aliases, and re-exports down to the primitives [c_layout] understands.
+    #[test] fn crate_structs_match_tables() {
+        let sources = rust_sources(&std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../src"));
+        for s in STRUCTS.iter() {
+            let name = s.rust.split('<').next().unwrap();
+            let expected = s.fields.iter().map(|(field, ty)| format!("{field}: {}", shape(*ty))).collect::<Vec<_>>();
+            let actual = struct_fields(&sources, name).unwrap_or_else(|| panic!("{name}: no `pub struct {name} {{ ... }}` found in src/"));
+            let actual = actual.iter().map(|(field, ty)| format!("{field}: {}", resolve(&sources, ty))).collect::<Vec<_>>();
+            assert_eq!(expected, actual, "{name}: src/ disagrees with crates/xtask/src/bin/data/layout.rs");
+        }
+    }
+
+    #[cfg(test)] fn shape(ty: Ty) -> String {
+        match ty {
+            Ty::U32             => "u32".into(),
+            Ty::I32             => "i32".into(),
+            Ty::Ptr             => "ptr".into(),
+            Ty::Struct(fields)  => format!("{{ {} }}", fields.iter().map(|(f, ty)| format!("{f}: {}", shape(*ty))).collect::<Vec<_>>().join(", ")),
+        }
+    }
+
+    #[cfg(test)] fn rust_sources(dir: &std::path::Path) -> Vec<String> {
+        let mut sources = Vec::new();
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() { sources.extend(rust_sources(&path)) }
+            else if path.extension().is_some_and(|ext| ext == "rs") { sources.push(std::fs::read_to_string(&path).unwrap()) }
+        }
+        sources
+    }
+
+    /// Find `name`'s definition in `sources`, and return the text after `pub struct {name}` / `pub type {name}` / `as {name};`
+    #[cfg(test)] fn definition<'s>(sources: &'s [String], name: &str) -> Option<(&'static str, &'s str)> {
+        for source in sources.iter() {
+            for line in source.lines() {
+                let (_attrs, line) = split_attrs(line);
+                let line = line.strip_prefix("pub ").unwrap_or(line);
+                for kind in ["struct", "type"] {
+                    if let Some(rest) = line.strip_prefix(kind).and_then(|r| r.strip_prefix(' ')).and_then(|r| r.strip_prefix(name)) {
+                        if rest.starts_with(['<', '(', ' ', '{', '=']) { return Some((kind, &source[rest.as_ptr() as usize - source.as_ptr() as usize ..])) }
+                    }
+                }
+                if let Some(path) = line.strip_prefix("use ").and_then(|r| r.strip_suffix(&format!(" as {name};"))) { return Some(("use", path)) }
+            }
+        }
+        None
+    }
+
+    /// The `(name, type)` of every field of `pub struct {name} { ... }` that exists on Windows
+    #[cfg(test)] fn struct_fields(sources: &[String], name: &str) -> Option<Vec<(String, String)>> {
+        let ("struct", rest) = definition(sources, name)? else { return None };
+        let body = &rest[rest.find('{')? + 1 ..];
+        let body = &body[.. body.find("\n}")?];
+        let mut fields = Vec::new();
+        let mut attrs = String::new();
+        for line in body.lines() {
+            let (line_attrs, field) = split_attrs(line.split("//").next().unwrap());
+            attrs.push_str(line_attrs);
+            if field.is_empty() { continue } // blank, comment, or attributes for the next line
+            let attrs = std::mem::take(&mut attrs);
+            assert!(!attrs.contains("cfg(") || attrs.contains("macos"), "{name}: can't tell if {line:?} applies to windows");
+            if attrs.contains("macos") { continue }
+            let field = field.strip_prefix("pub ").unwrap_or(field);
+            let (field, ty) = field.split_once(':').unwrap_or_else(|| panic!("{name}: couldn't parse field {line:?}"));
+            fields.push((field.trim().to_string(), ty.trim().trim_end_matches(',').trim().to_string()));
+        }
+        Some(fields)
+    }
+
+    /// Split leading `#[...]` attributes from the rest of `line`
+    #[cfg(test)] fn split_attrs(line: &str) -> (&str, &str) {
+        let line = line.trim();
+        let mut rest = line;
+        while let Some(r) = rest.strip_prefix("#[") { rest = r.split_once(']').map_or("", |(_, r)| r).trim_start(); }
+        (&line[.. line.len() - rest.len()], rest)
+    }
+
+    /// Resolve a rust type to the same shape as [shape] (`"u32"`, `"i32"`, `"ptr"`, or `"{ field: shape, ... }"`)
+    #[cfg(test)] fn resolve(sources: &[String], ty: &str) -> String {
+        let ty = ty.trim().strip_prefix("pub(crate) ").unwrap_or(ty.trim());
+        let (path, generics) = match ty.find('<') { Some(i) => (&ty[.. i], &ty[i + 1 .. ty.rfind('>').unwrap()]), None => (ty, "") };
+        let name = path.rsplit("::").next().unwrap();
+        match name {
+            "u32"                                           => return "u32".into(),
+            "i32"                                           => return "i32".into(),
+            "usize" | "isize" | "WPARAM" | "LPARAM"         => return "ptr".into(),
+            "HWND" | "HBRUSH" | "HICON" | "HCURSOR"         => return "ptr".into(), // winapi / hwnd0 handles
+            "Option" => {
+                let inner = generics.split(',').next().unwrap().trim();
+                assert!(non_null(sources, inner), "Option<{inner}> isn't pointer sized");
+                return "ptr".into();
+            },
+            _ => {},
+        }
+        if ty.starts_with("unsafe extern ") || ty.starts_with("extern ") { return "ptr".into() }
+        match definition(sources, name) {
+            Some(("struct", rest)) if rest.trim_start_matches(|c| c != '(' && c != '{').starts_with('(') => {
+                let tuple = rest.split_once('(').unwrap().1.trim_start();
+                let tuple = tuple.strip_prefix("pub(crate) ").or_else(|| tuple.strip_prefix("pub ")).unwrap_or(tuple);
+                resolve(sources, tuple.split([',', ')']).next().unwrap()) // newtype: PhantomData etc. are zero sized
+            },
+            Some(("struct", _)) => {
+                let fields = struct_fields(sources, name).unwrap();
+                format!("{{ {} }}", fields.iter().map(|(f, ty)| format!("{f}: {}", resolve(sources, ty))).collect::<Vec<_>>().join(", "))
+            },
+            Some(("type", rest)) => resolve(sources, rest.split_once('=').unwrap().1.split(';').next().unwrap()),
+            Some((_use, path)) => resolve(sources, path),
+            None => panic!("{ty}: unknown type"),
+        }
+    }
+
+    /// `true` if `Option<ty>` is pointer sized (function pointers, references, and [core::ptr::NonNull] based types)
+    #[cfg(test)] fn non_null(sources: &[String], ty: &str) -> bool {
+        if ty.starts_with('&') || ty.starts_with("unsafe extern ") || ty.starts_with("extern ") { return true }
+        let name = ty.split('<').next().unwrap().rsplit("::").next().unwrap();
+        match (name, definition(sources, name)) {
+            ("CStrNonNull", _)                  => true, // abistr: a NonNull<U>
+            (_, Some(("type", rest)))           => non_null(sources, rest.split_once('=').unwrap().1.split(';').next().unwrap().trim()),
+            _                                   => false,
+        }
+    }
+
+    /// [c_layout] should agree with rustc's own `#[repr(C)]` layout, at least for the host's pointer size.
+    #[test] fn c_layout_matches_rustc() {
+        use std::mem::{align_of, offset_of, size_of};
+
+        #[allow(dead_code)] #[repr(C)] struct Point { x: i32, y: i32 }
+        #[allow(dead_code)] #[repr(C)] struct Msg { hwnd: usize, message: u32, wparam: usize, lparam: isize, time: u32, pt: Point }
+        #[allow(dead_code)] #[repr(C)] struct WndClassEx { size: u32, style: u32, wnd_proc: usize, cls_extra: i32, wnd_extra: i32, hinstance: usize, hicon: usize, hcursor: usize, background: usize, menu_name: usize, class_name: usize, hicon_sm: usize }
+
+        let fields = |rust: &str| STRUCTS.iter().find(|s| s.rust == rust).unwrap().fields;
+        let ptr = size_of::<usize>();
+
+        assert_eq!(c_layout(fields("Msg"), ptr), (size_of::<Msg>(), align_of::<Msg>(), vec![
+            offset_of!(Msg, hwnd), offset_of!(Msg, message), offset_of!(Msg, wparam), offset_of!(Msg, lparam), offset_of!(Msg, time), offset_of!(Msg, pt),
+        ]));
+        assert_eq!(c_layout(fields("WndClassExW<'static>"), ptr), (size_of::<WndClassEx>(), align_of::<WndClassEx>(), vec![
+            offset_of!(WndClassEx, size), offset_of!(WndClassEx, style), offset_of!(WndClassEx, wnd_proc), offset_of!(WndClassEx, cls_extra),
+            offset_of!(WndClassEx, wnd_extra), offset_of!(WndClassEx, hinstance), offset_of!(WndClassEx, hicon), offset_of!(WndClassEx, hcursor),
+            offset_of!(WndClassEx, background), offset_of!(WndClassEx, menu_name), offset_of!(WndClassEx, class_name), offset_of!(WndClassEx, hicon_sm),
+        ]));
+    }
+}
+
+mod flags {
+    use crate::data::flags::*;
+    use std::io::Write as _;
+
+    pub const ALL : &[&Flags] = &[
+        &crate::data::ismex::FLAGS,
+        &crate::data::load_library::FLAGS,
+        &crate::data::lr::FLAGS,
+        &crate::data::lwa::FLAGS,
+        &crate::data::pm::FLAGS,
+        &crate::data::rdw::FLAGS,
+        &crate::data::smto::FLAGS,
+        &crate::data::swp::FLAGS,
+        &crate::data::wpf::FLAGS,
+        &crate::data::ws::FLAGS,
+        &crate::data::ws_ex::FLAGS,
+    ];
+
+    pub fn gen(out: &mut crate::Output) {
+        for flags in ALL.iter() {
+            flags.validate();
+            out.text(&format!("src/um/{}/flags/{}.rs", flags.header, flags.module), |rs| module(rs, flags));
+        }
+    }
+
+    fn module(rs: &mut Vec<u8>, flags: &Flags) -> std::io::Result<()> {
+        let Flags { header, module, ty, url, summary, values, invalid } = *flags;
+        let name_width = values.iter().map(|f| f.name.len()).max().unwrap_or(0);
+        let data = format!("crates/xtask/src/bin/data/{}.rs", module.to_ascii_lowercase());
+
+        writeln!(rs, "// WARNING: this file is auto-generated by xtask gen and may be overwritten: edit {data} instead")?;
+        writeln!(rs, "//! \\[[learn.microsoft.com]({url})\\]")?;
+        writeln!(rs, "//! {summary}")?;
+        writeln!(rs)?;
+        writeln!(rs, "#![allow(non_snake_case)]")?;
+        writeln!(rs)?;
+        writeln!(rs, "use crate::*;")?;
+        writeln!(rs, "use bytemuck::*;")?;
+        writeln!(rs, "use winapi::um::{header}::*;")?;
+        writeln!(rs)?;
+        writeln!(rs)?;
+        writeln!(rs)?;
+        writeln!(rs, "/// \\[[learn.microsoft.com]({url})\\]")?;
+        writeln!(rs, "/// {summary}")?;
+        writeln!(rs, "#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Zeroable)] #[repr(transparent)] pub struct {ty}(u32);")?;
+        writeln!(rs, "impl_ops_for_flag!({ty});")?;
+        writeln!(rs)?;
+        writeln!(rs, "impl From<{ty}> for u32 {{ fn from(cmd: {ty}) -> Self {{ cmd.0 }} }}")?;
+        writeln!(rs, "impl From<u32> for {ty} {{ fn from(cmd: {u32:<w$}) -> Self {{ Self(cmd) }} }}", u32 = "u32", w = ty.len())?;
+        writeln!(rs)?;
+        writeln!(rs, "impl_debug_for_flags! {{")?;
+        writeln!(rs, "    {ty} => {{")?;
+        for f in values.iter() {
+            let path = format!("{module}::{},", f.name);
+            let w = module.len() + name_width + 4; // width of commented out paths: comments are aligned
+            match f.debug {
+                Debug::Show => match composition(flags, f) {
+                    Some(parts) => writeln!(rs, "        {path:<w2$}// {parts}", w2 = w + 2)?,
+                    None        => writeln!(rs, "        {path}")?,
+                },
+                Debug::Alias(of)        => writeln!(rs, "        //{path:<w$}// alias for {module}::{of}")?,
+                Debug::Overlaps(with)   => writeln!(rs, "        //{path:<w$}// overlaps with {module}::{with}")?,
+                Debug::Zero             => writeln!(rs, "        //{path:<w$}// 0 / default")?,
+            }
+        }
+        writeln!(rs, "    }}")?;
+        writeln!(rs, "}}")?;
+        writeln!(rs)?;
+        writeln!(rs, "impl_from_str_for_flags! {{")?;
+        writeln!(rs, "    {ty} => {{")?;
+        for f in values.iter() { writeln!(rs, "        {module}::{},", f.name)?; }
+        writeln!(rs, "    }}")?;
+        writeln!(rs, "}}")?;
+        writeln!(rs)?;
+        writeln!(rs, "impl_validate_for_flags! {{")?;
+        writeln!(rs, "    {ty} => {{")?;
+        for i in invalid.iter() {
+            let all = i.all.iter().map(|n| format!("{module}::{n}")).collect::<Vec<_>>().join(" | ");
+            writeln!(rs, "        {all} => {:?},", i.reason)?;
+        }
+        writeln!(rs, "    }}")?;
+        writeln!(rs, "}}")?;
+        writeln!(rs)?;
+        writeln!(rs)?;
+        writeln!(rs)?;
+        let mut prev_docs = false;
+        for (i, f) in values.iter().enumerate() {
+            let docs = !f.docs.is_empty();
+            if i != 0 && (docs || prev_docs) { writeln!(rs)?; }
+            for line in f.docs.iter() { writeln!(rs, "/// {line}")?; }
+            writeln!(rs, "pub const {name:<name_width$} : {ty} = {ty}({module}_{name});", name = f.name)?;
+            prev_docs = docs;
+        }
+        writeln!(rs)?;
+        writeln!(rs, "const _ : () = {{ // {data} must agree with winapi")?;
+        for (cpp, _rust, value) in flags.cpp_rust_values() {
+            writeln!(rs, "    assert!({cpp:<w$} == 0x{value:08X});", w = module.len() + 1 + name_width)?;
+        }
+        writeln!(rs, "}};")?;
+        Ok(())
+    }
+
+    /// e.g. `"BORDER | DLGFRAME"` for `WS::CAPTION`, if `f` is entirely composed of multiple other flags
+    fn composition(flags: &Flags, f: &Flag) -> Option<String> {
+        let mut remaining = f.value;
+        let mut parts = Vec::new();
+        for part in flags.values.iter() {
+            if part.name == f.name || part.debug != Debug::Show || part.value == 0 || part.value & remaining != part.value { continue }
+            remaining &= !part.value;
+            parts.push(part.name);
+        }
+        (remaining == 0 && parts.len() >= 2).then(|| parts.join(" | "))
     }
 }
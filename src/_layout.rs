@@ -0,0 +1,281 @@
+// WARNING: this file is auto-generated by xtask gen and may be overwritten
+//! Compile time checks of `#[repr(C)]` structure layouts against the tables in `crates/xtask/src/bin/data/layout.rs`.
+//!
+//! These don't rely on winapi's own structures, so a regression on any architecture can be caught from any host via e.g.:
+//! `cargo check --all-targets --target aarch64-pc-windows-msvc`
+//!
+//! On non-Windows hosts, where these asserts are `cfg`ed out, `cargo test --manifest-path crates/xtask/Cargo.toml`
+//! still checks the structures' field types in `src/` against the same tables.
+
+#[cfg(all(windows, target_arch = "x86"))] const _ : () = {
+    use crate::*;
+    use core::mem::{align_of, offset_of, size_of};
+
+    assert!(size_of ::<Point>() == 8, "size_of::<Point>() != 8 (i686)");
+    assert!(align_of::<Point>() == 4, "align_of::<Point>() != 4 (i686)");
+    assert!(offset_of!(Point, x) == 0, "offset_of!(Point, x) != 0 (i686)");
+    assert!(offset_of!(Point, y) == 4, "offset_of!(Point, y) != 4 (i686)");
+
+    assert!(size_of ::<Rect>() == 16, "size_of::<Rect>() != 16 (i686)");
+    assert!(align_of::<Rect>() == 4, "align_of::<Rect>() != 4 (i686)");
+    assert!(offset_of!(Rect, left) == 0, "offset_of!(Rect, left) != 0 (i686)");
+    assert!(offset_of!(Rect, top) == 4, "offset_of!(Rect, top) != 4 (i686)");
+    assert!(offset_of!(Rect, right) == 8, "offset_of!(Rect, right) != 8 (i686)");
+    assert!(offset_of!(Rect, bottom) == 12, "offset_of!(Rect, bottom) != 12 (i686)");
+
+    assert!(size_of ::<Msg>() == 28, "size_of::<Msg>() != 28 (i686)");
+    assert!(align_of::<Msg>() == 4, "align_of::<Msg>() != 4 (i686)");
+    assert!(offset_of!(Msg, hwnd) == 0, "offset_of!(Msg, hwnd) != 0 (i686)");
+    assert!(offset_of!(Msg, message) == 4, "offset_of!(Msg, message) != 4 (i686)");
+    assert!(offset_of!(Msg, wparam) == 8, "offset_of!(Msg, wparam) != 8 (i686)");
+    assert!(offset_of!(Msg, lparam) == 12, "offset_of!(Msg, lparam) != 12 (i686)");
+    assert!(offset_of!(Msg, time) == 16, "offset_of!(Msg, time) != 16 (i686)");
+    assert!(offset_of!(Msg, pt) == 20, "offset_of!(Msg, pt) != 20 (i686)");
+
+    assert!(size_of ::<WindowPlacement>() == 44, "size_of::<WindowPlacement>() != 44 (i686)");
+    assert!(align_of::<WindowPlacement>() == 4, "align_of::<WindowPlacement>() != 4 (i686)");
+    assert!(offset_of!(WindowPlacement, length) == 0, "offset_of!(WindowPlacement, length) != 0 (i686)");
+    assert!(offset_of!(WindowPlacement, flags) == 4, "offset_of!(WindowPlacement, flags) != 4 (i686)");
+    assert!(offset_of!(WindowPlacement, show_cmd) == 8, "offset_of!(WindowPlacement, show_cmd) != 8 (i686)");
+    assert!(offset_of!(WindowPlacement, min_position) == 12, "offset_of!(WindowPlacement, min_position) != 12 (i686)");
+    assert!(offset_of!(WindowPlacement, max_position) == 20, "offset_of!(WindowPlacement, max_position) != 20 (i686)");
+    assert!(offset_of!(WindowPlacement, normal_position) == 28, "offset_of!(WindowPlacement, normal_position) != 28 (i686)");
+
+    assert!(size_of ::<WndClassA<'static>>() == 40, "size_of::<WndClassA>() != 40 (i686)");
+    assert!(align_of::<WndClassA<'static>>() == 4, "align_of::<WndClassA>() != 4 (i686)");
+    assert!(offset_of!(WndClassA<'static>, style) == 0, "offset_of!(WndClassA, style) != 0 (i686)");
+    assert!(offset_of!(WndClassA<'static>, wnd_proc) == 4, "offset_of!(WndClassA, wnd_proc) != 4 (i686)");
+    assert!(offset_of!(WndClassA<'static>, cls_extra) == 8, "offset_of!(WndClassA, cls_extra) != 8 (i686)");
+    assert!(offset_of!(WndClassA<'static>, wnd_extra) == 12, "offset_of!(WndClassA, wnd_extra) != 12 (i686)");
+    assert!(offset_of!(WndClassA<'static>, hinstance) == 16, "offset_of!(WndClassA, hinstance) != 16 (i686)");
+    assert!(offset_of!(WndClassA<'static>, hicon) == 20, "offset_of!(WndClassA, hicon) != 20 (i686)");
+    assert!(offset_of!(WndClassA<'static>, hcursor) == 24, "offset_of!(WndClassA, hcursor) != 24 (i686)");
+    assert!(offset_of!(WndClassA<'static>, background) == 28, "offset_of!(WndClassA, background) != 28 (i686)");
+    assert!(offset_of!(WndClassA<'static>, menu_name) == 32, "offset_of!(WndClassA, menu_name) != 32 (i686)");
+    assert!(offset_of!(WndClassA<'static>, class_name) == 36, "offset_of!(WndClassA, class_name) != 36 (i686)");
+
+    assert!(size_of ::<WndClassW<'static>>() == 40, "size_of::<WndClassW>() != 40 (i686)");
+    assert!(align_of::<WndClassW<'static>>() == 4, "align_of::<WndClassW>() != 4 (i686)");
+    assert!(offset_of!(WndClassW<'static>, style) == 0, "offset_of!(WndClassW, style) != 0 (i686)");
+    assert!(offset_of!(WndClassW<'static>, wnd_proc) == 4, "offset_of!(WndClassW, wnd_proc) != 4 (i686)");
+    assert!(offset_of!(WndClassW<'static>, cls_extra) == 8, "offset_of!(WndClassW, cls_extra) != 8 (i686)");
+    assert!(offset_of!(WndClassW<'static>, wnd_extra) == 12, "offset_of!(WndClassW, wnd_extra) != 12 (i686)");
+    assert!(offset_of!(WndClassW<'static>, hinstance) == 16, "offset_of!(WndClassW, hinstance) != 16 (i686)");
+    assert!(offset_of!(WndClassW<'static>, hicon) == 20, "offset_of!(WndClassW, hicon) != 20 (i686)");
+    assert!(offset_of!(WndClassW<'static>, hcursor) == 24, "offset_of!(WndClassW, hcursor) != 24 (i686)");
+    assert!(offset_of!(WndClassW<'static>, background) == 28, "offset_of!(WndClassW, background) != 28 (i686)");
+    assert!(offset_of!(WndClassW<'static>, menu_name) == 32, "offset_of!(WndClassW, menu_name) != 32 (i686)");
+    assert!(offset_of!(WndClassW<'static>, class_name) == 36, "offset_of!(WndClassW, class_name) != 36 (i686)");
+
+    assert!(size_of ::<WndClassExA<'static>>() == 48, "size_of::<WndClassExA>() != 48 (i686)");
+    assert!(align_of::<WndClassExA<'static>>() == 4, "align_of::<WndClassExA>() != 4 (i686)");
+    assert!(offset_of!(WndClassExA<'static>, size) == 0, "offset_of!(WndClassExA, size) != 0 (i686)");
+    assert!(offset_of!(WndClassExA<'static>, style) == 4, "offset_of!(WndClassExA, style) != 4 (i686)");
+    assert!(offset_of!(WndClassExA<'static>, wnd_proc) == 8, "offset_of!(WndClassExA, wnd_proc) != 8 (i686)");
+    assert!(offset_of!(WndClassExA<'static>, cls_extra) == 12, "offset_of!(WndClassExA, cls_extra) != 12 (i686)");
+    assert!(offset_of!(WndClassExA<'static>, wnd_extra) == 16, "offset_of!(WndClassExA, wnd_extra) != 16 (i686)");
+    assert!(offset_of!(WndClassExA<'static>, hinstance) == 20, "offset_of!(WndClassExA, hinstance) != 20 (i686)");
+    assert!(offset_of!(WndClassExA<'static>, hicon) == 24, "offset_of!(WndClassExA, hicon) != 24 (i686)");
+    assert!(offset_of!(WndClassExA<'static>, hcursor) == 28, "offset_of!(WndClassExA, hcursor) != 28 (i686)");
+    assert!(offset_of!(WndClassExA<'static>, background) == 32, "offset_of!(WndClassExA, background) != 32 (i686)");
+    assert!(offset_of!(WndClassExA<'static>, menu_name) == 36, "offset_of!(WndClassExA, menu_name) != 36 (i686)");
+    assert!(offset_of!(WndClassExA<'static>, class_name) == 40, "offset_of!(WndClassExA, class_name) != 40 (i686)");
+    assert!(offset_of!(WndClassExA<'static>, hicon_sm) == 44, "offset_of!(WndClassExA, hicon_sm) != 44 (i686)");
+
+    assert!(size_of ::<WndClassExW<'static>>() == 48, "size_of::<WndClassExW>() != 48 (i686)");
+    assert!(align_of::<WndClassExW<'static>>() == 4, "align_of::<WndClassExW>() != 4 (i686)");
+    assert!(offset_of!(WndClassExW<'static>, size) == 0, "offset_of!(WndClassExW, size) != 0 (i686)");
+    assert!(offset_of!(WndClassExW<'static>, style) == 4, "offset_of!(WndClassExW, style) != 4 (i686)");
+    assert!(offset_of!(WndClassExW<'static>, wnd_proc) == 8, "offset_of!(WndClassExW, wnd_proc) != 8 (i686)");
+    assert!(offset_of!(WndClassExW<'static>, cls_extra) == 12, "offset_of!(WndClassExW, cls_extra) != 12 (i686)");
+    assert!(offset_of!(WndClassExW<'static>, wnd_extra) == 16, "offset_of!(WndClassExW, wnd_extra) != 16 (i686)");
+    assert!(offset_of!(WndClassExW<'static>, hinstance) == 20, "offset_of!(WndClassExW, hinstance) != 20 (i686)");
+    assert!(offset_of!(WndClassExW<'static>, hicon) == 24, "offset_of!(WndClassExW, hicon) != 24 (i686)");
+    assert!(offset_of!(WndClassExW<'static>, hcursor) == 28, "offset_of!(WndClassExW, hcursor) != 28 (i686)");
+    assert!(offset_of!(WndClassExW<'static>, background) == 32, "offset_of!(WndClassExW, background) != 32 (i686)");
+    assert!(offset_of!(WndClassExW<'static>, menu_name) == 36, "offset_of!(WndClassExW, menu_name) != 36 (i686)");
+    assert!(offset_of!(WndClassExW<'static>, class_name) == 40, "offset_of!(WndClassExW, class_name) != 40 (i686)");
+    assert!(offset_of!(WndClassExW<'static>, hicon_sm) == 44, "offset_of!(WndClassExW, hicon_sm) != 44 (i686)");
+};
+
+#[cfg(all(windows, target_arch = "x86_64"))] const _ : () = {
+    use crate::*;
+    use core::mem::{align_of, offset_of, size_of};
+
+    assert!(size_of ::<Point>() == 8, "size_of::<Point>() != 8 (x86_64)");
+    assert!(align_of::<Point>() == 4, "align_of::<Point>() != 4 (x86_64)");
+    assert!(offset_of!(Point, x) == 0, "offset_of!(Point, x) != 0 (x86_64)");
+    assert!(offset_of!(Point, y) == 4, "offset_of!(Point, y) != 4 (x86_64)");
+
+    assert!(size_of ::<Rect>() == 16, "size_of::<Rect>() != 16 (x86_64)");
+    assert!(align_of::<Rect>() == 4, "align_of::<Rect>() != 4 (x86_64)");
+    assert!(offset_of!(Rect, left) == 0, "offset_of!(Rect, left) != 0 (x86_64)");
+    assert!(offset_of!(Rect, top) == 4, "offset_of!(Rect, top) != 4 (x86_64)");
+    assert!(offset_of!(Rect, right) == 8, "offset_of!(Rect, right) != 8 (x86_64)");
+    assert!(offset_of!(Rect, bottom) == 12, "offset_of!(Rect, bottom) != 12 (x86_64)");
+
+    assert!(size_of ::<Msg>() == 48, "size_of::<Msg>() != 48 (x86_64)");
+    assert!(align_of::<Msg>() == 8, "align_of::<Msg>() != 8 (x86_64)");
+    assert!(offset_of!(Msg, hwnd) == 0, "offset_of!(Msg, hwnd) != 0 (x86_64)");
+    assert!(offset_of!(Msg, message) == 8, "offset_of!(Msg, message) != 8 (x86_64)");
+    assert!(offset_of!(Msg, wparam) == 16, "offset_of!(Msg, wparam) != 16 (x86_64)");
+    assert!(offset_of!(Msg, lparam) == 24, "offset_of!(Msg, lparam) != 24 (x86_64)");
+    assert!(offset_of!(Msg, time) == 32, "offset_of!(Msg, time) != 32 (x86_64)");
+    assert!(offset_of!(Msg, pt) == 36, "offset_of!(Msg, pt) != 36 (x86_64)");
+
+    assert!(size_of ::<WindowPlacement>() == 44, "size_of::<WindowPlacement>() != 44 (x86_64)");
+    assert!(align_of::<WindowPlacement>() == 4, "align_of::<WindowPlacement>() != 4 (x86_64)");
+    assert!(offset_of!(WindowPlacement, length) == 0, "offset_of!(WindowPlacement, length) != 0 (x86_64)");
+    assert!(offset_of!(WindowPlacement, flags) == 4, "offset_of!(WindowPlacement, flags) != 4 (x86_64)");
+    assert!(offset_of!(WindowPlacement, show_cmd) == 8, "offset_of!(WindowPlacement, show_cmd) != 8 (x86_64)");
+    assert!(offset_of!(WindowPlacement, min_position) == 12, "offset_of!(WindowPlacement, min_position) != 12 (x86_64)");
+    assert!(offset_of!(WindowPlacement, max_position) == 20, "offset_of!(WindowPlacement, max_position) != 20 (x86_64)");
+    assert!(offset_of!(WindowPlacement, normal_position) == 28, "offset_of!(WindowPlacement, normal_position) != 28 (x86_64)");
+
+    assert!(size_of ::<WndClassA<'static>>() == 72, "size_of::<WndClassA>() != 72 (x86_64)");
+    assert!(align_of::<WndClassA<'static>>() == 8, "align_of::<WndClassA>() != 8 (x86_64)");
+    assert!(offset_of!(WndClassA<'static>, style) == 0, "offset_of!(WndClassA, style) != 0 (x86_64)");
+    assert!(offset_of!(WndClassA<'static>, wnd_proc) == 8, "offset_of!(WndClassA, wnd_proc) != 8 (x86_64)");
+    assert!(offset_of!(WndClassA<'static>, cls_extra) == 16, "offset_of!(WndClassA, cls_extra) != 16 (x86_64)");
+    assert!(offset_of!(WndClassA<'static>, wnd_extra) == 20, "offset_of!(WndClassA, wnd_extra) != 20 (x86_64)");
+    assert!(offset_of!(WndClassA<'static>, hinstance) == 24, "offset_of!(WndClassA, hinstance) != 24 (x86_64)");
+    assert!(offset_of!(WndClassA<'static>, hicon) == 32, "offset_of!(WndClassA, hicon) != 32 (x86_64)");
+    assert!(offset_of!(WndClassA<'static>, hcursor) == 40, "offset_of!(WndClassA, hcursor) != 40 (x86_64)");
+    assert!(offset_of!(WndClassA<'static>, background) == 48, "offset_of!(WndClassA, background) != 48 (x86_64)");
+    assert!(offset_of!(WndClassA<'static>, menu_name) == 56, "offset_of!(WndClassA, menu_name) != 56 (x86_64)");
+    assert!(offset_of!(WndClassA<'static>, class_name) == 64, "offset_of!(WndClassA, class_name) != 64 (x86_64)");
+
+    assert!(size_of ::<WndClassW<'static>>() == 72, "size_of::<WndClassW>() != 72 (x86_64)");
+    assert!(align_of::<WndClassW<'static>>() == 8, "align_of::<WndClassW>() != 8 (x86_64)");
+    assert!(offset_of!(WndClassW<'static>, style) == 0, "offset_of!(WndClassW, style) != 0 (x86_64)");
+    assert!(offset_of!(WndClassW<'static>, wnd_proc) == 8, "offset_of!(WndClassW, wnd_proc) != 8 (x86_64)");
+    assert!(offset_of!(WndClassW<'static>, cls_extra) == 16, "offset_of!(WndClassW, cls_extra) != 16 (x86_64)");
+    assert!(offset_of!(WndClassW<'static>, wnd_extra) == 20, "offset_of!(WndClassW, wnd_extra) != 20 (x86_64)");
+    assert!(offset_of!(WndClassW<'static>, hinstance) == 24, "offset_of!(WndClassW, hinstance) != 24 (x86_64)");
+    assert!(offset_of!(WndClassW<'static>, hicon) == 32, "offset_of!(WndClassW, hicon) != 32 (x86_64)");
+    assert!(offset_of!(WndClassW<'static>, hcursor) == 40, "offset_of!(WndClassW, hcursor) != 40 (x86_64)");
+    assert!(offset_of!(WndClassW<'static>, background) == 48, "offset_of!(WndClassW, background) != 48 (x86_64)");
+    assert!(offset_of!(WndClassW<'static>, menu_name) == 56, "offset_of!(WndClassW, menu_name) != 56 (x86_64)");
+    assert!(offset_of!(WndClassW<'static>, class_name) == 64, "offset_of!(WndClassW, class_name) != 64 (x86_64)");
+
+    assert!(size_of ::<WndClassExA<'static>>() == 80, "size_of::<WndClassExA>() != 80 (x86_64)");
+    assert!(align_of::<WndClassExA<'static>>() == 8, "align_of::<WndClassExA>() != 8 (x86_64)");
+    assert!(offset_of!(WndClassExA<'static>, size) == 0, "offset_of!(WndClassExA, size) != 0 (x86_64)");
+    assert!(offset_of!(WndClassExA<'static>, style) == 4, "offset_of!(WndClassExA, style) != 4 (x86_64)");
+    assert!(offset_of!(WndClassExA<'static>, wnd_proc) == 8, "offset_of!(WndClassExA, wnd_proc) != 8 (x86_64)");
+    assert!(offset_of!(WndClassExA<'static>, cls_extra) == 16, "offset_of!(WndClassExA, cls_extra) != 16 (x86_64)");
+    assert!(offset_of!(WndClassExA<'static>, wnd_extra) == 20, "offset_of!(WndClassExA, wnd_extra) != 20 (x86_64)");
+    assert!(offset_of!(WndClassExA<'static>, hinstance) == 24, "offset_of!(WndClassExA, hinstance) != 24 (x86_64)");
+    assert!(offset_of!(WndClassExA<'static>, hicon) == 32, "offset_of!(WndClassExA, hicon) != 32 (x86_64)");
+    assert!(offset_of!(WndClassExA<'static>, hcursor) == 40, "offset_of!(WndClassExA, hcursor) != 40 (x86_64)");
+    assert!(offset_of!(WndClassExA<'static>, background) == 48, "offset_of!(WndClassExA, background) != 48 (x86_64)");
+    assert!(offset_of!(WndClassExA<'static>, menu_name) == 56, "offset_of!(WndClassExA, menu_name) != 56 (x86_64)");
+    assert!(offset_of!(WndClassExA<'static>, class_name) == 64, "offset_of!(WndClassExA, class_name) != 64 (x86_64)");
+    assert!(offset_of!(WndClassExA<'static>, hicon_sm) == 72, "offset_of!(WndClassExA, hicon_sm) != 72 (x86_64)");
+
+    assert!(size_of ::<WndClassExW<'static>>() == 80, "size_of::<WndClassExW>() != 80 (x86_64)");
+    assert!(align_of::<WndClassExW<'static>>() == 8, "align_of::<WndClassExW>() != 8 (x86_64)");
+    assert!(offset_of!(WndClassExW<'static>, size) == 0, "offset_of!(WndClassExW, size) != 0 (x86_64)");
+    assert!(offset_of!(WndClassExW<'static>, style) == 4, "offset_of!(WndClassExW, style) != 4 (x86_64)");
+    assert!(offset_of!(WndClassExW<'static>, wnd_proc) == 8, "offset_of!(WndClassExW, wnd_proc) != 8 (x86_64)");
+    assert!(offset_of!(WndClassExW<'static>, cls_extra) == 16, "offset_of!(WndClassExW, cls_extra) != 16 (x86_64)");
+    assert!(offset_of!(WndClassExW<'static>, wnd_extra) == 20, "offset_of!(WndClassExW, wnd_extra) != 20 (x86_64)");
+    assert!(offset_of!(WndClassExW<'static>, hinstance) == 24, "offset_of!(WndClassExW, hinstance) != 24 (x86_64)");
+    assert!(offset_of!(WndClassExW<'static>, hicon) == 32, "offset_of!(WndClassExW, hicon) != 32 (x86_64)");
+    assert!(offset_of!(WndClassExW<'static>, hcursor) == 40, "offset_of!(WndClassExW, hcursor) != 40 (x86_64)");
+    assert!(offset_of!(WndClassExW<'static>, background) == 48, "offset_of!(WndClassExW, background) != 48 (x86_64)");
+    assert!(offset_of!(WndClassExW<'static>, menu_name) == 56, "offset_of!(WndClassExW, menu_name) != 56 (x86_64)");
+    assert!(offset_of!(WndClassExW<'static>, class_name) == 64, "offset_of!(WndClassExW, class_name) != 64 (x86_64)");
+    assert!(offset_of!(WndClassExW<'static>, hicon_sm) == 72, "offset_of!(WndClassExW, hicon_sm) != 72 (x86_64)");
+};
+
+#[cfg(all(windows, target_arch = "aarch64"))] const _ : () = {
+    use crate::*;
+    use core::mem::{align_of, offset_of, size_of};
+
+    assert!(size_of ::<Point>() == 8, "size_of::<Point>() != 8 (aarch64)");
+    assert!(align_of::<Point>() == 4, "align_of::<Point>() != 4 (aarch64)");
+    assert!(offset_of!(Point, x) == 0, "offset_of!(Point, x) != 0 (aarch64)");
+    assert!(offset_of!(Point, y) == 4, "offset_of!(Point, y) != 4 (aarch64)");
+
+    assert!(size_of ::<Rect>() == 16, "size_of::<Rect>() != 16 (aarch64)");
+    assert!(align_of::<Rect>() == 4, "align_of::<Rect>() != 4 (aarch64)");
+    assert!(offset_of!(Rect, left) == 0, "offset_of!(Rect, left) != 0 (aarch64)");
+    assert!(offset_of!(Rect, top) == 4, "offset_of!(Rect, top) != 4 (aarch64)");
+    assert!(offset_of!(Rect, right) == 8, "offset_of!(Rect, right) != 8 (aarch64)");
+    assert!(offset_of!(Rect, bottom) == 12, "offset_of!(Rect, bottom) != 12 (aarch64)");
+
+    assert!(size_of ::<Msg>() == 48, "size_of::<Msg>() != 48 (aarch64)");
+    assert!(align_of::<Msg>() == 8, "align_of::<Msg>() != 8 (aarch64)");
+    assert!(offset_of!(Msg, hwnd) == 0, "offset_of!(Msg, hwnd) != 0 (aarch64)");
+    assert!(offset_of!(Msg, message) == 8, "offset_of!(Msg, message) != 8 (aarch64)");
+    assert!(offset_of!(Msg, wparam) == 16, "offset_of!(Msg, wparam) != 16 (aarch64)");
+    assert!(offset_of!(Msg, lparam) == 24, "offset_of!(Msg, lparam) != 24 (aarch64)");
+    assert!(offset_of!(Msg, time) == 32, "offset_of!(Msg, time) != 32 (aarch64)");
+    assert!(offset_of!(Msg, pt) == 36, "offset_of!(Msg, pt) != 36 (aarch64)");
+
+    assert!(size_of ::<WindowPlacement>() == 44, "size_of::<WindowPlacement>() != 44 (aarch64)");
+    assert!(align_of::<WindowPlacement>() == 4, "align_of::<WindowPlacement>() != 4 (aarch64)");
+    assert!(offset_of!(WindowPlacement, length) == 0, "offset_of!(WindowPlacement, length) != 0 (aarch64)");
+    assert!(offset_of!(WindowPlacement, flags) == 4, "offset_of!(WindowPlacement, flags) != 4 (aarch64)");
+    assert!(offset_of!(WindowPlacement, show_cmd) == 8, "offset_of!(WindowPlacement, show_cmd) != 8 (aarch64)");
+    assert!(offset_of!(WindowPlacement, min_position) == 12, "offset_of!(WindowPlacement, min_position) != 12 (aarch64)");
+    assert!(offset_of!(WindowPlacement, max_position) == 20, "offset_of!(WindowPlacement, max_position) != 20 (aarch64)");
+    assert!(offset_of!(WindowPlacement, normal_position) == 28, "offset_of!(WindowPlacement, normal_position) != 28 (aarch64)");
+
+    assert!(size_of ::<WndClassA<'static>>() == 72, "size_of::<WndClassA>() != 72 (aarch64)");
+    assert!(align_of::<WndClassA<'static>>() == 8, "align_of::<WndClassA>() != 8 (aarch64)");
+    assert!(offset_of!(WndClassA<'static>, style) == 0, "offset_of!(WndClassA, style) != 0 (aarch64)");
+    assert!(offset_of!(WndClassA<'static>, wnd_proc) == 8, "offset_of!(WndClassA, wnd_proc) != 8 (aarch64)");
+    assert!(offset_of!(WndClassA<'static>, cls_extra) == 16, "offset_of!(WndClassA, cls_extra) != 16 (aarch64)");
+    assert!(offset_of!(WndClassA<'static>, wnd_extra) == 20, "offset_of!(WndClassA, wnd_extra) != 20 (aarch64)");
+    assert!(offset_of!(WndClassA<'static>, hinstance) == 24, "offset_of!(WndClassA, hinstance) != 24 (aarch64)");
+    assert!(offset_of!(WndClassA<'static>, hicon) == 32, "offset_of!(WndClassA, hicon) != 32 (aarch64)");
+    assert!(offset_of!(WndClassA<'static>, hcursor) == 40, "offset_of!(WndClassA, hcursor) != 40 (aarch64)");
+    assert!(offset_of!(WndClassA<'static>, background) == 48, "offset_of!(WndClassA, background) != 48 (aarch64)");
+    assert!(offset_of!(WndClassA<'static>, menu_name) == 56, "offset_of!(WndClassA, menu_name) != 56 (aarch64)");
+    assert!(offset_of!(WndClassA<'static>, class_name) == 64, "offset_of!(WndClassA, class_name) != 64 (aarch64)");
+
+    assert!(size_of ::<WndClassW<'static>>() == 72, "size_of::<WndClassW>() != 72 (aarch64)");
+    assert!(align_of::<WndClassW<'static>>() == 8, "align_of::<WndClassW>() != 8 (aarch64)");
+    assert!(offset_of!(WndClassW<'static>, style) == 0, "offset_of!(WndClassW, style) != 0 (aarch64)");
+    assert!(offset_of!(WndClassW<'static>, wnd_proc) == 8, "offset_of!(WndClassW, wnd_proc) != 8 (aarch64)");
+    assert!(offset_of!(WndClassW<'static>, cls_extra) == 16, "offset_of!(WndClassW, cls_extra) != 16 (aarch64)");
+    assert!(offset_of!(WndClassW<'static>, wnd_extra) == 20, "offset_of!(WndClassW, wnd_extra) != 20 (aarch64)");
+    assert!(offset_of!(WndClassW<'static>, hinstance) == 24, "offset_of!(WndClassW, hinstance) != 24 (aarch64)");
+    assert!(offset_of!(WndClassW<'static>, hicon) == 32, "offset_of!(WndClassW, hicon) != 32 (aarch64)");
+    assert!(offset_of!(WndClassW<'static>, hcursor) == 40, "offset_of!(WndClassW, hcursor) != 40 (aarch64)");
+    assert!(offset_of!(WndClassW<'static>, background) == 48, "offset_of!(WndClassW, background) != 48 (aarch64)");
+    assert!(offset_of!(WndClassW<'static>, menu_name) == 56, "offset_of!(WndClassW, menu_name) != 56 (aarch64)");
+    assert!(offset_of!(WndClassW<'static>, class_name) == 64, "offset_of!(WndClassW, class_name) != 64 (aarch64)");
+
+    assert!(size_of ::<WndClassExA<'static>>() == 80, "size_of::<WndClassExA>() != 80 (aarch64)");
+    assert!(align_of::<WndClassExA<'static>>() == 8, "align_of::<WndClassExA>() != 8 (aarch64)");
+    assert!(offset_of!(WndClassExA<'static>, size) == 0, "offset_of!(WndClassExA, size) != 0 (aarch64)");
+    assert!(offset_of!(WndClassExA<'static>, style) == 4, "offset_of!(WndClassExA, style) != 4 (aarch64)");
+    assert!(offset_of!(WndClassExA<'static>, wnd_proc) == 8, "offset_of!(WndClassExA, wnd_proc) != 8 (aarch64)");
+    assert!(offset_of!(WndClassExA<'static>, cls_extra) == 16, "offset_of!(WndClassExA, cls_extra) != 16 (aarch64)");
+    assert!(offset_of!(WndClassExA<'static>, wnd_extra) == 20, "offset_of!(WndClassExA, wnd_extra) != 20 (aarch64)");
+    assert!(offset_of!(WndClassExA<'static>, hinstance) == 24, "offset_of!(WndClassExA, hinstance) != 24 (aarch64)");
+    assert!(offset_of!(WndClassExA<'static>, hicon) == 32, "offset_of!(WndClassExA, hicon) != 32 (aarch64)");
+    assert!(offset_of!(WndClassExA<'static>, hcursor) == 40, "offset_of!(WndClassExA, hcursor) != 40 (aarch64)");
+    assert!(offset_of!(WndClassExA<'static>, background) == 48, "offset_of!(WndClassExA, background) != 48 (aarch64)");
+    assert!(offset_of!(WndClassExA<'static>, menu_name) == 56, "offset_of!(WndClassExA, menu_name) != 56 (aarch64)");
+    assert!(offset_of!(WndClassExA<'static>, class_name) == 64, "offset_of!(WndClassExA, class_name) != 64 (aarch64)");
+    assert!(offset_of!(WndClassExA<'static>, hicon_sm) == 72, "offset_of!(WndClassExA, hicon_sm) != 72 (aarch64)");
+
+    assert!(size_of ::<WndClassExW<'static>>() == 80, "size_of::<WndClassExW>() != 80 (aarch64)");
+    assert!(align_of::<WndClassExW<'static>>() == 8, "align_of::<WndClassExW>() != 8 (aarch64)");
+    assert!(offset_of!(WndClassExW<'static>, size) == 0, "offset_of!(WndClassExW, size) != 0 (aarch64)");
+    assert!(offset_of!(WndClassExW<'static>, style) == 4, "offset_of!(WndClassExW, style) != 4 (aarch64)");
+    assert!(offset_of!(WndClassExW<'static>, wnd_proc) == 8, "offset_of!(WndClassExW, wnd_proc) != 8 (aarch64)");
+    assert!(offset_of!(WndClassExW<'static>, cls_extra) == 16, "offset_of!(WndClassExW, cls_extra) != 16 (aarch64)");
+    assert!(offset_of!(WndClassExW<'static>, wnd_extra) == 20, "offset_of!(WndClassExW, wnd_extra) != 20 (aarch64)");
+    assert!(offset_of!(WndClassExW<'static>, hinstance) == 24, "offset_of!(WndClassExW, hinstance) != 24 (aarch64)");
+    assert!(offset_of!(WndClassExW<'static>, hicon) == 32, "offset_of!(WndClassExW, hicon) != 32 (aarch64)");
+    assert!(offset_of!(WndClassExW<'static>, hcursor) == 40, "offset_of!(WndClassExW, hcursor) != 40 (aarch64)");
+    assert!(offset_of!(WndClassExW<'static>, background) == 48, "offset_of!(WndClassExW, background) != 48 (aarch64)");
+    assert!(offset_of!(WndClassExW<'static>, menu_name) == 56, "offset_of!(WndClassExW, menu_name) != 56 (aarch64)");
+    assert!(offset_of!(WndClassExW<'static>, class_name) == 64, "offset_of!(WndClassExW, class_name) != 64 (aarch64)");
+    assert!(offset_of!(WndClassExW<'static>, hicon_sm) == 72, "offset_of!(WndClassExW, hicon_sm) != 72 (aarch64)");
+};
@@ -4,11 +4,14 @@
 #![debugger_visualizer(natvis_file = "../hwnd.natvis")]
 
 use winresult::ERROR;
+#[doc(hidden)] pub use WM::WM16;
 #[doc(hidden)] pub use WM::WM32;
 use WS::WindowStyle;
 use WS_EX::WindowStyleExtended;
 
 #[macro_use] mod _macros;
+mod _layout;
+mod _messages;
 
 pub use winapi::shared::minwindef::LPARAM;          // OK?
 pub use winapi::shared::minwindef::LRESULT;         // OK?
@@ -19,6 +22,7 @@ pub use winapi::shared::windef::HCURSOR;            // TODO: wrap / typeify
 pub use winapi::shared::windef::HMENU;              // TODO: wrap / typeify
 pub use winapi::shared::windef::HICON;              // TODO: wrap / typeify
 pub use winapi::shared::windef::HBRUSH;             // TODO: wrap / typeify
+pub use winapi::shared::windef::HDC;                // TODO: wrap / typeify
 
 #[cfg(doc)] pub mod doc {
     //! `doc/*.md` markdown documentation
@@ -30,15 +34,31 @@ pub use winapi::shared::windef::HBRUSH;             // TODO: wrap / typeify
 }
 
 #[path = "assoc/_assoc.rs"] pub mod assoc;
+#[path = "hooks/_hooks.rs"] pub mod hooks;
+pub mod ico;
+pub mod msg;
+pub mod pixels;
+pub mod single_instance;
+pub mod timer;
+pub mod trace;
 
 mods! {
     inl mod structures {
+        inl mod callback_panic;
         inl mod error;
+        inl mod invalid_flags;
+        inl mod last_error_guard;
+        inl mod parse_error;
     }
 
     inl mod utils {
         inl mod _32;
+        inl mod bitmap;
+        inl mod dc;
         inl mod gle;
+        pub(crate) mod parse;
+        #[cfg(test)] pub(crate) mod testing;
+        inl mod user32;
     }
 
     /// shared/*.h
@@ -51,6 +71,8 @@ mods! {
 
             inl mod handles {
                 inl mod hmodule;
+                inl mod hrsrc;
+                inl mod library;
             }
 
             inl mod values {
@@ -61,15 +83,21 @@ mods! {
         /// shared/windef.h
         pub mod windef {
             inl mod handles {
+                inl mod dpi_awareness_context;
                 inl mod hcursor;
                 inl mod hicon;
                 inl mod hwnd_;
+                inl mod owned_icon;
             }
 
             inl mod structures {
                 inl mod point;
                 inl mod rect;
             }
+
+            inl mod values {
+                pub mod DPI_AWARENESS_CONTEXT;
+            }
         }
     }
 
@@ -77,8 +105,20 @@ mods! {
     pub mod um {
         /// um/libloaderapi.h
         pub mod libloaderapi {
+            inl mod flags {
+                pub mod LOAD_LIBRARY;
+            }
+
             inl mod functions {
                 inl mod get_module_handle_;
+                inl mod get_proc_address_;
+                inl mod load_library;
+                inl mod load_string;
+                inl mod resource;
+            }
+
+            inl mod structures {
+                inl mod resource_name;
             }
         }
 
@@ -96,13 +136,17 @@ mods! {
                 pub mod GWLP;
                 pub mod IDC;
                 pub mod IDI;
+                pub mod RT;
                 pub mod SW;
                 pub mod WM;
             }
 
             inl mod flags {
                 pub mod ISMEX;
+                pub mod LR;
+                pub mod LWA;
                 pub mod PM;
+                pub mod RDW;
                 pub mod SMTO;
                 pub mod SWP;
                 pub mod WPF;
@@ -112,13 +156,20 @@ mods! {
 
             inl mod functions {
                 inl mod adjust_window_rect_;
+                inl mod begin_paint_;
                 inl mod close_window_;
+                inl mod create_icon_from_resource;
+                inl mod create_icon_from_rgba_;
                 inl mod create_window_;
                 inl mod def_window_proc;
                 inl mod destroy_window_;
                 inl mod dispatch_message;
+                inl mod find_window_;
                 inl mod get_client_rect_;
+                inl mod get_dpi_for_window_;
+                inl mod get_icon_info_;
                 inl mod get_message;
+                inl mod get_update_rect_;
                 inl mod get_window_long_ptr;
                 inl mod get_window_long;
                 inl mod get_window_placement_;
@@ -127,18 +178,25 @@ mods! {
                 inl mod get_window_thread_process_id_;
                 inl mod get_x_window;
                 inl mod in_send_message_;
+                inl mod invalidate_rect_;
                 inl mod is;
                 inl mod kill_timer_;
+                inl mod layered_window;
                 inl mod load_cursor;
                 inl mod load_icon;
+                inl mod load_image;
                 inl mod peek_message;
                 inl mod post_message;
+                inl mod prop;
+                inl mod redraw_window_;
                 inl mod register_class_;
                 inl mod register_window_message;
                 inl mod reply_message_;
+                inl mod send_copy_data;
                 inl mod send_message;
                 inl mod set_coalescable_timer_;
                 inl mod set_foreground_window_;
+                inl mod set_thread_dpi_awareness_context_;
                 inl mod set_timer_;
                 inl mod set_timerproc_exception_suppression_;
                 inl mod set_window_placement_;
@@ -149,6 +207,11 @@ mods! {
             }
 
             inl mod structures {
+                inl mod copy_data;
+                inl mod icon_info;
+                inl mod message_info;
+                inl mod paint_guard;
+                inl mod registered_class;
                 inl mod msg;
                 inl mod timer_proc;
                 inl mod window_placement;
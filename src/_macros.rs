@@ -1,10 +1,13 @@
-macro_rules! fn_context     { ( $cpp:path => $rust:path ) => {} }
-macro_rules! fn_succeeded   { ( $expr:expr ) => { if false == abibool::b32::from($expr) { Err($crate::Error::new_gle()) } else { Ok(()) } }}
-macro_rules! fn_err         { (               $error:expr ) => { Err($crate::Error(($error).into())) } }
-macro_rules! fn_error       { (               $error:expr ) => {     $crate::Error(($error).into())  } }
-macro_rules! fn_param_error { ( $_param:expr, $error:expr ) => {     $crate::Error(($error).into())  } }
-macro_rules! fn_error_gle   { (                           ) => {     $crate::Error::new_gle()        } }
-macro_rules! fn_error_gle_nz{ (                           ) => {     $crate::Error::new_gle_nz()     } }
+macro_rules! fn_context     {
+    ( $rust:path => $win32:path ) => { #[allow(dead_code)] const FN_CONTEXT : &'static $crate::ErrorContext = &$crate::ErrorContext { function: stringify!($rust), win32: Some(stringify!($win32)), param: None }; };
+    ( $rust:path                ) => { #[allow(dead_code)] const FN_CONTEXT : &'static $crate::ErrorContext = &$crate::ErrorContext { function: stringify!($rust), win32: None,                     param: None }; };
+}
+macro_rules! fn_succeeded   { ( $expr:expr ) => { if false == abibool::b32::from($expr) { Err($crate::Error::new_gle().with_context(FN_CONTEXT)) } else { Ok(()) } }}
+macro_rules! fn_err         { (               $error:expr ) => { Err($crate::Error::new(($error).into()).with_context(FN_CONTEXT)) } }
+macro_rules! fn_error       { (               $error:expr ) => {     $crate::Error::new(($error).into()).with_context(FN_CONTEXT)  } }
+macro_rules! fn_param_error { ( $param:expr,  $error:expr ) => {     $crate::Error::new(($error).into()).with_context({ const C : &'static $crate::ErrorContext = &FN_CONTEXT.with_param(stringify!($param)); C }) } }
+macro_rules! fn_error_gle   { (                           ) => {     $crate::Error::new_gle().with_context(FN_CONTEXT)                        } }
+macro_rules! fn_error_gle_nz{ (                           ) => {     $crate::Error::new_gle_nz().map_err(|e| e.with_context(FN_CONTEXT))      } }
 
 macro_rules! mods {
     ( $( #[$attr:meta] )* inl      mod $mod:ident ;                $($tt:tt)* ) => { $(#[$attr])*      mod $mod;                       #[allow(unused_imports)] pub use $mod::*; mods!{ $($tt)* } };
@@ -101,6 +104,65 @@ macro_rules! impl_ops_for_flag {( $flag:ty ) => {
         /// `true` if all bits of `other` are set in `self`<br>
         /// `false` if `other` is `0`
         pub const fn has_any(self, other: Self) -> bool { self.0 & other.0 != 0 }
+
+        /// `true` if all bits of `other` are set in `self` (a `const` alias of [`has_all`](Self::has_all))
+        pub const fn contains(self, other: Self) -> bool { self.has_all(other) }
+
+        /// `self | other`, but `const`
+        pub const fn union(self, other: Self) -> Self { Self(self.0 | other.0) }
+
+        /// `self & !other`: the bits of `self` which aren't set in `other`
+        pub const fn difference(self, other: Self) -> Self { Self(self.0 & !other.0) }
+    }
+}}
+
+/// Implement [FromStr](std::str::FromStr) (and serde, if enabled) for a flag type, accepting any `|`-separated combination of `$path`s (or their C spellings) and integers.
+/// Should list every constant - including aliases - unlike [impl_debug_for_flags].
+macro_rules! impl_from_str_for_flags {( $flag:ty => { $($path:path),* $(,)? } ) => {
+    impl std::str::FromStr for $flag {
+        type Err = crate::ParseError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            crate::utils::parse::flags(stringify!($flag), s, &[$( (stringify!($path), $path.0), )*]).map(Self)
+        }
+    }
+    impl_serde_via_str!($flag);
+}}
+
+/// Implement `validate()` for a flag type, rejecting documented-invalid combinations of flags
+macro_rules! impl_validate_for_flags {( $flag:ty => { $( $($path:path)|+ => $reason:literal ),* $(,)? } ) => {
+    impl $flag {
+        /// Check `self` for documented-invalid combinations of flags (e.g. `WS::CHILD | WS::POPUP`, or `SWP::SHOWWINDOW | SWP::HIDEWINDOW`.)
+        ///
+        /// ### Errors
+        /// *   [InvalidFlags]  if `self` contains an invalid combination of flags (the first such combination is reported.)
+        pub const fn validate(self) -> Result<(), crate::InvalidFlags> {
+            $(
+                if self.has_all(Self(0 $(| $path.0)+)) { return Err(crate::InvalidFlags { ty: stringify!($flag), flags: stringify!($($path)|+), reason: $reason }) }
+            )*
+            Ok(())
+        }
+    }
+}}
+
+/// Implement [FromStr](std::str::FromStr) (and serde, if enabled) for an enum type, accepting any of `$path`s (or their C spellings) or an integer.
+macro_rules! impl_from_str_for_enum {( $enum:ty => { $($path:path),* $(,)? } ) => {
+    impl std::str::FromStr for $enum {
+        type Err = crate::ParseError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            crate::utils::parse::enum_(stringify!($enum), s, &[$( (stringify!($path), $path.0), )*]).map(Self)
+        }
+    }
+    impl_serde_via_str!($enum);
+}}
+
+/// (De)serialize via [Debug] / [FromStr](std::str::FromStr) if the `serde` feature is enabled
+macro_rules! impl_serde_via_str {( $ty:ty ) => {
+    #[cfg(feature = "serde")] impl serde::Serialize for $ty {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { serializer.collect_str(&format_args!("{:?}", self)) }
+    }
+
+    #[cfg(feature = "serde")] impl<'de> serde::Deserialize<'de> for $ty {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> { crate::utils::parse::deserialize(deserializer) }
     }
 }}
 
@@ -116,7 +178,29 @@ macro_rules! impl_debug_for_enum {( $flag:ty => { $($path:path),* $(,)? } ) => {
     }
 }}
 
+/// Implement [Debug](std::fmt::Debug), `iter_named`, and `unknown_bits` for a flag type.
+/// `$path`s are in [Debug](std::fmt::Debug) order: aggregates (e.g. `WS::OVERLAPPEDWINDOW`) should precede their parts, and aliases should be omitted.
 macro_rules! impl_debug_for_flags {( $flag:ty => { $($path:path),* $(,)? } ) => {
+    impl $flag {
+        const NAMED : &'static [(&'static str, $flag)] = &[$( (stringify!($path), $path), )*];
+        const KNOWN : $flag = Self(0 $(| $path.0)*);
+
+        /// Iterate the named flags composing `self`, in the same order (and with the same names) as [Debug](std::fmt::Debug).<br>
+        /// Aggregates (e.g. `WS::OVERLAPPEDWINDOW`) are preferred over their parts, and `0` flags are never yielded.<br>
+        /// Bits without a name are omitted: see [`unknown_bits`](Self::unknown_bits).
+        pub fn iter_named(self) -> impl Iterator<Item = (&'static str, Self)> {
+            let mut remaining = self;
+            Self::NAMED.iter().copied().filter(move |&(_, flag)| {
+                let named = flag.0 != 0 && remaining.has_all(flag);
+                if named { remaining = remaining.difference(flag) }
+                named
+            })
+        }
+
+        /// The bits of `self` which aren't part of any named flag
+        pub const fn unknown_bits(self) -> Self { self.difference(Self::KNOWN) }
+    }
+
     impl std::fmt::Debug for $flag {
         fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
             match *self {
@@ -143,3 +227,22 @@ macro_rules! impl_debug_for_flags {( $flag:ty => { $($path:path),* $(,)? } ) =>
         }
     }
 }}
+
+#[test] fn flag_helpers() {
+    use crate::*;
+
+    let style = WS::OVERLAPPEDWINDOW | WS::VISIBLE | WindowStyle::from(0x1);
+    assert_eq!(style.iter_named().collect::<Vec<_>>(), [("WS::OVERLAPPEDWINDOW", WS::OVERLAPPEDWINDOW), ("WS::VISIBLE", WS::VISIBLE)]);
+    assert_eq!(style.unknown_bits(), WindowStyle::from(0x1));
+    assert_eq!(WS::OVERLAPPED.iter_named().count(), 0);
+
+    for bits in (0 .. 32).map(|bit| 1 << bit).chain([0, 0x1234_5678, !0]) {
+        let flags = SWP::SetWindowPosFlags::from(bits);
+        let named = flags.iter_named().fold(SWP::SetWindowPosFlags::default(), |all, (_, flag)| { assert!(!all.has_any(flag), "{flags:?}: overlapping names"); all.union(flag) });
+        assert_eq!(named.union(flags.unknown_bits()), flags);
+        assert_eq!(named.difference(flags), SWP::SetWindowPosFlags::default());
+    }
+
+    const STYLE : WindowStyle = WS::CHILD.union(WS::VISIBLE);
+    const _ : () = assert!(STYLE.contains(WS::CHILD) && !STYLE.contains(WS::CHILD.union(WS::POPUP)));
+}
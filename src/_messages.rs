@@ -0,0 +1,510 @@
+// WARNING: this file is auto-generated by xtask gen and may be overwritten: edit crates/xtask/src/bin/data/wm.rs instead
+//! [MessageInfo] for every named system message, sorted by value for [WM32::info].
+
+use crate::*;
+
+
+
+pub(crate) const MESSAGES : &[MessageInfo] = &[
+    MessageInfo { wm: WM::NULL,                           name: "WM::NULL",                           wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CREATE,                         name: "WM::CREATE",                         wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("CREATESTRUCT")), result: MessageResult::Value,            send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::DESTROY,                        name: "WM::DESTROY",                        wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MOVE,                           name: "WM::MOVE",                           wparam: MessageParam::Unused,                lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SIZE,                           name: "WM::SIZE",                           wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::ACTIVATE,                       name: "WM::ACTIVATE",                       wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SETFOCUS,                       name: "WM::SETFOCUS",                       wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::KILLFOCUS,                      name: "WM::KILLFOCUS",                      wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::ENABLE,                         name: "WM::ENABLE",                         wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SETREDRAW,                      name: "WM::SETREDRAW",                      wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SETTEXT,                        name: "WM::SETTEXT",                        wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("TCHAR")),    result: MessageResult::Bool,             send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::GETTEXT,                        name: "WM::GETTEXT",                        wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("TCHAR")),    result: MessageResult::Value,            send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::GETTEXTLENGTH,                  name: "WM::GETTEXTLENGTH",                  wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::PAINT,                          name: "WM::PAINT",                          wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CLOSE,                          name: "WM::CLOSE",                          wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::QUERYENDSESSION,                name: "WM::QUERYENDSESSION",                wparam: MessageParam::Unused,                lparam: MessageParam::Value,                 result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::QUIT,                           name: "WM::QUIT",                           wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::QUERYOPEN,                      name: "WM::QUERYOPEN",                      wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::ERASEBKGND,                     name: "WM::ERASEBKGND",                     wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SYSCOLORCHANGE,                 name: "WM::SYSCOLORCHANGE",                 wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::ENDSESSION,                     name: "WM::ENDSESSION",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SHOWWINDOW,                     name: "WM::SHOWWINDOW",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::WININICHANGE,                   name: "WM::WININICHANGE",                   wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("TCHAR")),    result: MessageResult::ZeroIfProcessed,  send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::SETTINGCHANGE,                  name: "WM::SETTINGCHANGE",                  wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("TCHAR")),    result: MessageResult::ZeroIfProcessed,  send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::DEVMODECHANGE,                  name: "WM::DEVMODECHANGE",                  wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("TCHAR")),    result: MessageResult::ZeroIfProcessed,  send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::ACTIVATEAPP,                    name: "WM::ACTIVATEAPP",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::FONTCHANGE,                     name: "WM::FONTCHANGE",                     wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::TIMECHANGE,                     name: "WM::TIMECHANGE",                     wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CANCELMODE,                     name: "WM::CANCELMODE",                     wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SETCURSOR,                      name: "WM::SETCURSOR",                      wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MOUSEACTIVATE,                  name: "WM::MOUSEACTIVATE",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CHILDACTIVATE,                  name: "WM::CHILDACTIVATE",                  wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::QUEUESYNC,                      name: "WM::QUEUESYNC",                      wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::GETMINMAXINFO,                  name: "WM::GETMINMAXINFO",                  wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("MINMAXINFO")), result: MessageResult::ZeroIfProcessed,  send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::PAINTICON,                      name: "WM::PAINTICON",                      wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::ICONERASEBKGND,                 name: "WM::ICONERASEBKGND",                 wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NEXTDLGCTL,                     name: "WM::NEXTDLGCTL",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SPOOLERSTATUS,                  name: "WM::SPOOLERSTATUS",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DRAWITEM,                       name: "WM::DRAWITEM",                       wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("DRAWITEMSTRUCT")), result: MessageResult::Bool,             send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::MEASUREITEM,                    name: "WM::MEASUREITEM",                    wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("MEASUREITEMSTRUCT")), result: MessageResult::Bool,             send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::DELETEITEM,                     name: "WM::DELETEITEM",                     wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("DELETEITEMSTRUCT")), result: MessageResult::Bool,             send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::VKEYTOITEM,                     name: "WM::VKEYTOITEM",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CHARTOITEM,                     name: "WM::CHARTOITEM",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SETFONT,                        name: "WM::SETFONT",                        wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::GETFONT,                        name: "WM::GETFONT",                        wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SETHOTKEY,                      name: "WM::SETHOTKEY",                      wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::GETHOTKEY,                      name: "WM::GETHOTKEY",                      wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::QUERYDRAGICON,                  name: "WM::QUERYDRAGICON",                  wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::COMPAREITEM,                    name: "WM::COMPAREITEM",                    wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("COMPAREITEMSTRUCT")), result: MessageResult::Value,            send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::GETOBJECT,                      name: "WM::GETOBJECT",                      wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::COMPACTING,                     name: "WM::COMPACTING",                     wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::COMMNOTIFY,                     name: "WM::COMMNOTIFY",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::WINDOWPOSCHANGING,              name: "WM::WINDOWPOSCHANGING",              wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("WINDOWPOS")), result: MessageResult::ZeroIfProcessed,  send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::WINDOWPOSCHANGED,               name: "WM::WINDOWPOSCHANGED",               wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("WINDOWPOS")), result: MessageResult::ZeroIfProcessed,  send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::POWER,                          name: "WM::POWER",                          wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::COPYDATA,                       name: "WM::COPYDATA",                       wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("COPYDATASTRUCT")), result: MessageResult::Bool,             send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::CANCELJOURNAL,                  name: "WM::CANCELJOURNAL",                  wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NOTIFY,                         name: "WM::NOTIFY",                         wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("NMHDR")),    result: MessageResult::Value,            send_only: true,  marshaled: false },
+    MessageInfo { wm: WM::INPUTLANGCHANGEREQUEST,         name: "WM::INPUTLANGCHANGEREQUEST",         wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::INPUTLANGCHANGE,                name: "WM::INPUTLANGCHANGE",                wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::TCARD,                          name: "WM::TCARD",                          wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::HELP,                           name: "WM::HELP",                           wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("HELPINFO")), result: MessageResult::Bool,             send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::USERCHANGED,                    name: "WM::USERCHANGED",                    wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NOTIFYFORMAT,                   name: "WM::NOTIFYFORMAT",                   wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CONTEXTMENU,                    name: "WM::CONTEXTMENU",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::STYLECHANGING,                  name: "WM::STYLECHANGING",                  wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("STYLESTRUCT")), result: MessageResult::ZeroIfProcessed,  send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::STYLECHANGED,                   name: "WM::STYLECHANGED",                   wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("STYLESTRUCT")), result: MessageResult::ZeroIfProcessed,  send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::DISPLAYCHANGE,                  name: "WM::DISPLAYCHANGE",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::GETICON,                        name: "WM::GETICON",                        wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SETICON,                        name: "WM::SETICON",                        wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCCREATE,                       name: "WM::NCCREATE",                       wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("CREATESTRUCT")), result: MessageResult::Bool,             send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::NCDESTROY,                      name: "WM::NCDESTROY",                      wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCCALCSIZE,                     name: "WM::NCCALCSIZE",                     wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("NCCALCSIZE_PARAMS")), result: MessageResult::Value,            send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::NCHITTEST,                      name: "WM::NCHITTEST",                      wparam: MessageParam::Unused,                lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCPAINT,                        name: "WM::NCPAINT",                        wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCACTIVATE,                     name: "WM::NCACTIVATE",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::GETDLGCODE,                     name: "WM::GETDLGCODE",                     wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("MSG")),      result: MessageResult::Value,            send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::SYNCPAINT,                      name: "WM::SYNCPAINT",                      wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::UAHDESTROYWINDOW,               name: "WM::UAHDESTROYWINDOW",               wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::UAHDRAWMENU,                    name: "WM::UAHDRAWMENU",                    wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("UAHMENU")),  result: MessageResult::Ignored,          send_only: true,  marshaled: false },
+    MessageInfo { wm: WM::UAHDRAWMENUITEM,                name: "WM::UAHDRAWMENUITEM",                wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("UAHDRAWMENUITEM")), result: MessageResult::Ignored,          send_only: true,  marshaled: false },
+    MessageInfo { wm: WM::UAHINITMENU,                    name: "WM::UAHINITMENU",                    wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("UAHMENU")),  result: MessageResult::Ignored,          send_only: true,  marshaled: false },
+    MessageInfo { wm: WM::UAHMEASUREMENUITEM,             name: "WM::UAHMEASUREMENUITEM",             wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("UAHMEASUREMENUITEM")), result: MessageResult::Ignored,          send_only: true,  marshaled: false },
+    MessageInfo { wm: WM::UAHNCPAINTMENUPOPUP,            name: "WM::UAHNCPAINTMENUPOPUP",            wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("UAHMENU")),  result: MessageResult::Ignored,          send_only: true,  marshaled: false },
+    MessageInfo { wm: WM::NCMOUSEMOVE,                    name: "WM::NCMOUSEMOVE",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCLBUTTONDOWN,                  name: "WM::NCLBUTTONDOWN",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCLBUTTONUP,                    name: "WM::NCLBUTTONUP",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCLBUTTONDBLCLK,                name: "WM::NCLBUTTONDBLCLK",                wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCRBUTTONDOWN,                  name: "WM::NCRBUTTONDOWN",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCRBUTTONUP,                    name: "WM::NCRBUTTONUP",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCRBUTTONDBLCLK,                name: "WM::NCRBUTTONDBLCLK",                wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCMBUTTONDOWN,                  name: "WM::NCMBUTTONDOWN",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCMBUTTONUP,                    name: "WM::NCMBUTTONUP",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCMBUTTONDBLCLK,                name: "WM::NCMBUTTONDBLCLK",                wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCXBUTTONDOWN,                  name: "WM::NCXBUTTONDOWN",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCXBUTTONUP,                    name: "WM::NCXBUTTONUP",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCXBUTTONDBLCLK,                name: "WM::NCXBUTTONDBLCLK",                wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::INPUT_DEVICE_CHANGE,            name: "WM::INPUT_DEVICE_CHANGE",            wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::INPUT,                          name: "WM::INPUT",                          wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::KEYDOWN,                        name: "WM::KEYDOWN",                        wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::KEYUP,                          name: "WM::KEYUP",                          wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CHAR,                           name: "WM::CHAR",                           wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DEADCHAR,                       name: "WM::DEADCHAR",                       wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SYSKEYDOWN,                     name: "WM::SYSKEYDOWN",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SYSKEYUP,                       name: "WM::SYSKEYUP",                       wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SYSCHAR,                        name: "WM::SYSCHAR",                        wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SYSDEADCHAR,                    name: "WM::SYSDEADCHAR",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::UNICHAR,                        name: "WM::UNICHAR",                        wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::IME_STARTCOMPOSITION,           name: "WM::IME_STARTCOMPOSITION",           wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::IME_ENDCOMPOSITION,             name: "WM::IME_ENDCOMPOSITION",             wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::IME_COMPOSITION,                name: "WM::IME_COMPOSITION",                wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::INITDIALOG,                     name: "WM::INITDIALOG",                     wparam: MessageParam::Value,                 lparam: MessageParam::Opaque,                result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::COMMAND,                        name: "WM::COMMAND",                        wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SYSCOMMAND,                     name: "WM::SYSCOMMAND",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::TIMER,                          name: "WM::TIMER",                          wparam: MessageParam::Value,                 lparam: MessageParam::Opaque,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::HSCROLL,                        name: "WM::HSCROLL",                        wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::VSCROLL,                        name: "WM::VSCROLL",                        wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::INITMENU,                       name: "WM::INITMENU",                       wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::INITMENUPOPUP,                  name: "WM::INITMENUPOPUP",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::GESTURE,                        name: "WM::GESTURE",                        wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::GESTURENOTIFY,                  name: "WM::GESTURENOTIFY",                  wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("GESTURENOTIFYSTRUCT")), result: MessageResult::ZeroIfProcessed,  send_only: true,  marshaled: false },
+    MessageInfo { wm: WM::MENUSELECT,                     name: "WM::MENUSELECT",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MENUCHAR,                       name: "WM::MENUCHAR",                       wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::ENTERIDLE,                      name: "WM::ENTERIDLE",                      wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MENURBUTTONUP,                  name: "WM::MENURBUTTONUP",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MENUDRAG,                       name: "WM::MENUDRAG",                       wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MENUGETOBJECT,                  name: "WM::MENUGETOBJECT",                  wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("MENUGETOBJECTINFO")), result: MessageResult::Value,            send_only: true,  marshaled: false },
+    MessageInfo { wm: WM::UNINITMENUPOPUP,                name: "WM::UNINITMENUPOPUP",                wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MENUCOMMAND,                    name: "WM::MENUCOMMAND",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CHANGEUISTATE,                  name: "WM::CHANGEUISTATE",                  wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::UPDATEUISTATE,                  name: "WM::UPDATEUISTATE",                  wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::QUERYUISTATE,                   name: "WM::QUERYUISTATE",                   wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CTLCOLORMSGBOX,                 name: "WM::CTLCOLORMSGBOX",                 wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CTLCOLOREDIT,                   name: "WM::CTLCOLOREDIT",                   wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CTLCOLORLISTBOX,                name: "WM::CTLCOLORLISTBOX",                wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CTLCOLORBTN,                    name: "WM::CTLCOLORBTN",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CTLCOLORDLG,                    name: "WM::CTLCOLORDLG",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CTLCOLORSCROLLBAR,              name: "WM::CTLCOLORSCROLLBAR",              wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CTLCOLORSTATIC,                 name: "WM::CTLCOLORSTATIC",                 wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MN_GETHMENU,                    name: "WM::MN_GETHMENU",                    wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MOUSEMOVE,                      name: "WM::MOUSEMOVE",                      wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::LBUTTONDOWN,                    name: "WM::LBUTTONDOWN",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::LBUTTONUP,                      name: "WM::LBUTTONUP",                      wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::LBUTTONDBLCLK,                  name: "WM::LBUTTONDBLCLK",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::RBUTTONDOWN,                    name: "WM::RBUTTONDOWN",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::RBUTTONUP,                      name: "WM::RBUTTONUP",                      wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::RBUTTONDBLCLK,                  name: "WM::RBUTTONDBLCLK",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MBUTTONDOWN,                    name: "WM::MBUTTONDOWN",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MBUTTONUP,                      name: "WM::MBUTTONUP",                      wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MBUTTONDBLCLK,                  name: "WM::MBUTTONDBLCLK",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MOUSEWHEEL,                     name: "WM::MOUSEWHEEL",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::XBUTTONDOWN,                    name: "WM::XBUTTONDOWN",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::XBUTTONUP,                      name: "WM::XBUTTONUP",                      wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::XBUTTONDBLCLK,                  name: "WM::XBUTTONDBLCLK",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MOUSEHWHEEL,                    name: "WM::MOUSEHWHEEL",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::PARENTNOTIFY,                   name: "WM::PARENTNOTIFY",                   wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::ENTERMENULOOP,                  name: "WM::ENTERMENULOOP",                  wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::EXITMENULOOP,                   name: "WM::EXITMENULOOP",                   wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NEXTMENU,                       name: "WM::NEXTMENU",                       wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("MDINEXTMENU")), result: MessageResult::ZeroIfProcessed,  send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::SIZING,                         name: "WM::SIZING",                         wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("RECT")),     result: MessageResult::Bool,             send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::CAPTURECHANGED,                 name: "WM::CAPTURECHANGED",                 wparam: MessageParam::Unused,                lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MOVING,                         name: "WM::MOVING",                         wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("RECT")),     result: MessageResult::Bool,             send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::POWERBROADCAST,                 name: "WM::POWERBROADCAST",                 wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("POWERBROADCAST_SETTING")), result: MessageResult::Bool,             send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::DEVICECHANGE,                   name: "WM::DEVICECHANGE",                   wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("DEV_BROADCAST_HDR")), result: MessageResult::Bool,             send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::MDICREATE,                      name: "WM::MDICREATE",                      wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("MDICREATESTRUCT")), result: MessageResult::Value,            send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::MDIDESTROY,                     name: "WM::MDIDESTROY",                     wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MDIACTIVATE,                    name: "WM::MDIACTIVATE",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MDIRESTORE,                     name: "WM::MDIRESTORE",                     wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MDINEXT,                        name: "WM::MDINEXT",                        wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MDIMAXIMIZE,                    name: "WM::MDIMAXIMIZE",                    wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MDITILE,                        name: "WM::MDITILE",                        wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MDICASCADE,                     name: "WM::MDICASCADE",                     wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MDIICONARRANGE,                 name: "WM::MDIICONARRANGE",                 wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MDIGETACTIVE,                   name: "WM::MDIGETACTIVE",                   wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("BOOL")),     result: MessageResult::Value,            send_only: true,  marshaled: false },
+    MessageInfo { wm: WM::MDISETMENU,                     name: "WM::MDISETMENU",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::ENTERSIZEMOVE,                  name: "WM::ENTERSIZEMOVE",                  wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::EXITSIZEMOVE,                   name: "WM::EXITSIZEMOVE",                   wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DROPFILES,                      name: "WM::DROPFILES",                      wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MDIREFRESHMENU,                 name: "WM::MDIREFRESHMENU",                 wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERDEVICECHANGE,            name: "WM::POINTERDEVICECHANGE",            wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERDEVICEINRANGE,           name: "WM::POINTERDEVICEINRANGE",           wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERDEVICEOUTOFRANGE,        name: "WM::POINTERDEVICEOUTOFRANGE",        wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::TOUCH,                          name: "WM::TOUCH",                          wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCPOINTERUPDATE,                name: "WM::NCPOINTERUPDATE",                wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCPOINTERDOWN,                  name: "WM::NCPOINTERDOWN",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCPOINTERUP,                    name: "WM::NCPOINTERUP",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERUPDATE,                  name: "WM::POINTERUPDATE",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERDOWN,                    name: "WM::POINTERDOWN",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERUP,                      name: "WM::POINTERUP",                      wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERENTER,                   name: "WM::POINTERENTER",                   wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERLEAVE,                   name: "WM::POINTERLEAVE",                   wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERACTIVATE,                name: "WM::POINTERACTIVATE",                wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERCAPTURECHANGED,          name: "WM::POINTERCAPTURECHANGED",          wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::TOUCHHITTESTING,                name: "WM::TOUCHHITTESTING",                wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("TOUCH_HIT_TESTING_INPUT")), result: MessageResult::Value,            send_only: true,  marshaled: false },
+    MessageInfo { wm: WM::POINTERWHEEL,                   name: "WM::POINTERWHEEL",                   wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERHWHEEL,                  name: "WM::POINTERHWHEEL",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DM_POINTERHITTEST,              name: "WM::DM_POINTERHITTEST",              wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERROUTEDTO,                name: "WM::POINTERROUTEDTO",                wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERROUTEDAWAY,              name: "WM::POINTERROUTEDAWAY",              wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::POINTERROUTEDRELEASED,          name: "WM::POINTERROUTEDRELEASED",          wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::IME_SETCONTEXT,                 name: "WM::IME_SETCONTEXT",                 wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::IME_NOTIFY,                     name: "WM::IME_NOTIFY",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::IME_CONTROL,                    name: "WM::IME_CONTROL",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Value,            send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::IME_COMPOSITIONFULL,            name: "WM::IME_COMPOSITIONFULL",            wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::IME_SELECT,                     name: "WM::IME_SELECT",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::IME_CHAR,                       name: "WM::IME_CHAR",                       wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::IME_REQUEST,                    name: "WM::IME_REQUEST",                    wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(None),             result: MessageResult::Value,            send_only: true,  marshaled: false },
+    MessageInfo { wm: WM::IME_KEYDOWN,                    name: "WM::IME_KEYDOWN",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::IME_KEYUP,                      name: "WM::IME_KEYUP",                      wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCMOUSEHOVER,                   name: "WM::NCMOUSEHOVER",                   wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MOUSEHOVER,                     name: "WM::MOUSEHOVER",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::NCMOUSELEAVE,                   name: "WM::NCMOUSELEAVE",                   wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::MOUSELEAVE,                     name: "WM::MOUSELEAVE",                     wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::WTSSESSION_CHANGE,              name: "WM::WTSSESSION_CHANGE",              wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DPICHANGED,                     name: "WM::DPICHANGED",                     wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("RECT")),     result: MessageResult::ZeroIfProcessed,  send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::DPICHANGED_BEFOREPARENT,        name: "WM::DPICHANGED_BEFOREPARENT",        wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DPICHANGED_AFTERPARENT,         name: "WM::DPICHANGED_AFTERPARENT",         wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::GETDPISCALEDSIZE,               name: "WM::GETDPISCALEDSIZE",               wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("SIZE")),     result: MessageResult::Bool,             send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::CUT,                            name: "WM::CUT",                            wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::COPY,                           name: "WM::COPY",                           wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::PASTE,                          name: "WM::PASTE",                          wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CLEAR,                          name: "WM::CLEAR",                          wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::UNDO,                           name: "WM::UNDO",                           wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::RENDERFORMAT,                   name: "WM::RENDERFORMAT",                   wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::RENDERALLFORMATS,               name: "WM::RENDERALLFORMATS",               wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DESTROYCLIPBOARD,               name: "WM::DESTROYCLIPBOARD",               wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DRAWCLIPBOARD,                  name: "WM::DRAWCLIPBOARD",                  wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::PAINTCLIPBOARD,                 name: "WM::PAINTCLIPBOARD",                 wparam: MessageParam::Value,                 lparam: MessageParam::Opaque,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::VSCROLLCLIPBOARD,               name: "WM::VSCROLLCLIPBOARD",               wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::SIZECLIPBOARD,                  name: "WM::SIZECLIPBOARD",                  wparam: MessageParam::Value,                 lparam: MessageParam::Opaque,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::ASKCBFORMATNAME,                name: "WM::ASKCBFORMATNAME",                wparam: MessageParam::Value,                 lparam: MessageParam::Ptr(Some("TCHAR")),    result: MessageResult::Ignored,          send_only: true,  marshaled: true  },
+    MessageInfo { wm: WM::CHANGECBCHAIN,                  name: "WM::CHANGECBCHAIN",                  wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::HSCROLLCLIPBOARD,               name: "WM::HSCROLLCLIPBOARD",               wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::QUERYNEWPALETTE,                name: "WM::QUERYNEWPALETTE",                wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::PALETTEISCHANGING,              name: "WM::PALETTEISCHANGING",              wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::PALETTECHANGED,                 name: "WM::PALETTECHANGED",                 wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::HOTKEY,                         name: "WM::HOTKEY",                         wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::PRINT,                          name: "WM::PRINT",                          wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::PRINTCLIENT,                    name: "WM::PRINTCLIENT",                    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Ignored,          send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::APPCOMMAND,                     name: "WM::APPCOMMAND",                     wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::Bool,             send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::THEMECHANGED,                   name: "WM::THEMECHANGED",                   wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::CLIPBOARDUPDATE,                name: "WM::CLIPBOARDUPDATE",                wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DWMCOMPOSITIONCHANGED,          name: "WM::DWMCOMPOSITIONCHANGED",          wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DWMNCRENDERINGCHANGED,          name: "WM::DWMNCRENDERINGCHANGED",          wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DWMCOLORIZATIONCOLORCHANGED,    name: "WM::DWMCOLORIZATIONCOLORCHANGED",    wparam: MessageParam::Value,                 lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DWMWINDOWMAXIMIZEDCHANGE,       name: "WM::DWMWINDOWMAXIMIZEDCHANGE",       wparam: MessageParam::Value,                 lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DWMSENDICONICTHUMBNAIL,         name: "WM::DWMSENDICONICTHUMBNAIL",         wparam: MessageParam::Unused,                lparam: MessageParam::Value,                 result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::DWMSENDICONICLIVEPREVIEWBITMAP, name: "WM::DWMSENDICONICLIVEPREVIEWBITMAP", wparam: MessageParam::Unused,                lparam: MessageParam::Unused,                result: MessageResult::ZeroIfProcessed,  send_only: false, marshaled: true  },
+    MessageInfo { wm: WM::GETTITLEBARINFOEX,              name: "WM::GETTITLEBARINFOEX",              wparam: MessageParam::Unused,                lparam: MessageParam::Ptr(Some("TITLEBARINFOEX")), result: MessageResult::Ignored,          send_only: true,  marshaled: true  },
+];
+
+const _ : () = { // crates/xtask/src/bin/data/wm.rs must agree with src/um/winuser/enums/WM.rs
+    assert!(WM::NULL                          .to_u32() == 0x0000);
+    assert!(WM::CREATE                        .to_u32() == 0x0001);
+    assert!(WM::DESTROY                       .to_u32() == 0x0002);
+    assert!(WM::MOVE                          .to_u32() == 0x0003);
+    assert!(WM::SIZE                          .to_u32() == 0x0005);
+    assert!(WM::ACTIVATE                      .to_u32() == 0x0006);
+    assert!(WM::SETFOCUS                      .to_u32() == 0x0007);
+    assert!(WM::KILLFOCUS                     .to_u32() == 0x0008);
+    assert!(WM::ENABLE                        .to_u32() == 0x000A);
+    assert!(WM::SETREDRAW                     .to_u32() == 0x000B);
+    assert!(WM::SETTEXT                       .to_u32() == 0x000C);
+    assert!(WM::GETTEXT                       .to_u32() == 0x000D);
+    assert!(WM::GETTEXTLENGTH                 .to_u32() == 0x000E);
+    assert!(WM::PAINT                         .to_u32() == 0x000F);
+    assert!(WM::CLOSE                         .to_u32() == 0x0010);
+    assert!(WM::QUERYENDSESSION               .to_u32() == 0x0011);
+    assert!(WM::QUERYOPEN                     .to_u32() == 0x0013);
+    assert!(WM::ENDSESSION                    .to_u32() == 0x0016);
+    assert!(WM::QUIT                          .to_u32() == 0x0012);
+    assert!(WM::ERASEBKGND                    .to_u32() == 0x0014);
+    assert!(WM::SYSCOLORCHANGE                .to_u32() == 0x0015);
+    assert!(WM::SHOWWINDOW                    .to_u32() == 0x0018);
+    assert!(WM::WININICHANGE                  .to_u32() == 0x001A);
+    assert!(WM::SETTINGCHANGE                 .to_u32() == 0x001A);
+    assert!(WM::DEVMODECHANGE                 .to_u32() == 0x001B);
+    assert!(WM::ACTIVATEAPP                   .to_u32() == 0x001C);
+    assert!(WM::FONTCHANGE                    .to_u32() == 0x001D);
+    assert!(WM::TIMECHANGE                    .to_u32() == 0x001E);
+    assert!(WM::CANCELMODE                    .to_u32() == 0x001F);
+    assert!(WM::SETCURSOR                     .to_u32() == 0x0020);
+    assert!(WM::MOUSEACTIVATE                 .to_u32() == 0x0021);
+    assert!(WM::CHILDACTIVATE                 .to_u32() == 0x0022);
+    assert!(WM::QUEUESYNC                     .to_u32() == 0x0023);
+    assert!(WM::GETMINMAXINFO                 .to_u32() == 0x0024);
+    assert!(WM::PAINTICON                     .to_u32() == 0x0026);
+    assert!(WM::ICONERASEBKGND                .to_u32() == 0x0027);
+    assert!(WM::NEXTDLGCTL                    .to_u32() == 0x0028);
+    assert!(WM::SPOOLERSTATUS                 .to_u32() == 0x002A);
+    assert!(WM::DRAWITEM                      .to_u32() == 0x002B);
+    assert!(WM::MEASUREITEM                   .to_u32() == 0x002C);
+    assert!(WM::DELETEITEM                    .to_u32() == 0x002D);
+    assert!(WM::VKEYTOITEM                    .to_u32() == 0x002E);
+    assert!(WM::CHARTOITEM                    .to_u32() == 0x002F);
+    assert!(WM::SETFONT                       .to_u32() == 0x0030);
+    assert!(WM::GETFONT                       .to_u32() == 0x0031);
+    assert!(WM::SETHOTKEY                     .to_u32() == 0x0032);
+    assert!(WM::GETHOTKEY                     .to_u32() == 0x0033);
+    assert!(WM::QUERYDRAGICON                 .to_u32() == 0x0037);
+    assert!(WM::COMPAREITEM                   .to_u32() == 0x0039);
+    assert!(WM::GETOBJECT                     .to_u32() == 0x003D);
+    assert!(WM::COMPACTING                    .to_u32() == 0x0041);
+    assert!(WM::COMMNOTIFY                    .to_u32() == 0x0044);
+    assert!(WM::WINDOWPOSCHANGING             .to_u32() == 0x0046);
+    assert!(WM::WINDOWPOSCHANGED              .to_u32() == 0x0047);
+    assert!(WM::POWER                         .to_u32() == 0x0048);
+    assert!(WM::COPYDATA                      .to_u32() == 0x004A);
+    assert!(WM::CANCELJOURNAL                 .to_u32() == 0x004B);
+    assert!(WM::NOTIFY                        .to_u32() == 0x004E);
+    assert!(WM::INPUTLANGCHANGEREQUEST        .to_u32() == 0x0050);
+    assert!(WM::INPUTLANGCHANGE               .to_u32() == 0x0051);
+    assert!(WM::TCARD                         .to_u32() == 0x0052);
+    assert!(WM::HELP                          .to_u32() == 0x0053);
+    assert!(WM::USERCHANGED                   .to_u32() == 0x0054);
+    assert!(WM::NOTIFYFORMAT                  .to_u32() == 0x0055);
+    assert!(WM::CONTEXTMENU                   .to_u32() == 0x007B);
+    assert!(WM::STYLECHANGING                 .to_u32() == 0x007C);
+    assert!(WM::STYLECHANGED                  .to_u32() == 0x007D);
+    assert!(WM::DISPLAYCHANGE                 .to_u32() == 0x007E);
+    assert!(WM::GETICON                       .to_u32() == 0x007F);
+    assert!(WM::SETICON                       .to_u32() == 0x0080);
+    assert!(WM::NCCREATE                      .to_u32() == 0x0081);
+    assert!(WM::NCDESTROY                     .to_u32() == 0x0082);
+    assert!(WM::NCCALCSIZE                    .to_u32() == 0x0083);
+    assert!(WM::NCHITTEST                     .to_u32() == 0x0084);
+    assert!(WM::NCPAINT                       .to_u32() == 0x0085);
+    assert!(WM::NCACTIVATE                    .to_u32() == 0x0086);
+    assert!(WM::GETDLGCODE                    .to_u32() == 0x0087);
+    assert!(WM::SYNCPAINT                     .to_u32() == 0x0088);
+    assert!(WM::NCMOUSEMOVE                   .to_u32() == 0x00A0);
+    assert!(WM::NCLBUTTONDOWN                 .to_u32() == 0x00A1);
+    assert!(WM::NCLBUTTONUP                   .to_u32() == 0x00A2);
+    assert!(WM::NCLBUTTONDBLCLK               .to_u32() == 0x00A3);
+    assert!(WM::NCRBUTTONDOWN                 .to_u32() == 0x00A4);
+    assert!(WM::NCRBUTTONUP                   .to_u32() == 0x00A5);
+    assert!(WM::NCRBUTTONDBLCLK               .to_u32() == 0x00A6);
+    assert!(WM::NCMBUTTONDOWN                 .to_u32() == 0x00A7);
+    assert!(WM::NCMBUTTONUP                   .to_u32() == 0x00A8);
+    assert!(WM::NCMBUTTONDBLCLK               .to_u32() == 0x00A9);
+    assert!(WM::NCXBUTTONDOWN                 .to_u32() == 0x00AB);
+    assert!(WM::NCXBUTTONUP                   .to_u32() == 0x00AC);
+    assert!(WM::NCXBUTTONDBLCLK               .to_u32() == 0x00AD);
+    assert!(WM::INPUT_DEVICE_CHANGE           .to_u32() == 0x00FE);
+    assert!(WM::INPUT                         .to_u32() == 0x00FF);
+    assert!(WM::KEYDOWN                       .to_u32() == 0x0100);
+    assert!(WM::KEYUP                         .to_u32() == 0x0101);
+    assert!(WM::CHAR                          .to_u32() == 0x0102);
+    assert!(WM::DEADCHAR                      .to_u32() == 0x0103);
+    assert!(WM::SYSKEYDOWN                    .to_u32() == 0x0104);
+    assert!(WM::SYSKEYUP                      .to_u32() == 0x0105);
+    assert!(WM::SYSCHAR                       .to_u32() == 0x0106);
+    assert!(WM::SYSDEADCHAR                   .to_u32() == 0x0107);
+    assert!(WM::UNICHAR                       .to_u32() == 0x0109);
+    assert!(WM::IME_STARTCOMPOSITION          .to_u32() == 0x010D);
+    assert!(WM::IME_ENDCOMPOSITION            .to_u32() == 0x010E);
+    assert!(WM::IME_COMPOSITION               .to_u32() == 0x010F);
+    assert!(WM::INITDIALOG                    .to_u32() == 0x0110);
+    assert!(WM::COMMAND                       .to_u32() == 0x0111);
+    assert!(WM::SYSCOMMAND                    .to_u32() == 0x0112);
+    assert!(WM::TIMER                         .to_u32() == 0x0113);
+    assert!(WM::HSCROLL                       .to_u32() == 0x0114);
+    assert!(WM::VSCROLL                       .to_u32() == 0x0115);
+    assert!(WM::INITMENU                      .to_u32() == 0x0116);
+    assert!(WM::INITMENUPOPUP                 .to_u32() == 0x0117);
+    assert!(WM::GESTURE                       .to_u32() == 0x0119);
+    assert!(WM::GESTURENOTIFY                 .to_u32() == 0x011A);
+    assert!(WM::MENUSELECT                    .to_u32() == 0x011F);
+    assert!(WM::MENUCHAR                      .to_u32() == 0x0120);
+    assert!(WM::ENTERIDLE                     .to_u32() == 0x0121);
+    assert!(WM::MENURBUTTONUP                 .to_u32() == 0x0122);
+    assert!(WM::MENUDRAG                      .to_u32() == 0x0123);
+    assert!(WM::MENUGETOBJECT                 .to_u32() == 0x0124);
+    assert!(WM::UNINITMENUPOPUP               .to_u32() == 0x0125);
+    assert!(WM::MENUCOMMAND                   .to_u32() == 0x0126);
+    assert!(WM::CHANGEUISTATE                 .to_u32() == 0x0127);
+    assert!(WM::UPDATEUISTATE                 .to_u32() == 0x0128);
+    assert!(WM::QUERYUISTATE                  .to_u32() == 0x0129);
+    assert!(WM::CTLCOLORMSGBOX                .to_u32() == 0x0132);
+    assert!(WM::CTLCOLOREDIT                  .to_u32() == 0x0133);
+    assert!(WM::CTLCOLORLISTBOX               .to_u32() == 0x0134);
+    assert!(WM::CTLCOLORBTN                   .to_u32() == 0x0135);
+    assert!(WM::CTLCOLORDLG                   .to_u32() == 0x0136);
+    assert!(WM::CTLCOLORSCROLLBAR             .to_u32() == 0x0137);
+    assert!(WM::CTLCOLORSTATIC                .to_u32() == 0x0138);
+    assert!(WM::MN_GETHMENU                   .to_u32() == 0x01E1);
+    assert!(WM::MOUSEMOVE                     .to_u32() == 0x0200);
+    assert!(WM::LBUTTONDOWN                   .to_u32() == 0x0201);
+    assert!(WM::LBUTTONUP                     .to_u32() == 0x0202);
+    assert!(WM::LBUTTONDBLCLK                 .to_u32() == 0x0203);
+    assert!(WM::RBUTTONDOWN                   .to_u32() == 0x0204);
+    assert!(WM::RBUTTONUP                     .to_u32() == 0x0205);
+    assert!(WM::RBUTTONDBLCLK                 .to_u32() == 0x0206);
+    assert!(WM::MBUTTONDOWN                   .to_u32() == 0x0207);
+    assert!(WM::MBUTTONUP                     .to_u32() == 0x0208);
+    assert!(WM::MBUTTONDBLCLK                 .to_u32() == 0x0209);
+    assert!(WM::MOUSEWHEEL                    .to_u32() == 0x020A);
+    assert!(WM::XBUTTONDOWN                   .to_u32() == 0x020B);
+    assert!(WM::XBUTTONUP                     .to_u32() == 0x020C);
+    assert!(WM::XBUTTONDBLCLK                 .to_u32() == 0x020D);
+    assert!(WM::MOUSEHWHEEL                   .to_u32() == 0x020E);
+    assert!(WM::PARENTNOTIFY                  .to_u32() == 0x0210);
+    assert!(WM::ENTERMENULOOP                 .to_u32() == 0x0211);
+    assert!(WM::EXITMENULOOP                  .to_u32() == 0x0212);
+    assert!(WM::NEXTMENU                      .to_u32() == 0x0213);
+    assert!(WM::SIZING                        .to_u32() == 0x0214);
+    assert!(WM::CAPTURECHANGED                .to_u32() == 0x0215);
+    assert!(WM::MOVING                        .to_u32() == 0x0216);
+    assert!(WM::POWERBROADCAST                .to_u32() == 0x0218);
+    assert!(WM::DEVICECHANGE                  .to_u32() == 0x0219);
+    assert!(WM::MDICREATE                     .to_u32() == 0x0220);
+    assert!(WM::MDIDESTROY                    .to_u32() == 0x0221);
+    assert!(WM::MDIACTIVATE                   .to_u32() == 0x0222);
+    assert!(WM::MDIRESTORE                    .to_u32() == 0x0223);
+    assert!(WM::MDINEXT                       .to_u32() == 0x0224);
+    assert!(WM::MDIMAXIMIZE                   .to_u32() == 0x0225);
+    assert!(WM::MDITILE                       .to_u32() == 0x0226);
+    assert!(WM::MDICASCADE                    .to_u32() == 0x0227);
+    assert!(WM::MDIICONARRANGE                .to_u32() == 0x0228);
+    assert!(WM::MDIGETACTIVE                  .to_u32() == 0x0229);
+    assert!(WM::MDISETMENU                    .to_u32() == 0x0230);
+    assert!(WM::ENTERSIZEMOVE                 .to_u32() == 0x0231);
+    assert!(WM::EXITSIZEMOVE                  .to_u32() == 0x0232);
+    assert!(WM::DROPFILES                     .to_u32() == 0x0233);
+    assert!(WM::MDIREFRESHMENU                .to_u32() == 0x0234);
+    assert!(WM::POINTERDEVICECHANGE           .to_u32() == 0x0238);
+    assert!(WM::POINTERDEVICEINRANGE          .to_u32() == 0x0239);
+    assert!(WM::POINTERDEVICEOUTOFRANGE       .to_u32() == 0x023A);
+    assert!(WM::TOUCH                         .to_u32() == 0x0240);
+    assert!(WM::NCPOINTERUPDATE               .to_u32() == 0x0241);
+    assert!(WM::NCPOINTERDOWN                 .to_u32() == 0x0242);
+    assert!(WM::NCPOINTERUP                   .to_u32() == 0x0243);
+    assert!(WM::POINTERUPDATE                 .to_u32() == 0x0245);
+    assert!(WM::POINTERDOWN                   .to_u32() == 0x0246);
+    assert!(WM::POINTERUP                     .to_u32() == 0x0247);
+    assert!(WM::POINTERENTER                  .to_u32() == 0x0249);
+    assert!(WM::POINTERLEAVE                  .to_u32() == 0x024A);
+    assert!(WM::POINTERACTIVATE               .to_u32() == 0x024B);
+    assert!(WM::POINTERCAPTURECHANGED         .to_u32() == 0x024C);
+    assert!(WM::TOUCHHITTESTING               .to_u32() == 0x024D);
+    assert!(WM::POINTERWHEEL                  .to_u32() == 0x024E);
+    assert!(WM::POINTERHWHEEL                 .to_u32() == 0x024F);
+    assert!(WM::DM_POINTERHITTEST             .to_u32() == 0x0250);
+    assert!(WM::POINTERROUTEDTO               .to_u32() == 0x0251);
+    assert!(WM::POINTERROUTEDAWAY             .to_u32() == 0x0252);
+    assert!(WM::POINTERROUTEDRELEASED         .to_u32() == 0x0253);
+    assert!(WM::IME_SETCONTEXT                .to_u32() == 0x0281);
+    assert!(WM::IME_NOTIFY                    .to_u32() == 0x0282);
+    assert!(WM::IME_CONTROL                   .to_u32() == 0x0283);
+    assert!(WM::IME_COMPOSITIONFULL           .to_u32() == 0x0284);
+    assert!(WM::IME_SELECT                    .to_u32() == 0x0285);
+    assert!(WM::IME_CHAR                      .to_u32() == 0x0286);
+    assert!(WM::IME_REQUEST                   .to_u32() == 0x0288);
+    assert!(WM::IME_KEYDOWN                   .to_u32() == 0x0290);
+    assert!(WM::IME_KEYUP                     .to_u32() == 0x0291);
+    assert!(WM::MOUSEHOVER                    .to_u32() == 0x02A1);
+    assert!(WM::MOUSELEAVE                    .to_u32() == 0x02A3);
+    assert!(WM::NCMOUSEHOVER                  .to_u32() == 0x02A0);
+    assert!(WM::NCMOUSELEAVE                  .to_u32() == 0x02A2);
+    assert!(WM::WTSSESSION_CHANGE             .to_u32() == 0x02B1);
+    assert!(WM::DPICHANGED                    .to_u32() == 0x02E0);
+    assert!(WM::DPICHANGED_BEFOREPARENT       .to_u32() == 0x02E2);
+    assert!(WM::DPICHANGED_AFTERPARENT        .to_u32() == 0x02E3);
+    assert!(WM::GETDPISCALEDSIZE              .to_u32() == 0x02E4);
+    assert!(WM::CUT                           .to_u32() == 0x0300);
+    assert!(WM::COPY                          .to_u32() == 0x0301);
+    assert!(WM::PASTE                         .to_u32() == 0x0302);
+    assert!(WM::CLEAR                         .to_u32() == 0x0303);
+    assert!(WM::UNDO                          .to_u32() == 0x0304);
+    assert!(WM::RENDERFORMAT                  .to_u32() == 0x0305);
+    assert!(WM::RENDERALLFORMATS              .to_u32() == 0x0306);
+    assert!(WM::DESTROYCLIPBOARD              .to_u32() == 0x0307);
+    assert!(WM::DRAWCLIPBOARD                 .to_u32() == 0x0308);
+    assert!(WM::PAINTCLIPBOARD                .to_u32() == 0x0309);
+    assert!(WM::VSCROLLCLIPBOARD              .to_u32() == 0x030A);
+    assert!(WM::SIZECLIPBOARD                 .to_u32() == 0x030B);
+    assert!(WM::ASKCBFORMATNAME               .to_u32() == 0x030C);
+    assert!(WM::CHANGECBCHAIN                 .to_u32() == 0x030D);
+    assert!(WM::HSCROLLCLIPBOARD              .to_u32() == 0x030E);
+    assert!(WM::QUERYNEWPALETTE               .to_u32() == 0x030F);
+    assert!(WM::PALETTEISCHANGING             .to_u32() == 0x0310);
+    assert!(WM::PALETTECHANGED                .to_u32() == 0x0311);
+    assert!(WM::HOTKEY                        .to_u32() == 0x0312);
+    assert!(WM::PRINT                         .to_u32() == 0x0317);
+    assert!(WM::PRINTCLIENT                   .to_u32() == 0x0318);
+    assert!(WM::APPCOMMAND                    .to_u32() == 0x0319);
+    assert!(WM::THEMECHANGED                  .to_u32() == 0x031A);
+    assert!(WM::CLIPBOARDUPDATE               .to_u32() == 0x031D);
+    assert!(WM::DWMCOMPOSITIONCHANGED         .to_u32() == 0x031E);
+    assert!(WM::DWMNCRENDERINGCHANGED         .to_u32() == 0x031F);
+    assert!(WM::DWMCOLORIZATIONCOLORCHANGED   .to_u32() == 0x0320);
+    assert!(WM::DWMWINDOWMAXIMIZEDCHANGE      .to_u32() == 0x0321);
+    assert!(WM::DWMSENDICONICTHUMBNAIL        .to_u32() == 0x0323);
+    assert!(WM::DWMSENDICONICLIVEPREVIEWBITMAP.to_u32() == 0x0326);
+    assert!(WM::GETTITLEBARINFOEX             .to_u32() == 0x033F);
+    assert!(WM::UAHDESTROYWINDOW              .to_u32() == 0x0090);
+    assert!(WM::UAHDRAWMENU                   .to_u32() == 0x0091);
+    assert!(WM::UAHDRAWMENUITEM               .to_u32() == 0x0092);
+    assert!(WM::UAHINITMENU                   .to_u32() == 0x0093);
+    assert!(WM::UAHMEASUREMENUITEM            .to_u32() == 0x0094);
+    assert!(WM::UAHNCPAINTMENUPOPUP           .to_u32() == 0x0095);
+};
@@ -1,3 +1,34 @@
-//! { [local] }: Associate [HWnd](crate::HWnd)s with other data
+//! { [global], [local], [prop] }: Associate [HWnd](crate::HWnd)s with other data
+//!
+//! ### Teardown
+//! When an [HWnd] is destroyed (or when its thread exits while the [HWnd] still exists), associated data is dropped:
+//! *   In reverse slot registration order (slots are registered when first used.)
+//! *   "Early" slots before [WM::DESTROY](crate::WM::DESTROY), "late" slots after [WM::NCDESTROY](crate::WM::NCDESTROY).
+//! *   At thread exit, windows are torn down in reverse order of when data was first associated with them.
+//! *   Panics from `Drop` are caught and reported (see [CallbackPanic](crate::CallbackPanic)), and never unwind through the window hooks.
+//!     The remaining data is still dropped.
 
+use crate::{HWnd, report_callback_panic};
+use std::panic::*;
+
+pub mod global;
 pub mod local;
+pub mod prop;
+
+
+
+/// Drop `values` in reverse order, catching and reporting any panics.
+fn drop_in_reverse<T>(hwnd: HWnd, values: Vec<T>) {
+    for value in values.into_iter().rev() {
+        if let Err(panic) = catch_unwind(AssertUnwindSafe(move || drop(value))) {
+            report_callback_panic("dropping associated data", hwnd, panic);
+        }
+    }
+}
+
+/// Run `f`, catching and reporting any panics.
+fn catch_and_report(hwnd: HWnd, context: &str, f: impl FnOnce()) {
+    if let Err(panic) = catch_unwind(AssertUnwindSafe(f)) {
+        report_callback_panic(context, hwnd, panic);
+    }
+}
@@ -0,0 +1,356 @@
+//! Associate data with an [HWnd] in a process-wide manner.
+//!
+//! Unlike [assoc::local](crate::assoc::local), data can be accessed from any thread of the process that owns the [HWnd].
+//! Data is still dropped by the thread that owns the [HWnd], from it's window hooks, when the [HWnd] is destroyed.
+//! Those hooks are installed on a thread the first time it uses `assoc` (including [prepare_thread], or [Slot::set] on one of it's own windows.)
+//!
+//! ### Common Errors
+//! *   [ERROR::INVALID_WINDOW_HANDLE]      if an [HWnd] is invalid (or, for "early" slots, is being destroyed)
+//! *   [ERROR::WINDOW_OF_OTHER_THREAD]     if an [HWnd] belongs to another process
+//! *   [ERROR::HOOK_NOT_INSTALLED]         if setting data for an [HWnd] whose thread hasn't used `assoc` yet (see [prepare_thread])
+//! *   [ERROR::DATATYPE_MISMATCH]          if the existing/previous value associated with a [HWnd] didn't match the expected type (a bug?)
+//!
+//! ### Example
+//! ```
+//! # use hwnd::*;
+//! # use winresult::*;
+//! # use std::ptr::*;
+//! use hwnd::assoc::global::*;
+//!
+//! static SIZE : Slot<(i32, i32)> = Slot::new_drop_late();
+//!
+//! let (send, recv) = std::sync::mpsc::channel();
+//! let worker = std::thread::spawn(move ||{
+//!     let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+//!     assert_eq!(None, SIZE.set(hwnd, (800, 600)).unwrap());
+//!     send.send(hwnd).unwrap();
+//!     // ...keep the window alive for a bit...
+//! #   std::thread::sleep(std::time::Duration::from_millis(100));
+//! });
+//!
+//! let worker_hwnd = recv.recv().unwrap();
+//! assert_eq!(Some((800, 600)),              SIZE.get_copy(worker_hwnd).unwrap());
+//! assert_eq!(ERROR::WINDOW_OF_OTHER_THREAD, SIZE.get_copy(get_desktop_window()).unwrap_err());
+//! assert_eq!(ERROR::INVALID_WINDOW_HANDLE,  SIZE.get_copy(HWnd::NULL).unwrap_err());
+//! # worker.join().unwrap();
+//! ```
+
+use crate::*;
+
+use std::any::*;
+use std::collections::*;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::*;
+use std::marker::*;
+use std::sync::{*, atomic::{*, Ordering::*}};
+
+
+
+/// A typed slot that can be used to set or retrieve data associated with an [HWnd], from any thread.
+pub struct Slot<T: Send + Sync + 'static> {
+    ty:             SlotType,
+    dense_slot_no:  AtomicUsize, // 0 => not set, N => dense_slot_idx = N-1
+    pd:             PhantomData<fn(T) -> T>,
+}
+
+impl<T: Send + Sync + 'static> Slot<T> {
+    /// Associated data will be dropped early (before [WM::DESTROY] or [WM::NCDESTROY]) when a window is [destroyed](destroy_window).
+    ///
+    /// Said data will not be accessible from within [WM::DESTROY], [WM::NCDESTROY], or similar events as a result - from any thread.
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// use hwnd::assoc::global::*;
+    ///
+    /// static SLOT : Slot<&'static str> = Slot::new_drop_early();
+    /// ```
+    pub const fn new_drop_early() -> Self { Self::new_impl(SlotType::DenseDropEarly) }
+
+    /// Associated data will be dropped late (after [WM::DESTROY] and [WM::NCDESTROY]) when a window is [destroyed](destroy_window).
+    ///
+    /// Said data will generally be accessible from within [WM::DESTROY] or [WM::NCDESTROY].
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// use hwnd::assoc::global::*;
+    ///
+    /// static SLOT : Slot<&'static str> = Slot::new_drop_late();
+    /// ```
+    pub const fn new_drop_late()  -> Self { Self::new_impl(SlotType::DenseDropLate) }
+
+    /// Get the data for this slot associated with `hwnd`.
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid (or, for "early" slots, is being destroyed)
+    /// *   [ERROR::WINDOW_OF_OTHER_THREAD]     if `hwnd` belongs to another process
+    /// *   [ERROR::DATATYPE_MISMATCH]          if the slot isn't a `T` (bug?)
+    pub fn get_copy(&'static self, hwnd: HWnd) -> Result<Option<T>, Error> where T : Copy { self.get_clone(hwnd) }
+
+    /// Get (and [Clone::clone]) the data for this slot associated with `hwnd`.
+    ///
+    /// `T::clone` is called while a (shared) lock is held: it shouldn't [set](Self::set) global slots of windows itself.
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid (or, for "early" slots, is being destroyed)
+    /// *   [ERROR::WINDOW_OF_OTHER_THREAD]     if `hwnd` belongs to another process
+    /// *   [ERROR::DATATYPE_MISMATCH]          if the slot isn't a `T` (bug?)
+    pub fn get_clone(&'static self, hwnd: HWnd) -> Result<Option<T>, Error> where T : Clone {
+        fn_context!(assoc::global::Slot::get_clone);
+        check_window_process_local(hwnd)?;
+
+        let slot_idx = self.slot_idx();
+        let global = Global::get();
+        let forbid_destroying = global.any_early.load(Acquire) && global.dense_slots.read().unwrap()[slot_idx].drop_early;
+
+        let stripe = global.stripe(hwnd).read().unwrap();
+        let pw = match stripe.get(&hwnd) {
+            None        => return Ok(None),
+            Some(pw)    => pw,
+        };
+
+        if forbid_destroying && pw.destroying { return fn_err!(ERROR::INVALID_WINDOW_HANDLE) }
+
+        match pw.dense_slots.get(slot_idx) {
+            None                => Ok(None), // slot_idx >= dense_slots.len()
+            Some(None)          => Ok(None), // dense_slots[slot_idx].is_none()
+            Some(Some(slot))    => match slot.downcast_ref::<T>() {
+                Some(slot_t)    => Ok(Some(slot_t.clone())),
+                None            => fn_err!(ERROR::DATATYPE_MISMATCH),
+            },
+        }
+    }
+
+    /// Set the data for this slot associated with `hwnd`.
+    ///
+    /// ### Returns
+    /// *   Ok(Some(previous_value))    if the `hwnd` previously had a value for this slot
+    /// *   Ok(None)                    if the `hwnd` had no previous value for this slot
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid (or, for "early" slots, is being destroyed)
+    /// *   [ERROR::WINDOW_OF_OTHER_THREAD]     if `hwnd` belongs to another process
+    /// *   [ERROR::HOOK_NOT_INSTALLED]         if `hwnd` belongs to another thread, which hasn't used `assoc` yet (see [prepare_thread])
+    /// *   [ERROR::DATATYPE_MISMATCH]          if the previous value in the slot isn't a `T` (bug?)
+    pub fn set(&'static self, hwnd: HWnd, value: T) -> Result<Option<T>, Error> {
+        fn_context!(assoc::global::Slot::set);
+        let thread = check_window_process_local(hwnd)?;
+        let local = thread == get_current_thread_id();
+        if local { prepare_thread() }
+
+        let value : Box<dyn Any + Send + Sync> = Box::new(value);
+        let slot_idx = self.slot_idx();
+        let global = Global::get();
+        let forbid_destroying = global.any_early.load(Acquire) && global.dense_slots.read().unwrap()[slot_idx].drop_early;
+
+        let prev = {
+            let mut stripe = global.stripe(hwnd).write().unwrap();
+
+            // Re-checked while holding the stripe lock: on_thread_unhooked and after_wm_nc_destroy clear the stripe under it,
+            // so checking first and inserting later could orphan `value` under a dead (or reused) `hwnd`.
+            if !local && !global.hooked_threads.lock().unwrap().contains(&thread) {
+                Err((value, ERROR::HOOK_NOT_INSTALLED))
+            } else if get_window_thread_process_id(hwnd).map_or(true, |(tid, _)| tid != thread) {
+                Err((value, ERROR::INVALID_WINDOW_HANDLE))
+            } else {
+                let pw = stripe.entry(hwnd).or_insert_with(|| PerWindow { thread, order: global.next_order.fetch_add(1, Relaxed), destroying: false, dense_slots: Vec::new() });
+                if forbid_destroying && pw.destroying {
+                    Err((value, ERROR::INVALID_WINDOW_HANDLE))
+                } else {
+                    if slot_idx >= pw.dense_slots.len() { pw.dense_slots.resize_with(slot_idx+1, || None) }
+                    Ok(pw.dense_slots[slot_idx].replace(value))
+                }
+            }
+        }; // unlock before dropping `value` or `prev`
+
+        let prev = match prev {
+            Ok(prev)            => prev,
+            Err((value, err))   => { drop(value); return fn_err!(err) },
+        };
+
+        match prev.map(|p| p.downcast::<T>()) {
+            Some(Ok(prev))  => Ok(Some(*prev)),
+            Some(Err(_))    => fn_err!(ERROR::DATATYPE_MISMATCH),
+            None            => Ok(None),
+        }
+    }
+
+    const fn new_impl(ty: SlotType) -> Self {
+        Self {
+            ty,
+            dense_slot_no:  AtomicUsize::new(0),
+            pd:             PhantomData,
+        }
+    }
+
+    fn slot_idx(&'static self) -> usize { self.slot_no() - 1 }
+    fn slot_no(&'static self) -> usize {
+        // https://en.wikipedia.org/wiki/Double-checked_locking
+
+        let s = self.dense_slot_no.load(Acquire);
+        if s != 0 { return s }
+
+        let g = Global::get();
+        let mut g_dense_slots = g.dense_slots.write().unwrap(); // XXX: avoid panicing?
+        let s = self.dense_slot_no.load(Relaxed);
+        if s != 0 { return s }
+
+        let drop_early = match self.ty {
+            SlotType::DenseDropEarly    => true,
+            SlotType::DenseDropLate     => false,
+        };
+
+        if drop_early   { g.any_early.store(true, Release); }
+        else            { g.any_late .store(true, Release); }
+
+        g_dense_slots.push(DenseSlotMeta { drop_early });
+        let slot_no = g_dense_slots.len();
+        self.dense_slot_no.store(slot_no, Release);
+        slot_no
+    }
+}
+
+/// Install `assoc`'s window hooks on the current thread, if they aren't already installed.
+///
+/// Other threads can only [set](Slot::set) data for this thread's windows after this has been called
+/// (or after this thread has otherwise used `assoc`), as the hooks are responsible for dropping said data.
+pub fn prepare_thread() { super::local::install_hooks() }
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)] enum SlotType {
+    DenseDropEarly,
+    DenseDropLate,
+}
+
+
+
+const STRIPES : usize = 16;
+
+#[derive(Default)]
+struct Global {
+    any_early:      AtomicBool,
+    any_late:       AtomicBool,
+    dense_slots:    RwLock<Vec<DenseSlotMeta>>,
+    per_window:     [RwLock<HashMap<HWnd, PerWindow>>; STRIPES],
+    hooked_threads: Mutex<HashSet<u32>>,
+    next_order:     AtomicU64,
+}
+
+impl Global {
+    fn get() -> &'static Self {
+        lazy_static::lazy_static! { static ref G : Global = Global::default(); }
+        &G
+    }
+
+    fn any(&self) -> bool { self.any_early.load(Acquire) || self.any_late.load(Acquire) }
+
+    fn stripe(&self, hwnd: HWnd) -> &RwLock<HashMap<HWnd, PerWindow>> {
+        let mut h = DefaultHasher::new();
+        hwnd.hash(&mut h);
+        &self.per_window[h.finish() as usize % STRIPES]
+    }
+}
+
+
+
+struct DenseSlotMeta {
+    drop_early: bool,
+}
+
+
+
+struct PerWindow {
+    thread:         u32,
+    order:          u64, // creation order, for deterministic teardown at thread exit
+    destroying:     bool,
+    dense_slots:    Vec<Option<Box<dyn Any + Send + Sync + 'static>>>,
+}
+
+impl PerWindow {
+    /// Drop early slots, then late slots, each in reverse registration order.
+    fn drop_slots(self, hwnd: HWnd) {
+        let g_dense_slots = Global::get().dense_slots.read().unwrap();
+        let (mut early, mut late) = (Vec::new(), Vec::new());
+        for (g, s) in g_dense_slots.iter().zip(self.dense_slots) {
+            if g.drop_early { early.push(s) } else { late.push(s) }
+        }
+        drop(g_dense_slots);
+        super::drop_in_reverse(hwnd, early);
+        super::drop_in_reverse(hwnd, late);
+    }
+}
+
+
+
+/// Require [get_current_process_id]\(\) == [get_window_process_id]\(hwnd\), returning [get_window_thread_id]\(hwnd\)
+fn check_window_process_local(hwnd: HWnd) -> Result<u32, Error> {
+    fn_context!(assoc::global::check_window_process_local => GetWindowThreadProcessId);
+    let (tid, pid) = get_window_thread_process_id(hwnd)?;
+    if get_current_process_id() != pid { return fn_err!(ERROR::WINDOW_OF_OTHER_THREAD) }
+    Ok(tid)
+}
+
+/// Called when the current thread installs it's window hooks
+pub(super) fn on_thread_hooked()   { Global::get().hooked_threads.lock().unwrap().insert(get_current_thread_id()); }
+
+/// Called when the current thread uninstalls it's window hooks (e.g. at thread exit, before it's windows are destroyed)
+pub(super) fn on_thread_unhooked() {
+    let g = Global::get();
+    let thread = get_current_thread_id();
+    g.hooked_threads.lock().unwrap().remove(&thread);
+    if !g.any() { return }
+
+    // Without hooks, WM::NCDESTROY won't be seen - drop everything now, rather than leaking it (or worse, exposing it to a reused HWND.)
+    let mut orphaned = Vec::new();
+    for stripe in g.per_window.iter() {
+        let mut stripe = stripe.write().unwrap();
+        let hwnds = stripe.iter().filter(|(_, pw)| pw.thread == thread).map(|(hwnd, _)| *hwnd).collect::<Vec<_>>();
+        orphaned.extend(hwnds.into_iter().filter_map(|hwnd| Some((hwnd, stripe.remove(&hwnd)?))));
+    }
+    // dropped without holding any locks, in case Drop accesses other slots - most recently associated windows first
+    orphaned.sort_by_key(|(_, pw)| std::cmp::Reverse(pw.order));
+    for (hwnd, pw) in orphaned { pw.drop_slots(hwnd) }
+}
+
+/// Called before [WM::GETMINMAXINFO] / [WM::NCCREATE]
+pub(super) fn before_wm_create(hwnd: HWnd) {
+    let g = Global::get();
+    if !g.any() { return }
+    if let Some(pw) = g.stripe(hwnd).write().unwrap().get_mut(&hwnd) {
+        // slot/handle was reused
+        pw.destroying = false;
+    }
+}
+
+/// Called before [WM::DESTROY]
+pub(super) fn before_wm_destroy(hwnd: HWnd) {
+    let g = Global::get();
+    if !g.any() { return }
+
+    let early = {
+        let mut stripe = g.stripe(hwnd).write().unwrap();
+        let pw = match stripe.get_mut(&hwnd) {
+            None        => return,
+            Some(pw)    => pw,
+        };
+        pw.destroying = true;
+        if !g.any_early.load(Acquire) { return }
+
+        let g_dense_slots = g.dense_slots.read().unwrap();
+        g_dense_slots.iter().zip(pw.dense_slots.iter_mut()).filter(|(g, _)| g.drop_early).filter_map(|(_, s)| s.take()).collect::<Vec<_>>()
+    };
+
+    // dropped without holding any locks, in case Drop accesses other slots
+    super::drop_in_reverse(hwnd, early);
+}
+
+/// Called after [WM::NCDESTROY]
+pub(super) fn after_wm_nc_destroy(hwnd: HWnd) {
+    let g = Global::get();
+    if !g.any() { return }
+    let pw = g.stripe(hwnd).write().unwrap().remove(&hwnd);
+    // dropped without holding any locks, in case Drop accesses other slots
+    if let Some(pw) = pw { super::drop_in_reverse(hwnd, pw.dense_slots) }
+}
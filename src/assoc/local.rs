@@ -3,6 +3,7 @@
 //! ### Common Errors
 //! *   [ERROR::INVALID_WINDOW_HANDLE]      if an [HWnd] is invalid (or, for "early" slots, is being destroyed)
 //! *   [ERROR::WINDOW_OF_OTHER_THREAD]     if an [HWnd] belongs to another thread or process
+//! *   [ERROR::BUSY]                       if a slot of an [HWnd] is already in use (reentrant access from within [Slot::with] or similar)
 //! *   [ERROR::DATATYPE_MISMATCH]          if the existing/previous value associated with a [HWnd] didn't match the expected type (a bug?)
 //!
 //! ### Example
@@ -64,6 +65,7 @@ impl<T: 'static> Slot<T> {
     ///
     /// Said data will, obviously, not be accessible from within [WM::DESTROY], [WM::NCDESTROY], or similar events as a result.
     /// This can sanely be used for e.g. references to Direct3D devices and resources that should simply be dropped before a window is destroyed.
+    /// Early slots are dropped in reverse registration order (see [Teardown](super#teardown).)
     ///
     /// ### Example
     /// ```
@@ -79,6 +81,7 @@ impl<T: 'static> Slot<T> {
     /// Said data will generally be accessible from within [WM::DESTROY] or [WM::NCDESTROY].
     /// You might use this for data you need to manually handle the destruction of within [WM::DESTROY].
     /// It's worth noting that in some edge cases (e.g. when calling [destroy_window] from within a [destroy_window] of the same hwnd) the data will have been removed anyways.
+    /// Late slots are dropped in reverse registration order, after all early slots (see [Teardown](super#teardown).)
     ///
     /// ### Example
     /// ```
@@ -89,13 +92,12 @@ impl<T: 'static> Slot<T> {
     /// ```
     pub const fn new_drop_late()  -> Self { Self::new_impl(SlotType::DenseDropLate) }
 
-    // TODO: set_new, \*_ref?, ...
-
     /// Get the data for this slot associated with `hwnd`.
     ///
     /// ### Errors
     /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid (or, for "early" slots, is being destroyed)
     /// *   [ERROR::WINDOW_OF_OTHER_THREAD]     if `hwnd` belongs to another thread or process
+    /// *   [ERROR::BUSY]                       if this slot of `hwnd` is in use (reentrant access, see [with](Self::with))
     /// *   [ERROR::DATATYPE_MISMATCH]          if the slot isn't a `T` (bug?)
     ///
     /// ### Example
@@ -132,6 +134,7 @@ impl<T: 'static> Slot<T> {
     /// ### Errors
     /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid (or, for "early" slots, is being destroyed)
     /// *   [ERROR::WINDOW_OF_OTHER_THREAD]     if `hwnd` belongs to another thread or process
+    /// *   [ERROR::BUSY]                       if this slot of `hwnd` is in use (reentrant access, see [with](Self::with))
     /// *   [ERROR::DATATYPE_MISMATCH]          if the slot isn't a `T` (bug?)
     ///
     /// ### Example
@@ -162,42 +165,162 @@ impl<T: 'static> Slot<T> {
     /// # }).join().unwrap();
     /// ```
     pub fn get_clone(&'static self, hwnd: HWnd) -> Result<Option<T>, Error> where T : Clone {
-        check_window_thread_local(hwnd)?;
+        self.with(hwnd, |value| value.cloned())
+    }
 
-        let slot_idx = self.slot_idx();
-        ThreadLocal::with(move |tl| {
-            let forbid_destroying = tl.global.any_early.load(Acquire) && tl.global.dense_slots.read().unwrap()[slot_idx].drop_early;
+    /// Borrow the data for this slot associated with `hwnd`, for the duration of `f`.
+    ///
+    /// While `f` runs, this slot of `hwnd` is "in use": reentrant attempts to access it (e.g. from a wndproc invoked by `f`) fail with [ERROR::BUSY] instead of panicing.
+    /// Other slots, and this slot of other windows, remain accessible.
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid (or, for "early" slots, is being destroyed)
+    /// *   [ERROR::WINDOW_OF_OTHER_THREAD]     if `hwnd` belongs to another thread or process
+    /// *   [ERROR::BUSY]                       if this slot of `hwnd` is already in use (reentrant access)
+    /// *   [ERROR::DATATYPE_MISMATCH]          if the slot isn't a `T` (bug?)
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// # use winresult::*;
+    /// # use std::ptr::*;
+    /// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+    /// use hwnd::assoc::local::*;
+    ///
+    /// static SLOT : Slot<String> = Slot::new_drop_late();
+    ///
+    /// assert_eq!(None, SLOT.with(hwnd, |s| s.map(|s| s.len())).unwrap());
+    /// SLOT.set(hwnd, String::from("slot")).unwrap();
+    /// assert_eq!(Some(4), SLOT.with(hwnd, |s| s.map(|s| s.len())).unwrap());
+    ///
+    /// SLOT.with(hwnd, |_| {
+    ///     assert_eq!(ERROR::BUSY, SLOT.set(hwnd, String::from("reentrant")).unwrap_err());
+    /// }).unwrap();
+    /// ```
+    pub fn with<R>(&'static self, hwnd: HWnd, f: impl FnOnce(Option<&T>) -> R) -> Result<R, Error> {
+        let lent = self.lend(hwnd)?;
+        Ok(f(lent.value.as_deref()))
+    }
 
-            let pw = tl.per_window.borrow();
-            let pw = match pw.get(&hwnd) {
-                None        => return Ok(None),
-                Some(pw)    => pw,
-            };
+    /// Mutably borrow the data for this slot associated with `hwnd`, for the duration of `f`.
+    ///
+    /// While `f` runs, this slot of `hwnd` is "in use": reentrant attempts to access it (e.g. from a wndproc invoked by `f`) fail with [ERROR::BUSY] instead of panicing.
+    /// Other slots, and this slot of other windows, remain accessible.
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid (or, for "early" slots, is being destroyed)
+    /// *   [ERROR::WINDOW_OF_OTHER_THREAD]     if `hwnd` belongs to another thread or process
+    /// *   [ERROR::BUSY]                       if this slot of `hwnd` is already in use (reentrant access)
+    /// *   [ERROR::DATATYPE_MISMATCH]          if the slot isn't a `T` (bug?)
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// # use winresult::*;
+    /// # use std::ptr::*;
+    /// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+    /// use hwnd::assoc::local::*;
+    ///
+    /// static CLICKS : Slot<u32> = Slot::new_drop_late();
+    ///
+    /// CLICKS.set(hwnd, 1).unwrap();
+    /// CLICKS.with_mut(hwnd, |c| *c.unwrap() += 1).unwrap();
+    /// assert_eq!(Some(2), CLICKS.get_copy(hwnd).unwrap());
+    /// ```
+    pub fn with_mut<R>(&'static self, hwnd: HWnd, f: impl FnOnce(Option<&mut T>) -> R) -> Result<R, Error> {
+        let mut lent = self.lend(hwnd)?;
+        Ok(f(lent.value.as_deref_mut()))
+    }
 
-            if forbid_destroying && pw.destroying.get() { return fn_err!(ERROR::INVALID_WINDOW_HANDLE) }
-
-            let pw_dense_slots = pw.dense_slots.borrow();
-            match pw_dense_slots.get(slot_idx) {
-                None                => Ok(None), // slot_idx >= dense_slots.len()
-                Some(None)          => Ok(None), // dense_slots[slot_idx].is_none()
-                Some(Some(slot))    => {
-                    if let Some(slot_t) = slot.downcast_ref::<T>() {
-                        Ok(Some((*slot_t).clone()))
-                    } else {
-                        fn_err!(ERROR::DATATYPE_MISMATCH)
-                        // Considered these error codes:
-                        //  ERROR::DATATYPE_MISMATCH    Chosen!
-                        //  ERROR::INVALID_HANDLE       Kinda makes sense?  Less specific tho.
-                        //  ERROR::INVALID_DATATYPE     nah: The datatype is valid, just mismatched?
-                        //  ERROR::BAD_TOKEN_TYPE       nah: The token *category* (dense thread local early/late slot) is fine, just what the token references is bad
-                        //  ERROR::UNSUPPORTED_TYPE     nah: The type is supported, there's just a mismatch
-                    }
-                }
-            }
-        })
+    /// Get the data for this slot associated with `hwnd`, or initialize it with `init()` if unset.
+    ///
+    /// This slot of `hwnd` is "in use" while `init` runs: reentrant attempts to access it fail with [ERROR::BUSY].
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid (or, for "early" slots, is being destroyed)
+    /// *   [ERROR::WINDOW_OF_OTHER_THREAD]     if `hwnd` belongs to another thread or process
+    /// *   [ERROR::BUSY]                       if this slot of `hwnd` is already in use (reentrant access)
+    /// *   [ERROR::DATATYPE_MISMATCH]          if the slot isn't a `T` (bug?)
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// # use std::ptr::*;
+    /// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+    /// use hwnd::assoc::local::*;
+    ///
+    /// static SLOT : Slot<u32> = Slot::new_drop_late();
+    ///
+    /// assert_eq!(1, SLOT.get_or_insert_with(hwnd, || 1).unwrap());
+    /// assert_eq!(1, SLOT.get_or_insert_with(hwnd, || 2).unwrap());
+    /// ```
+    pub fn get_or_insert_with(&'static self, hwnd: HWnd, init: impl FnOnce() -> T) -> Result<T, Error> where T : Clone {
+        let mut lent = self.lend(hwnd)?;
+        Ok((**lent.value.get_or_insert_with(|| Box::new(init()))).clone())
     }
 
-    /// Get the data for this slot associated with `hwnd`.
+    /// Remove the data for this slot associated with `hwnd`.
+    ///
+    /// ### Returns
+    /// *   Ok(Some(previous_value))    if the `hwnd` previously had a value for this slot
+    /// *   Ok(None)                    if the `hwnd` had no previous value for this slot
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid (or, for "early" slots, is being destroyed)
+    /// *   [ERROR::WINDOW_OF_OTHER_THREAD]     if `hwnd` belongs to another thread or process
+    /// *   [ERROR::BUSY]                       if this slot of `hwnd` is in use (reentrant access)
+    /// *   [ERROR::DATATYPE_MISMATCH]          if the previous value in the slot isn't a `T` (bug?)
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// # use std::ptr::*;
+    /// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+    /// use hwnd::assoc::local::*;
+    ///
+    /// static SLOT : Slot<&'static str> = Slot::new_drop_late();
+    ///
+    /// SLOT.set(hwnd, "slot").unwrap();
+    /// assert_eq!(Some("slot"), SLOT.take(hwnd).unwrap());
+    /// assert_eq!(None,         SLOT.take(hwnd).unwrap());
+    /// ```
+    pub fn take(&'static self, hwnd: HWnd) -> Result<Option<T>, Error> {
+        let mut lent = self.lend(hwnd)?;
+        Ok(lent.value.take().map(|v| *v))
+    }
+
+    /// Set the data for this slot associated with `hwnd`, if it was previously unset.
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid (or, for "early" slots, is being destroyed)
+    /// *   [ERROR::WINDOW_OF_OTHER_THREAD]     if `hwnd` belongs to another thread or process
+    /// *   [ERROR::ALREADY_EXISTS]             if `hwnd` already had a value for this slot (`value` is dropped)
+    /// *   [ERROR::BUSY]                       if this slot of `hwnd` is in use (reentrant access)
+    /// *   [ERROR::DATATYPE_MISMATCH]          if the previous value in the slot isn't a `T` (bug?)
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// # use winresult::*;
+    /// # use std::ptr::*;
+    /// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+    /// use hwnd::assoc::local::*;
+    ///
+    /// static SLOT : Slot<&'static str> = Slot::new_drop_late();
+    ///
+    /// SLOT.set_new(hwnd, "first").unwrap();
+    /// assert_eq!(ERROR::ALREADY_EXISTS, SLOT.set_new(hwnd, "second").unwrap_err());
+    /// assert_eq!(Some("first"), SLOT.get_copy(hwnd).unwrap());
+    /// ```
+    pub fn set_new(&'static self, hwnd: HWnd, value: T) -> Result<(), Error> {
+        fn_context!(assoc::local::Slot::set_new);
+        let mut lent = self.lend(hwnd)?;
+        if lent.value.is_some() { return fn_err!(ERROR::ALREADY_EXISTS) }
+        lent.value = Some(Box::new(value));
+        Ok(())
+    }
+
+    /// Set the data for this slot associated with `hwnd`.
     ///
     /// ### Returns
     /// *   Ok(Some(previous_value))    if the `hwnd` previously had a value for this slot
@@ -206,6 +329,7 @@ impl<T: 'static> Slot<T> {
     /// ### Errors
     /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid (or, for "early" slots, is being destroyed)
     /// *   [ERROR::WINDOW_OF_OTHER_THREAD]     if `hwnd` belongs to another thread or process
+    /// *   [ERROR::BUSY]                       if this slot of `hwnd` is in use (reentrant access, see [with](Self::with))
     /// *   [ERROR::DATATYPE_MISMATCH]          if the previous value in the slot isn't a `T` (bug?)
     ///
     /// ### Example
@@ -237,30 +361,8 @@ impl<T: 'static> Slot<T> {
     /// # }).join().unwrap();
     /// ```
     pub fn set(&'static self, hwnd: HWnd, value: T) -> Result<Option<T>, Error> {
-        check_window_thread_local(hwnd)?;
-        let value = Box::new(value);
-
-        let slot_idx = self.slot_idx();
-        let prev = ThreadLocal::with(move |tl| {
-            let forbid_destroying = tl.global.any_early.load(Acquire) && tl.global.dense_slots.read().unwrap()[slot_idx].drop_early;
-
-            let mut pw = tl.per_window.borrow_mut();
-            let pw = pw.entry(hwnd).or_default();
-
-            if forbid_destroying && pw.destroying.get() { return fn_err!(ERROR::INVALID_WINDOW_HANDLE) }
-
-            let mut pw_dense_slots = pw.dense_slots.borrow_mut();
-            if slot_idx >= pw_dense_slots.len() { pw_dense_slots.resize_with(slot_idx+1, || None) }
-            let pw_dense_slot = &mut pw_dense_slots[slot_idx];
-
-            Ok(pw_dense_slot.replace(value))
-        })?;
-
-        match prev.map(|p| p.downcast::<T>()) {
-            Some(Ok(prev))  => Ok(Some(*prev)),
-            Some(Err(_))    => fn_err!(ERROR::DATATYPE_MISMATCH),
-            None            => Ok(None),
-        }
+        let mut lent = self.lend(hwnd)?;
+        Ok(lent.value.replace(Box::new(value)).map(|prev| *prev))
     }
 
     const fn new_impl(ty: SlotType) -> Self {
@@ -271,6 +373,51 @@ impl<T: 'static> Slot<T> {
         }
     }
 
+    /// Move the value out of this slot of `hwnd`, marking the slot as in use until the returned [Lent] is dropped.
+    ///
+    /// No `RefCell` borrows are held while the value is lent out, so user code (`Drop`, `Clone`, closures) can safely reenter.
+    fn lend(&'static self, hwnd: HWnd) -> Result<Lent<T>, Error> {
+        fn_context!(assoc::local::Slot::lend);
+        check_window_thread_local(hwnd)?;
+
+        let slot_idx = self.slot_idx();
+        ThreadLocal::with(move |tl| {
+            let drop_early = tl.global.any_early.load(Acquire) && tl.global.dense_slots.read().unwrap()[slot_idx].drop_early;
+
+            let value = {
+                let mut pw = tl.per_window.try_borrow_mut().map_err(|_| fn_error!(ERROR::BUSY))?;
+                let pw = pw.entry(hwnd).or_insert_with(|| PerWindow { order: tl.next_order.replace(tl.next_order.get() + 1), ..Default::default() });
+
+                if drop_early && pw.destroying.get() { return fn_err!(ERROR::INVALID_WINDOW_HANDLE) }
+
+                let mut pw_dense_slots = pw.dense_slots.try_borrow_mut().map_err(|_| fn_error!(ERROR::BUSY))?;
+                if slot_idx >= pw_dense_slots.len() { pw_dense_slots.resize_with(slot_idx+1, Default::default) }
+                match std::mem::replace(&mut pw_dense_slots[slot_idx], DenseSlot::InUse) {
+                    DenseSlot::InUse        => return fn_err!(ERROR::BUSY),
+                    DenseSlot::Empty        => None,
+                    DenseSlot::Value(value) => Some(value),
+                }
+            };
+
+            let mut lent = Lent { hwnd, slot_idx, drop_early, value: None };
+            match value.map(|v| v.downcast::<T>()) {
+                None            => {},
+                Some(Ok(value)) => lent.value = Some(value),
+                Some(Err(value)) => {
+                    lent.restore(Some(value));
+                    return fn_err!(ERROR::DATATYPE_MISMATCH);
+                    // Considered these error codes:
+                    //  ERROR::DATATYPE_MISMATCH    Chosen!
+                    //  ERROR::INVALID_HANDLE       Kinda makes sense?  Less specific tho.
+                    //  ERROR::INVALID_DATATYPE     nah: The datatype is valid, just mismatched?
+                    //  ERROR::BAD_TOKEN_TYPE       nah: The token *category* (dense thread local early/late slot) is fine, just what the token references is bad
+                    //  ERROR::UNSUPPORTED_TYPE     nah: The type is supported, there's just a mismatch
+                },
+            }
+            Ok(lent)
+        })
+    }
+
     fn slot_idx(&'static self) -> usize { self.slot_no() - 1 }
     fn slot_no(&'static self) -> usize {
         // https://en.wikipedia.org/wiki/Double-checked_locking
@@ -300,6 +447,42 @@ impl<T: 'static> Slot<T> {
 
 
 
+/// A value (or lack thereof) moved out of a [Slot] by [Slot::lend], restored when dropped.
+struct Lent<T: 'static> {
+    hwnd:       HWnd,
+    slot_idx:   usize,
+    drop_early: bool,
+    value:      Option<Box<T>>,
+}
+
+impl<T: 'static> Lent<T> {
+    fn restore(&mut self, value: Option<Box<dyn Any>>) {
+        let (hwnd, slot_idx, drop_early) = (self.hwnd, self.slot_idx, self.drop_early);
+        let orphaned = ThreadLocal::with(move |tl| {
+            let mut pw = match tl.per_window.try_borrow_mut() { Ok(pw) => pw, Err(_) => return value };
+            let pw = match pw.get_mut(&hwnd) { Some(pw) => pw, None => return value }; // window destroyed while lent out
+            let mut pw_dense_slots = match pw.dense_slots.try_borrow_mut() { Ok(s) => s, Err(_) => return value };
+            let slot = match pw_dense_slots.get_mut(slot_idx) { Some(slot @ DenseSlot::InUse) => slot, _ => return value };
+            if drop_early && pw.destroying.get() {
+                *slot = DenseSlot::Empty; // window started being destroyed while lent out
+                return value;
+            }
+            *slot = match value { Some(value) => DenseSlot::Value(value), None => DenseSlot::Empty };
+            None
+        });
+        super::drop_in_reverse(hwnd, orphaned.into_iter().collect()); // dropped without holding any borrows
+    }
+}
+
+impl<T: 'static> Drop for Lent<T> {
+    fn drop(&mut self) {
+        let value = self.value.take().map(|v| -> Box<dyn Any> { v });
+        self.restore(value);
+    }
+}
+
+
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)] enum SlotType {
     DenseDropEarly,
     DenseDropLate,
@@ -309,27 +492,44 @@ impl<T: 'static> Slot<T> {
 
 struct ThreadLocal {
     global:     &'static Global,
-    hooks:      Hooks,
     per_window: RefCell<HashMap<HWnd, PerWindow>>,
+    next_order: Cell<u64>,
+    hooks:      Hooks, // dropped last: unhooks, then tears down this thread's assoc::global data
 }
 
+thread_local! { static TL : ThreadLocal = ThreadLocal::default(); }
+
 impl Default for ThreadLocal {
     fn default() -> Self {
         Self {
             global:     Global::get(),
-            hooks:      Default::default(),
             per_window: Default::default(),
+            next_order: Default::default(),
+            hooks:      Default::default(),
         }
     }
 }
 
-impl ThreadLocal {
-    fn with<R>(f: impl FnOnce(&ThreadLocal) -> R) -> R {
-        thread_local! { static TL : ThreadLocal = ThreadLocal::default(); }
-        TL.with(f)
+impl Drop for ThreadLocal {
+    /// Thread exit:  windows that outlive their thread never see [WM::DESTROY] / [WM::NCDESTROY] via our hooks, so drop their data now.
+    fn drop(&mut self) {
+        let mut per_window = std::mem::take(self.per_window.get_mut()).into_iter().collect::<Vec<_>>();
+        per_window.sort_by_key(|(_, pw)| std::cmp::Reverse(pw.order)); // most recently associated windows first
+        for (hwnd, mut pw) in per_window {
+            let early = pw.before_wm_destroy(self.global);
+            super::drop_in_reverse(hwnd, early);
+            pw.after_wm_nc_destroy(hwnd);
+        }
     }
 }
 
+impl ThreadLocal {
+    fn with<R>(f: impl FnOnce(&ThreadLocal) -> R) -> R { TL.with(f) }
+
+    /// Like [ThreadLocal::with], but returns [None] instead of panicing if this thread's [ThreadLocal] has been (or is being) destroyed.
+    fn try_with<R>(f: impl FnOnce(&ThreadLocal) -> R) -> Option<R> { TL.try_with(f).ok() }
+}
+
 
 
 #[derive(Default)]
@@ -356,8 +556,17 @@ struct DenseSlotMeta {
 
 #[derive(Default)]
 struct PerWindow {
+    order:          u64, // creation order, for deterministic teardown at thread exit
     destroying:     Cell<bool>,
-    dense_slots:    RefCell<Vec<Option<Box<dyn Any + 'static>>>>,
+    dense_slots:    RefCell<Vec<DenseSlot>>,
+}
+
+#[derive(Default)]
+enum DenseSlot {
+    #[default] Empty,
+    Value(Box<dyn Any + 'static>),
+    /// Lent out by [Slot::lend]
+    InUse,
 }
 
 impl PerWindow {
@@ -377,29 +586,28 @@ impl PerWindow {
     fn after_wm_create(&self) {
     }
 
-    /// Called before [WM::DESTROY] if data is associated with the [HWnd]
-    fn before_wm_destroy(&mut self, global: &Global) {
+    /// Called before [WM::DESTROY] if data is associated with the [HWnd].
+    /// Returns early data, which the caller should drop after releasing any borrows.
+    fn before_wm_destroy(&mut self, global: &Global) -> Vec<DenseSlot> {
         self.destroying.set(true);
-        if !global.any_early.load(Acquire) { return }
+        if !global.any_early.load(Acquire) { return Vec::new() }
 
-        // XXX: abort to avoid unwinding through FFI?
         let     g_dense_slots = global.dense_slots.read().unwrap();
         let mut s_dense_slots = self.dense_slots.borrow_mut();
 
+        let mut early = Vec::new();
         for (g_dense, s_dense) in g_dense_slots.iter().zip(s_dense_slots.iter_mut()) {
-            if g_dense.drop_early {
-                // TODO: abort on panics?  Avoids:
-                //  * unwinding across FFI boundaries
-                //  * early Drop s outliving WM::DESTROY
-                let _ = s_dense.take();
+            if g_dense.drop_early && matches!(s_dense, DenseSlot::Value(_)) {
+                early.push(std::mem::take(s_dense));
             }
         }
+        early
     }
 
-    /// Called after [WM::NCDESTROY] if data is associated with the [HWnd]
-    fn after_wm_nc_destroy(mut self) {
-        // TODO: control drop order?
-        self.dense_slots.get_mut().clear();
+    /// Called after [WM::NCDESTROY] if data is associated with the [HWnd].
+    /// Drops remaining (late) data in reverse registration order.
+    fn after_wm_nc_destroy(self, hwnd: HWnd) {
+        super::drop_in_reverse(hwnd, self.dense_slots.into_inner());
     }
 }
 
@@ -422,12 +630,14 @@ impl Default for Hooks {
         debug_assert!(!hooks.wh_cbt             .is_null());
         debug_assert!(!hooks.wh_callwndproc     .is_null());
         debug_assert!(!hooks.wh_callwndprocret  .is_null());
+        super::global::on_thread_hooked();
         hooks
     }
 }
 
 impl Drop for Hooks {
     fn drop(&mut self) {
+        super::global::on_thread_unhooked();
         let unhook_wh_cbt               = self.wh_cbt.is_null()             || unsafe { UnhookWindowsHookEx(self.wh_cbt             ) != 0 };
         let unhook_wh_callwndproc       = self.wh_callwndproc.is_null()     || unsafe { UnhookWindowsHookEx(self.wh_callwndproc     ) != 0 };
         let unhook_wh_callwndprocret    = self.wh_callwndprocret.is_null()  || unsafe { UnhookWindowsHookEx(self.wh_callwndprocret  ) != 0 };
@@ -437,6 +647,9 @@ impl Drop for Hooks {
     }
 }
 
+/// Ensure this thread's hooks are installed (for [super::global] cleanup, [crate::hooks] subscriptions, including [super::prop]'s leak checks.)
+pub(crate) fn install_hooks() { ThreadLocal::with(|_| {}) }
+
 /// Require [get_current_thread_id]\(\) == [get_window_thread_id]\(hwnd\)
 fn check_window_thread_local(hwnd: HWnd) -> Result<(), Error> {
     fn_context!(assoc::local::check_window_thread_local => GetWindowThreadProcessId);
@@ -445,28 +658,30 @@ fn check_window_thread_local(hwnd: HWnd) -> Result<(), Error> {
     Ok(())
 }
 
-// TODO: catch panics to avoid unwinding across FFI boundaries?
-// Rust plans to catch unwinding through extern "system", but that hasn't landed yet:
-// https://github.com/rust-lang/rust/issues/52652
-// Review/use: https://docs.rs/unwind_aborts/ ?
+// Hooks must never unwind across the FFI boundary (https://github.com/rust-lang/rust/issues/52652), and must always reach CallNextHookEx:
+// panics (e.g. from Drop) are caught and reported via super::catch_and_report / super::drop_in_reverse.
+// Hooks can also run during thread exit, after ThreadLocal has been destroyed (e.g. a Drop calling destroy_window) - hence try_with.
 
 /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/previous-versions/windows/desktop/legacy/ms644977(v=vs.85)) \]
 unsafe extern "system" fn wh_cbt(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
-    let hook = ThreadLocal::with(|tl| tl.hooks.wh_cbt);
+    let _gle = LastErrorGuard::new(); // don't clobber the last error of e.g. the wndproc we're hooking
+    let hook = ThreadLocal::try_with(|tl| tl.hooks.wh_cbt);
 
-    if code >= 0 {
+    if hook.is_some() && code == HCBT_CREATEWND {
+        crate::hooks::on_cbt_create_wnd(HWnd::from(wparam as HWND));
     }
 
-    let lr = unsafe { CallNextHookEx(hook, code, wparam, lparam) };
+    let lr = unsafe { CallNextHookEx(hook.unwrap_or(null_mut()), code, wparam, lparam) };
     lr
 }
 
 /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/previous-versions/windows/desktop/legacy/ms644975(v=vs.85)) \]
 /// CallWndProc callback
 unsafe extern "system" fn wh_callwndproc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
-    let hook = ThreadLocal::with(|tl| tl.hooks.wh_callwndproc);
+    let _gle = LastErrorGuard::new(); // don't clobber the last error of e.g. the wndproc we're hooking
+    let hook = ThreadLocal::try_with(|tl| tl.hooks.wh_callwndproc);
 
-    if code == HC_ACTION {
+    if let (Some(_), HC_ACTION) = (hook, code) {
         // "Specifies whether the message was sent by the current thread. If the message was sent by the current thread, it is nonzero; otherwise, it is zero."
         // NOTE: CallWndRetProc thinks this instead indicates if the message is instead sent by the current *process*.
         let _from_current_proc_or_thread = wparam != 0;
@@ -475,26 +690,33 @@ unsafe extern "system" fn wh_callwndproc(code: i32, wparam: WPARAM, lparam: LPAR
         let hwnd    = HWnd::from(call.hwnd);
         let msg     = WM32::from(call.message);
 
+        crate::hooks::before_message(&crate::hooks::SentMessage { hwnd, msg, wparam: call.wParam, lparam: call.lParam });
+
         if !call.hwnd.is_null() {
-            match msg {
-                WM::GETMINMAXINFO   => { ThreadLocal::with(|tl| tl.per_window.borrow    ().get    (&hwnd).map(|pw| pw.before_wm_get_min_max_info()  )); },
-                WM::NCCREATE        => { ThreadLocal::with(|tl| tl.per_window.borrow    ().get    (&hwnd).map(|pw| pw.before_wm_nc_create()         )); },
-                WM::DESTROY         => { ThreadLocal::with(|tl| tl.per_window.borrow_mut().get_mut(&hwnd).map(|pw| pw.before_wm_destroy(tl.global)  )); },
+            super::catch_and_report(hwnd, "handling assoc hooks", || match msg {
+                WM::GETMINMAXINFO   => { ThreadLocal::with(|tl| tl.per_window.borrow    ().get    (&hwnd).map(|pw| pw.before_wm_get_min_max_info()  )); super::global::before_wm_create(hwnd); },
+                WM::NCCREATE        => { ThreadLocal::with(|tl| tl.per_window.borrow    ().get    (&hwnd).map(|pw| pw.before_wm_nc_create()         )); super::global::before_wm_create(hwnd); },
+                WM::DESTROY         => {
+                    let early = ThreadLocal::with(|tl| tl.per_window.borrow_mut().get_mut(&hwnd).map(|pw| pw.before_wm_destroy(tl.global)));
+                    super::drop_in_reverse(hwnd, early.unwrap_or_default()); // dropped without holding any borrows
+                    super::global::before_wm_destroy(hwnd);
+                },
                 _                   => {}
-            }
+            });
         }
     }
 
-    let lr = unsafe { CallNextHookEx(hook, code, wparam, lparam) };
+    let lr = unsafe { CallNextHookEx(hook.unwrap_or(null_mut()), code, wparam, lparam) };
     lr
 }
 
 /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nc-winuser-hookproc)\]
 /// CallWndRetProc
 unsafe extern "system" fn wh_callwndprocret(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
-    let hook = ThreadLocal::with(|tl| tl.hooks.wh_callwndprocret);
+    let _gle = LastErrorGuard::new(); // don't clobber the last error of e.g. the wndproc we're hooking
+    let hook = ThreadLocal::try_with(|tl| tl.hooks.wh_callwndprocret);
 
-    if code >= 0 {
+    if hook.is_some() && code >= 0 {
         // "Specifies whether the message is sent by the current process. If the message is sent by the current process, it is nonzero; otherwise, it is NULL."
         // NOTE: CallWndProc thinks this instead indicates if the message is instead sent by the current *thread*.
         let _from_current_proc_or_thread = wparam != 0;
@@ -503,15 +725,93 @@ unsafe extern "system" fn wh_callwndprocret(code: i32, wparam: WPARAM, lparam: L
         let hwnd    = HWnd::from(ret.hwnd);
         let msg     = WM32::from(ret.message);
 
+        crate::hooks::after_message(&crate::hooks::SentMessage { hwnd, msg, wparam: ret.wParam, lparam: ret.lParam }, ret.lResult);
+
         if !hwnd.is_null() {
-            match msg {
+            super::catch_and_report(hwnd, "handling assoc hooks", || match msg {
                 WM::CREATE      => { ThreadLocal::with(|tl| tl.per_window.borrow    ().get   (&hwnd).map(|pw| pw.after_wm_create()    )); }
-                WM::NCDESTROY   => { ThreadLocal::with(|tl| tl.per_window.borrow_mut().remove(&hwnd)).map(|pw| pw.after_wm_nc_destroy()); }
+                WM::NCDESTROY   => {
+                    let pw = ThreadLocal::with(|tl| tl.per_window.borrow_mut().remove(&hwnd));
+                    if let Some(pw) = pw { pw.after_wm_nc_destroy(hwnd) }
+                    super::global::after_wm_nc_destroy(hwnd);
+                }
                 _               => {}
-            }
+            });
         }
     }
 
-    let lr = unsafe { CallNextHookEx(hook, code, wparam, lparam) };
+    let lr = unsafe { CallNextHookEx(hook.unwrap_or(null_mut()), code, wparam, lparam) };
     lr
 }
+
+
+
+#[cfg(test)] struct Logged { log: &'static Mutex<Vec<&'static str>>, name: &'static str, panic: bool }
+#[cfg(test)] impl Drop for Logged {
+    fn drop(&mut self) {
+        self.log.lock().unwrap().push(self.name);
+        if self.panic { panic!("{} panicked (expected by test)", self.name) }
+    }
+}
+
+#[cfg(test)] use crate::utils::testing::test_window;
+
+#[test] fn teardown_order() {
+    static LOG      : Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+    static EARLY_1  : Slot<Logged> = Slot::new_drop_early();
+    static LATE_1   : Slot<Logged> = Slot::new_drop_late();
+    static EARLY_2  : Slot<Logged> = Slot::new_drop_early();
+    static LATE_2   : Slot<Logged> = Slot::new_drop_late();
+
+    let hwnd = test_window();
+    for (slot, name) in [(&EARLY_1, "early 1"), (&LATE_1, "late 1"), (&EARLY_2, "early 2"), (&LATE_2, "late 2")] {
+        assert!(slot.set(hwnd, Logged { log: &LOG, name, panic: false }).unwrap().is_none());
+    }
+    assert!(LOG.lock().unwrap().is_empty());
+
+    unsafe { destroy_window(hwnd) }.unwrap();
+    assert_eq!(*LOG.lock().unwrap(), ["early 2", "early 1", "late 2", "late 1"]);
+}
+
+#[test] fn teardown_panic() {
+    static LOG      : Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+    static EARLY_1  : Slot<Logged> = Slot::new_drop_early();
+    static EARLY_2  : Slot<Logged> = Slot::new_drop_early();
+    static LATE_1   : Slot<Logged> = Slot::new_drop_late();
+    static LATE_2   : Slot<Logged> = Slot::new_drop_late();
+
+    let hwnd = test_window();
+    EARLY_1 .set(hwnd, Logged { log: &LOG, name: "early 1", panic: false }).unwrap();
+    EARLY_2 .set(hwnd, Logged { log: &LOG, name: "early 2", panic: true  }).unwrap();
+    LATE_1  .set(hwnd, Logged { log: &LOG, name: "late 1",  panic: true  }).unwrap();
+    LATE_2  .set(hwnd, Logged { log: &LOG, name: "late 2",  panic: false }).unwrap();
+
+    unsafe { destroy_window(hwnd) }.unwrap(); // panics contained: didn't unwind through the hooks or destroy_window
+    assert_eq!(*LOG.lock().unwrap(), ["early 2", "early 1", "late 2", "late 1"]);
+
+    // thread remains usable
+    let hwnd = test_window();
+    EARLY_1.set(hwnd, Logged { log: &LOG, name: "early 1 (again)", panic: false }).unwrap();
+    unsafe { destroy_window(hwnd) }.unwrap();
+    assert_eq!(LOG.lock().unwrap().last(), Some(&"early 1 (again)"));
+}
+
+#[test] fn teardown_thread_exit() {
+    static LOG      : Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+    static EARLY    : Slot<Logged> = Slot::new_drop_early();
+    static LATE     : Slot<Logged> = Slot::new_drop_late();
+    static GLOBAL   : super::global::Slot<Logged> = super::global::Slot::new_drop_late();
+
+    std::thread::spawn(|| {
+        let a = test_window();
+        let b = test_window();
+        LATE    .set(a, Logged { log: &LOG, name: "a late",     panic: true  }).unwrap();
+        EARLY   .set(a, Logged { log: &LOG, name: "a early",    panic: false }).unwrap();
+        GLOBAL  .set(a, Logged { log: &LOG, name: "a global",   panic: false }).unwrap();
+        EARLY   .set(b, Logged { log: &LOG, name: "b early",    panic: true  }).unwrap();
+        LATE    .set(b, Logged { log: &LOG, name: "b late",     panic: false }).unwrap();
+        // exit without destroying `a` or `b`
+    }).join().unwrap();
+
+    assert_eq!(*LOG.lock().unwrap(), ["b early", "b late", "a early", "a late", "a global"]);
+}
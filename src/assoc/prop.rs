@@ -0,0 +1,231 @@
+//! Associate [Pod] data with an [HWnd] via [window properties](https://learn.microsoft.com/en-us/windows/win32/winmsg/about-window-properties).
+//!
+//! Unlike [assoc::local](crate::assoc::local), window properties work for windows belonging to other threads of the current process.
+//! However, values are limited to [Pod] types no larger than a pointer, and are never dropped:
+//! properties must be [removed](Key::remove) before the window is destroyed.
+//! Install a [set_leak_hook] to be notified of properties set via a [Key] that are still present after [WM::NCDESTROY].
+//!
+//! ### Common Errors
+//! *   [ERROR::INVALID_WINDOW_HANDLE]      if an [HWnd] is invalid
+//! *   [ERROR::ACCESS_DENIED]              if an [HWnd] belongs to another process (setting/removing properties only)
+//!
+//! ### Example
+//! ```
+//! # use hwnd::*;
+//! # use winresult::*;
+//! # use std::ptr::*;
+//! # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+//! use hwnd::assoc::prop::*;
+//!
+//! static SCALE : Key<f32> = unsafe { Key::new(abistr::cstr16!("com.example.my-crate.scale")) };
+//!
+//! assert_eq!(0.0,                             SCALE.get(hwnd).unwrap());
+//! SCALE.set(hwnd, 1.5).unwrap();
+//! assert_eq!(1.5,                             SCALE.get(hwnd).unwrap());
+//! assert_eq!(1.5,                             SCALE.remove(hwnd).unwrap());
+//! assert_eq!(0.0,                             SCALE.get(hwnd).unwrap());
+//! assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    SCALE.get(HWnd::NULL).unwrap_err());
+//! ```
+
+use crate::*;
+use bytemuck::*;
+use std::cell::Cell;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::marker::*;
+use std::mem::*;
+use std::panic::*;
+use std::sync::*;
+
+
+
+/// A typed window property name.
+///
+/// Values are stored directly in the property's `HANDLE`, zero-extended as necessary.
+/// An unset property reads as [zeroed](Zeroable::zeroed).
+pub struct Key<T: Pod> {
+    name:   abistr::CStrNonNull<'static, u16>,
+    pd:     PhantomData<fn(T) -> T>,
+}
+
+// SAFETY: `name` is a `'static` immutable string
+unsafe impl<T: Pod> Send for Key<T> {}
+unsafe impl<T: Pod> Sync for Key<T> {}
+
+impl<T: Pod> Key<T> {
+    /// Create a typed property key named `name`.
+    ///
+    /// Fails to compile (when used to initialize a `static` or `const`) if `T` is larger than a pointer.
+    ///
+    /// ### Safety
+    /// *   No other code may use `name` as a property name with a different meaning or type (e.g. as a pointer.)
+    ///     Prefix `name` with something unique to your crate.
+    pub const unsafe fn new(name: abistr::CStrNonNull<'static, u16>) -> Self {
+        assert!(size_of::<T>() <= size_of::<usize>(), "assoc::prop::Key<T>: T is too large to fit in a HANDLE");
+        Self { name, pd: PhantomData }
+    }
+
+    /// The name of this property.
+    pub fn name(&self) -> abistr::CStrNonNull<'static, u16> { self.name }
+
+    /// Get the value of this property for `hwnd` (or [zeroed](Zeroable::zeroed) if unset.)
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid
+    pub fn get(&self, hwnd: impl TryInto<HWnd>) -> Result<T, Error> {
+        Ok(from_handle(get_prop_w(hwnd, self.name)? as usize))
+    }
+
+    /// Set the value of this property for `hwnd`.
+    ///
+    /// Window properties have no atomic exchange, so the previous value isn't returned:
+    /// reading it first with [get](Self::get) would race with other threads setting the same property.
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid
+    /// *   [ERROR::ACCESS_DENIED]              if `hwnd` belongs to another process
+    pub fn set(&self, hwnd: impl TryInto<HWnd>, value: T) -> Result<(), Error> {
+        fn_context!(assoc::prop::Key::set => SetPropW);
+        let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?;
+        unsafe { set_prop_w(hwnd, self.name, to_handle(value) as _) }?;
+        if leak_hook().read().is_ok_and(|h| h.is_some()) { track(hwnd, self.name) }
+        Ok(())
+    }
+
+    /// Remove this property from `hwnd`, returning the previous value (or [zeroed](Zeroable::zeroed) if previously unset.)
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_WINDOW_HANDLE]      if `hwnd` is invalid
+    /// *   [ERROR::ACCESS_DENIED]              if `hwnd` belongs to another process
+    pub fn remove(&self, hwnd: impl TryInto<HWnd>) -> Result<T, Error> {
+        Ok(from_handle(unsafe { remove_prop_w(hwnd, self.name) }? as usize))
+    }
+}
+
+fn to_handle<T: Pod>(value: T) -> usize {
+    let mut handle = 0usize;
+    bytes_of_mut(&mut handle)[..size_of::<T>()].copy_from_slice(bytes_of(&value));
+    handle
+}
+
+fn from_handle<T: Pod>(handle: usize) -> T {
+    pod_read_unaligned(&bytes_of(&handle)[..size_of::<T>()])
+}
+
+
+
+/// A property set via a [Key], still present after its window processed [WM::NCDESTROY] (see [set_leak_hook].)
+pub struct LeakedProp<'a> {
+    /// The destroyed window (no longer valid, and possibly already reused by a new window.)
+    pub hwnd:   HWnd,
+
+    /// The leaked property.
+    pub prop:   &'a Prop,
+}
+
+impl Debug for LeakedProp<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        fmt.debug_struct("LeakedProp").field("hwnd", &self.hwnd).field("name", &self.prop.name).field("data", &self.prop.data).finish()
+    }
+}
+
+impl Display for LeakedProp<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "{:?} still has property {:?} = {:?} after WM::NCDESTROY (leaked - remove it before the window is destroyed)", self.hwnd, self.prop.name, self.prop.data)
+    }
+}
+
+type LeakHook = Arc<dyn Fn(&LeakedProp) + Send + Sync>;
+
+fn leak_hook() -> &'static RwLock<Option<LeakHook>> {
+    lazy_static::lazy_static! { static ref HOOK : RwLock<Option<LeakHook>> = Default::default(); }
+    &HOOK
+}
+
+/// Replace the process-wide hook called for each [LeakedProp].
+///
+/// By default, leaked properties aren't checked for.
+/// Once a hook is installed, [Key::set] remembers which property names it has set, and each thread that sets one on a window it owns
+/// subscribes to [hooks::on_nc_destroy] to check its windows as they're destroyed.
+/// Properties set on windows of other threads are only checked if their own thread does the same.
+///
+/// The hook is called on the window's thread, and panics from the hook itself are caught and ignored.
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// hwnd::assoc::prop::set_leak_hook(|leak| {
+///     // e.g. "HWnd(0x1234) still has property \"com.example.my-crate.scale\" = 0x3fc00000 after WM::NCDESTROY (...)"
+///     eprintln!("{leak}");
+/// });
+/// ```
+pub fn set_leak_hook(hook: impl Fn(&LeakedProp) + Send + Sync + 'static) {
+    *leak_hook().write().unwrap_or_else(|p| p.into_inner()) = Some(Arc::new(hook));
+}
+
+/// Property names set via a [Key] while a [set_leak_hook] was installed, including their terminal `\0`s.
+fn tracked_names() -> &'static Mutex<Vec<&'static [u16]>> {
+    lazy_static::lazy_static! { static ref NAMES : Mutex<Vec<&'static [u16]>> = Default::default(); }
+    &NAMES
+}
+
+fn track(hwnd: HWnd, name: abistr::CStrNonNull<'static, u16>) {
+    thread_local! { static SUBSCRIBED : Cell<bool> = Default::default(); }
+
+    if let Ok(mut names) = tracked_names().lock() {
+        let units = name.to_units_with_nul();
+        if !names.contains(&units) { names.push(units) }
+    }
+    if get_window_thread_id(hwnd).ok() == Some(get_current_thread_id()) && SUBSCRIBED.try_with(|s| !s.replace(true)).unwrap_or(false) {
+        crate::hooks::on_nc_destroy(after_wm_nc_destroy).forget();
+    }
+}
+
+fn after_wm_nc_destroy(hwnd: HWnd) {
+    let leaked = {
+        let names = match tracked_names().lock() {
+            Ok(names) if !names.is_empty() => names,
+            _ => return,
+        };
+        let props = match enum_props_ex_w(hwnd) {
+            Ok(props) => props,
+            Err(_) => return,
+        };
+        props.filter(|prop| names.iter().filter_map(|n| abistr::CStrNonNull::from_units_with_nul(n).ok()).any(|n| prop.name.is(n))).collect::<Vec<_>>()
+    };
+    if leaked.is_empty() { return }
+    let Some(hook) = leak_hook().read().ok().and_then(|h| h.clone()) else { return }; // not held while calling user code
+    for prop in leaked.iter() {
+        let _ = catch_unwind(AssertUnwindSafe(|| hook(&LeakedProp { hwnd, prop })));
+    }
+}
+
+
+
+#[test] fn handle_round_trip() {
+    assert_eq!(0u8,         from_handle::<u8 >(to_handle(0u8)));
+    assert_eq!(0xA5u8,      from_handle::<u8 >(to_handle(0xA5u8)));
+    assert_eq!(-2i16,       from_handle::<i16>(to_handle(-2i16)));
+    assert_eq!(1.5f32,      from_handle::<f32>(to_handle(1.5f32)));
+    assert_eq!([1u8, 2, 3], from_handle::<[u8; 3]>(to_handle([1u8, 2, 3])));
+    assert_eq!(0usize,      to_handle(()));
+    assert_eq!(!0usize,     from_handle::<usize>(to_handle(!0usize)));
+}
+
+#[test] fn leaked_props() {
+    use crate::utils::testing::test_window;
+
+    static KEY     : Key<u32> = unsafe { Key::new(abistr::cstr16!("hwnd::assoc::prop::leak_hook")) };
+    static LEAKED  : Mutex<Vec<HWnd>> = Mutex::new(Vec::new());
+    set_leak_hook(|leak| if leak.prop.name.is(KEY.name()) { LEAKED.lock().unwrap().push(leak.hwnd) });
+
+    let leaks = test_window();
+    KEY.set(leaks, 42).unwrap();
+    unsafe { destroy_window(leaks) }.unwrap();
+    assert_eq!(*LEAKED.lock().unwrap(), [leaks]);
+
+    let cleans_up = test_window();
+    KEY.set(cleans_up, 42).unwrap();
+    assert_eq!(42, KEY.remove(cleans_up).unwrap());
+    unsafe { destroy_window(cleans_up) }.unwrap();
+    assert_eq!(*LEAKED.lock().unwrap(), [leaks]);
+}
@@ -0,0 +1,64 @@
+//! Observe windows and messages of the current thread, including windows created by third-party code.
+//!
+//! | Subscription          | Called                                                                | Hook                  |
+//! | --------------------- | --------------------------------------------------------------------- | --------------------- |
+//! | [on_create]           | When a window is created, before [WM::NCCREATE](crate::WM::NCCREATE)                  | `WH_CBT`              |
+//! | [on_destroy]          | Before [WM::DESTROY](crate::WM::DESTROY) is sent to a window                            | `WH_CALLWNDPROC`      |
+//! | [on_nc_destroy]       | After [WM::NCDESTROY](crate::WM::NCDESTROY) has been processed by a window                | `WH_CALLWNDPROCRET`   |
+//! | [on_message]          | Before any message is sent to a window                                | `WH_CALLWNDPROC`      |
+//! | [on_message_ret]      | After any message sent to a window has been processed                 | `WH_CALLWNDPROCRET`   |
+//!
+//! For other hook types - or to block events - install a [Hook] via e.g. [Hook::cbt], [Hook::keyboard_ll], or [Hook::mouse_ll].
+//!
+//! See also [Window Lifecycle Events](crate::doc::Window_Lifecycle_Events) for the order in which these occur.
+
+use std::any::Any;
+use std::cell::*;
+use std::panic::*;
+use std::rc::Rc;
+
+mod lifecycle;
+mod windows_hook;
+
+pub use lifecycle::*;
+pub use windows_hook::*;
+
+
+
+type List<F> = Vec<(u64, Rc<Subscriber<F>>)>;
+
+struct Subscriber<F: ?Sized> {
+    subscribed: Cell<bool>,
+    f:          RefCell<F>,
+}
+
+impl<F> Subscriber<F> {
+    fn new(f: F) -> Self { Self { subscribed: Cell::new(true), f: RefCell::new(f) } }
+}
+
+/// Remove subscriber `id` from `list`, returning it to be dropped by the caller (after releasing any borrows.)
+fn remove<F: ?Sized + 'static>(list: &mut List<F>, id: u64) -> Option<Box<dyn Any>> {
+    let idx = list.iter().position(|(i, _)| *i == id)?;
+    let (_, sub) = list.remove(idx);
+    sub.subscribed.set(false); // in case a dispatch in progress hasn't reached it yet
+    Some(Box::new(sub))
+}
+
+fn snapshot<F: ?Sized>(list: &List<F>) -> Vec<Rc<Subscriber<F>>> { list.iter().map(|(_, sub)| sub.clone()).collect() }
+
+/// Call each of `subs` (a [snapshot] taken without holding borrows during user code) until `call` returns `true`, catching and reporting panics (for `hwnd`, if known.)
+/// Returns `true` if `call` did.
+///
+/// A subscriber that (indirectly) reenters itself - e.g. by sending a message from within [on_message] - is skipped by the reentrant call.
+fn call_each<F: ?Sized>(name: &str, hwnd: crate::HWnd, subs: Vec<Rc<Subscriber<F>>>, mut call: impl FnMut(&mut F) -> bool) -> bool {
+    for sub in subs {
+        if !sub.subscribed.get() { continue }
+        let Ok(mut f) = sub.f.try_borrow_mut() else { continue };
+        match catch_unwind(AssertUnwindSafe(|| call(&mut f))) {
+            Ok(true)    => return true,
+            Ok(false)   => {},
+            Err(panic)  => crate::report_callback_panic(&format!("{name} callback"), hwnd, panic),
+        }
+    }
+    false
+}
@@ -0,0 +1,255 @@
+use crate::*;
+
+use super::*;
+use std::marker::*;
+use std::rc::Rc;
+
+
+
+/// A message sent to a window of the current thread, as observed by [on_message] or [on_message_ret].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub struct SentMessage {
+    pub hwnd:   HWnd,
+    pub msg:    WM32,
+    pub wparam: WPARAM,
+    pub lparam: LPARAM,
+}
+
+/// A subscription created by [on_create], [on_destroy], [on_nc_destroy], [on_message], or [on_message_ret].
+///
+/// The callback is unsubscribed (and dropped) when this is dropped.
+/// Use [Subscription::forget] to keep the callback subscribed until the thread exits.
+#[must_use = "the callback is unsubscribed when the Subscription is dropped"]
+pub struct Subscription {
+    event:  Event,
+    id:     u64,
+    pd:     PhantomData<*const ()>, // !Send, !Sync: subscriptions are per-thread
+}
+
+impl Subscription {
+    /// Keep the callback subscribed until the current thread exits.
+    pub fn forget(self) { std::mem::forget(self) }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        let (event, id) = (self.event, self.id);
+        let removed = SUBSCRIBERS.try_with(|s| {
+            let mut s = s.try_borrow_mut().ok()?;
+            match event {
+                Event::Create       => remove(&mut s.create,      id),
+                Event::Destroy      => remove(&mut s.destroy,     id),
+                Event::NcDestroy    => remove(&mut s.nc_destroy,  id),
+                Event::Message      => remove(&mut s.message,     id),
+                Event::MessageRet   => remove(&mut s.message_ret, id),
+            }
+        });
+        drop(removed); // dropped without holding any borrows
+    }
+}
+
+impl std::fmt::Debug for Subscription {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result { write!(fmt, "Subscription({:?} #{})", self.event, self.id) }
+}
+
+
+
+/// Call `f(hwnd)` whenever a window is created on the current thread (including windows created by third-party code.)
+///
+/// `f` is called from `WH_CBT` (`HCBT_CREATEWND`), before the window has received [WM::NCCREATE] or any other message.
+/// Creation might still fail afterwards (e.g. if the window procedure returns `FALSE` from [WM::NCCREATE]),
+/// in which case [WM::DESTROY] is never sent, but [on_nc_destroy] subscriptions will still be called.
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// # use std::ptr::*;
+/// use std::{cell::Cell, rc::Rc};
+///
+/// let created = Rc::new(Cell::new(HWnd::NULL));
+/// let sub = hwnd::hooks::on_create({ let created = created.clone(); move |hwnd| created.set(hwnd) });
+///
+/// let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+/// assert_eq!(hwnd, created.get());
+/// drop(sub);
+/// ```
+pub fn on_create(f: impl FnMut(HWnd) + 'static) -> Subscription {
+    subscribe(Event::Create, |s, id| s.create.push((id, Rc::new(Subscriber::new(f)))))
+}
+
+/// Call `f(hwnd)` before [WM::DESTROY] is sent to any window of the current thread.
+///
+/// This is called before [assoc](crate::assoc) drops "early" data, so said data is still accessible from `f`.
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// # use std::ptr::*;
+/// use std::{cell::Cell, rc::Rc};
+///
+/// let destroyed = Rc::new(Cell::new(HWnd::NULL));
+/// let sub = hwnd::hooks::on_destroy({ let destroyed = destroyed.clone(); move |hwnd| destroyed.set(hwnd) });
+///
+/// let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+/// unsafe { destroy_window(hwnd) }.unwrap();
+/// assert_eq!(hwnd, destroyed.get());
+/// ```
+pub fn on_destroy(f: impl FnMut(HWnd) + 'static) -> Subscription {
+    subscribe(Event::Destroy, |s, id| s.destroy.push((id, Rc::new(Subscriber::new(f)))))
+}
+
+/// Call `f(hwnd)` after any window of the current thread has processed [WM::NCDESTROY].
+///
+/// This is the last message a window receives: `hwnd` is no longer valid, and might be reused by a new window afterwards.
+/// This is called before [assoc](crate::assoc) drops "late" data, so said data is still accessible from `f`.
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// # use std::ptr::*;
+/// use std::{cell::Cell, rc::Rc};
+///
+/// let destroyed = Rc::new(Cell::new(HWnd::NULL));
+/// let sub = hwnd::hooks::on_nc_destroy({ let destroyed = destroyed.clone(); move |hwnd| destroyed.set(hwnd) });
+///
+/// let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+/// unsafe { destroy_window(hwnd) }.unwrap();
+/// assert_eq!(hwnd, destroyed.get());
+/// assert!(!is_window(hwnd));
+/// ```
+pub fn on_nc_destroy(f: impl FnMut(HWnd) + 'static) -> Subscription {
+    subscribe(Event::NcDestroy, |s, id| s.nc_destroy.push((id, Rc::new(Subscriber::new(f)))))
+}
+
+/// Call `f(message)` before any message is sent to a window of the current thread.
+///
+/// This observes messages sent via e.g. [send_message_w] (from any thread), and messages sent by the system.
+/// Messages posted via e.g. [post_message_w] and then [dispatched](dispatch_message_w) are *not* observed.
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// # use std::ptr::*;
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// let messages = Rc::new(RefCell::new(Vec::new()));
+/// let sub = hwnd::hooks::on_message({ let messages = messages.clone(); move |m| messages.borrow_mut().push(m.msg) });
+///
+/// let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+/// assert!(messages.borrow().contains(&WM::NCCREATE));
+/// assert!(messages.borrow().contains(&WM::CREATE));
+/// ```
+pub fn on_message(f: impl FnMut(&SentMessage) + 'static) -> Subscription {
+    subscribe(Event::Message, |s, id| s.message.push((id, Rc::new(Subscriber::new(f)))))
+}
+
+/// Call `f(message, result)` after any message sent to a window of the current thread has been processed.
+///
+/// See [on_message] for which messages are observed.
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// # use std::ptr::*;
+/// use std::{cell::Cell, rc::Rc};
+///
+/// let create_result = Rc::new(Cell::new(None));
+/// let sub = hwnd::hooks::on_message_ret({ let r = create_result.clone(); move |m, lr| if m.msg == WM::CREATE { r.set(Some(lr)) } });
+///
+/// let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+/// assert_eq!(Some(0), create_result.get());
+/// ```
+pub fn on_message_ret(f: impl FnMut(&SentMessage, LRESULT) + 'static) -> Subscription {
+    subscribe(Event::MessageRet, |s, id| s.message_ret.push((id, Rc::new(Subscriber::new(f)))))
+}
+
+
+
+/// Called from `WH_CBT` (`HCBT_CREATEWND`)
+pub(crate) fn on_cbt_create_wnd(hwnd: HWnd) { dispatch("on_create", hwnd, |s| &s.create, |f| f(hwnd)) }
+
+/// Called from `WH_CALLWNDPROC`
+pub(crate) fn before_message(m: &SentMessage) {
+    dispatch("on_message", m.hwnd, |s| &s.message, |f| f(m));
+    if m.msg == WM::DESTROY { dispatch("on_destroy", m.hwnd, |s| &s.destroy, |f| f(m.hwnd)) }
+}
+
+/// Called from `WH_CALLWNDPROCRET`
+pub(crate) fn after_message(m: &SentMessage, lr: LRESULT) {
+    dispatch("on_message_ret", m.hwnd, |s| &s.message_ret, |f| f(m, lr));
+    if m.msg == WM::NCDESTROY { dispatch("on_nc_destroy", m.hwnd, |s| &s.nc_destroy, |f| f(m.hwnd)) }
+}
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] enum Event {
+    Create,
+    Destroy,
+    NcDestroy,
+    Message,
+    MessageRet,
+}
+
+type MessageRetFn = dyn FnMut(&SentMessage, LRESULT);
+
+#[derive(Default)] struct Subscribers {
+    next_id:        u64,
+    create:         List<dyn FnMut(HWnd)>,
+    destroy:        List<dyn FnMut(HWnd)>,
+    nc_destroy:     List<dyn FnMut(HWnd)>,
+    message:        List<dyn FnMut(&SentMessage)>,
+    message_ret:    List<MessageRetFn>,
+}
+
+thread_local! { static SUBSCRIBERS : RefCell<Subscribers> = Default::default(); }
+
+fn subscribe(event: Event, push: impl FnOnce(&mut Subscribers, u64)) -> Subscription {
+    crate::assoc::local::install_hooks();
+    let id = SUBSCRIBERS.with(|s| {
+        let mut s = s.borrow_mut(); // never held while calling user code
+        let id = s.next_id;
+        s.next_id += 1;
+        push(&mut s, id);
+        id
+    });
+    Subscription { event, id, pd: PhantomData }
+}
+
+/// Call every subscriber of `list` (in subscription order) without holding any borrows of [SUBSCRIBERS], catching and reporting panics for `hwnd`.
+fn dispatch<F: ?Sized>(name: &str, hwnd: HWnd, list: impl FnOnce(&Subscribers) -> &List<F>, call: impl Fn(&mut F)) {
+    let subs = SUBSCRIBERS.try_with(|s| match s.try_borrow() {
+        Ok(s)   => snapshot(list(&s)),
+        Err(_)  => Vec::new(),
+    }).unwrap_or_default();
+    call_each(name, hwnd, subs, |f| { call(f); false });
+}
+
+
+
+#[cfg(test)] use crate::utils::testing::test_window;
+
+#[test] fn lifecycle() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let create      = on_create     ({ let log = log.clone(); move |hwnd| log.borrow_mut().push(("create",      hwnd)) });
+    let destroy     = on_destroy    ({ let log = log.clone(); move |hwnd| log.borrow_mut().push(("destroy",     hwnd)) });
+    let nc_destroy  = on_nc_destroy ({ let log = log.clone(); move |hwnd| log.borrow_mut().push(("nc_destroy",  hwnd)) });
+
+    let hwnd = test_window();
+    unsafe { destroy_window(hwnd) }.unwrap();
+    assert_eq!(*log.borrow(), [("create", hwnd), ("destroy", hwnd), ("nc_destroy", hwnd)]);
+
+    drop((create, destroy, nc_destroy));
+    log.borrow_mut().clear();
+    let hwnd = test_window();
+    unsafe { destroy_window(hwnd) }.unwrap();
+    assert!(log.borrow().is_empty(), "unsubscribed callbacks were still called: {:?}", log.borrow());
+}
+
+#[test] fn message_panic_contained() {
+    let seen = Rc::new(Cell::new(0));
+    let _panic  = on_message(|m| if m.msg == WM::CREATE { panic!("on_message panicked (expected by test)") });
+    let _count  = on_message({ let seen = seen.clone(); move |m| if m.msg == WM::CREATE { seen.set(seen.get() + 1) } });
+    let hwnd = test_window(); // didn't unwind through WH_CALLWNDPROC
+    assert_eq!(1, seen.get());
+    unsafe { destroy_window(hwnd) }.unwrap();
+}
@@ -0,0 +1,497 @@
+use crate::*;
+
+use super::*;
+
+use winapi::shared::windef::*;
+use winapi::um::libloaderapi::GetModuleHandleW;
+use winapi::um::winuser::*;
+
+use std::marker::*;
+use std::ptr::*;
+use std::time::*;
+
+
+
+/// A hook installed by e.g. [Hook::keyboard_ll] or [Hook::cbt].
+///
+/// The callback is removed (and dropped) when this is dropped.
+/// Callbacks are stored per-thread, and are only ever called on the thread that installed them.
+/// Callbacks that panic are contained: the panic is reported (see [CallbackPanic](crate::CallbackPanic)), and the event is passed along as if [HookAction::CallNext] had been returned.
+///
+/// Each thread installs at most one OS-level hook per hook type, shared by all of that thread's callbacks of said type,
+/// which are called in the order they were installed.
+#[must_use = "the hook is removed when the Hook is dropped"]
+pub struct Hook {
+    kind:   Kind,
+    id:     u64,
+    pd:     PhantomData<*const ()>, // !Send, !Sync: callbacks are per-thread
+}
+
+/// What a [Hook] callback wants done with an event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum HookAction {
+    /// Pass the event along to later callbacks, other hooks, and eventually the target window.
+    #[default] CallNext,
+
+    /// Swallow/prevent the event: later callbacks and other hooks aren't called.
+    /// For [WhKeyboardLl] and [WhMouseLl], the input is discarded.
+    /// For [WhCbt], the operation (e.g. creating or activating a window) is prevented.
+    Block,
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-kbdllhookstruct)\]
+/// WH_KEYBOARD_LL event data (KBDLLHOOKSTRUCT)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WhKeyboardLl {
+    /// [WM::KEYDOWN], [WM::KEYUP], [WM::SYSKEYDOWN], or [WM::SYSKEYUP]
+    pub msg:        WM32,
+    pub vk_code:    u32,
+    pub scan_code:  u32,
+    /// `LLKHF_*` flags
+    pub flags:      u32,
+    pub time:       u32,
+    pub extra_info: usize,
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-msllhookstruct)\]
+/// WH_MOUSE_LL event data (MSLLHOOKSTRUCT)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WhMouseLl {
+    /// [WM::MOUSEMOVE], [WM::LBUTTONDOWN], [WM::MOUSEWHEEL], etc.
+    pub msg:        WM32,
+    /// Per-monitor aware screen coordinates
+    pub pt:         Point,
+    /// Wheel delta or X button in the high word, depending on `msg`
+    pub mouse_data: u32,
+    /// `LLMHF_*` flags
+    pub flags:      u32,
+    pub time:       u32,
+    pub extra_info: usize,
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/cbtproc)\]
+/// WH_CBT event data (HCBT_\*)
+#[derive(Clone, Copy)]
+#[non_exhaustive] pub enum WhCbt<'a> {
+    /// HCBT_ACTIVATE: `hwnd` is about to be activated.
+    Activate        { hwnd: HWnd, mouse: bool, active: HWnd },
+    /// HCBT_CLICKSKIPPED: a mouse message was removed from the message queue.
+    ClickSkipped    { msg: WM32, pt: Point, hwnd: HWnd, hit_test: u32 },
+    /// HCBT_CREATEWND: `hwnd` is about to be created (before [WM::NCCREATE].)
+    CreateWnd       { hwnd: HWnd, cs: &'a CREATESTRUCTW, insert_after: HWnd },
+    /// HCBT_DESTROYWND: `hwnd` is about to be destroyed.
+    DestroyWnd      { hwnd: HWnd },
+    /// HCBT_KEYSKIPPED: a keyboard message was removed from the message queue.
+    KeySkipped      { vk: u32, flags: u32 },
+    /// HCBT_MINMAX: `hwnd` is about to be minimized or maximized.
+    MinMax          { hwnd: HWnd, sw: SW::ShowWindowCmd },
+    /// HCBT_MOVESIZE: `hwnd` is about to be moved or sized.
+    MoveSize        { hwnd: HWnd, rect: Rect },
+    /// HCBT_QS: a [WM::QUEUESYNC] message was retrieved from the message queue.
+    Qs,
+    /// HCBT_SETFOCUS: `hwnd` is about to receive the keyboard focus from `lost`.
+    SetFocus        { hwnd: HWnd, lost: HWnd },
+    /// HCBT_SYSCOMMAND: a `SC_*` system command is about to be carried out.
+    SysCommand      { command: usize, lparam: LPARAM },
+    /// An unrecognized HCBT_\* code.
+    Other           { code: i32, wparam: WPARAM, lparam: LPARAM },
+}
+
+impl std::fmt::Debug for WhCbt<'_> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            WhCbt::Activate     { hwnd, mouse, active }         => fmt.debug_struct("Activate").field("hwnd", &hwnd).field("mouse", &mouse).field("active", &active).finish(),
+            WhCbt::ClickSkipped { msg, pt, hwnd, hit_test }     => fmt.debug_struct("ClickSkipped").field("msg", &msg).field("pt", &pt).field("hwnd", &hwnd).field("hit_test", &hit_test).finish(),
+            WhCbt::CreateWnd    { hwnd, cs, insert_after }      => fmt.debug_struct("CreateWnd").field("hwnd", &hwnd).field("cs", &(cs as *const CREATESTRUCTW)).field("insert_after", &insert_after).finish(),
+            WhCbt::DestroyWnd   { hwnd }                        => fmt.debug_struct("DestroyWnd").field("hwnd", &hwnd).finish(),
+            WhCbt::KeySkipped   { vk, flags }                   => fmt.debug_struct("KeySkipped").field("vk", &vk).field("flags", &flags).finish(),
+            WhCbt::MinMax       { hwnd, sw }                    => fmt.debug_struct("MinMax").field("hwnd", &hwnd).field("sw", &sw).finish(),
+            WhCbt::MoveSize     { hwnd, rect }                  => fmt.debug_struct("MoveSize").field("hwnd", &hwnd).field("rect", &rect).finish(),
+            WhCbt::Qs                                           => fmt.write_str("Qs"),
+            WhCbt::SetFocus     { hwnd, lost }                  => fmt.debug_struct("SetFocus").field("hwnd", &hwnd).field("lost", &lost).finish(),
+            WhCbt::SysCommand   { command, lparam }             => fmt.debug_struct("SysCommand").field("command", &command).field("lparam", &lparam).finish(),
+            WhCbt::Other        { code, wparam, lparam }        => fmt.debug_struct("Other").field("code", &code).field("wparam", &wparam).field("lparam", &lparam).finish(),
+        }
+    }
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/getmsgproc)\]
+/// WH_GETMESSAGE event data
+#[derive(Debug)]
+pub struct WhGetMessage<'a> {
+    /// `true` if the message has been removed from the queue (`PM_REMOVE`)
+    pub removed:    bool,
+    /// The message about to be returned by [get_message_w] or [peek_message_w], which may be modified.
+    pub msg:        &'a mut Msg,
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/callwndproc)\]
+/// WH_CALLWNDPROC event data (CWPSTRUCT)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WhCallWndProc {
+    /// `true` if the message was sent by the current thread
+    pub from_current_thread:    bool,
+    pub message:                SentMessage,
+}
+
+impl Hook {
+    /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/lowlevelkeyboardproc)\]
+    /// SetWindowsHookExW(WH_KEYBOARD_LL, ...)
+    ///
+    /// Call `f` for all keyboard input to the desktop, before it's delivered to any thread.
+    ///
+    /// ### Message Loop and Timeout Requirements
+    /// *   `f` is called via the installing thread's message queue: said thread **must** pump messages (e.g. [get_message_w] / [peek_message_w]) while the hook is installed, or input will lag system-wide.
+    /// *   `f` must return quickly.  If callbacks exceed `LowLevelHooksTimeout` (at most 1 second on Windows 10 1709+), input is passed along without waiting for them,
+    ///     and on Windows 7+ the hook may be **silently removed**.  Debug builds warn on stderr when callbacks are slow.
+    ///
+    /// ### Errors
+    /// *   [ERROR::HOOK_NEEDS_HMOD]    (unexpected)
+    /// *   [ERROR::ACCESS_DENIED]      possibly, in restricted environments
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// use hwnd::hooks::*;
+    ///
+    /// let hook = Hook::keyboard_ll(|e| {
+    ///     if e.msg == WM::KEYDOWN { println!("vk {:02X} pressed", e.vk_code) }
+    ///     HookAction::CallNext
+    /// }).unwrap();
+    /// // ...pump messages...
+    /// drop(hook);
+    /// ```
+    pub fn keyboard_ll(f: impl FnMut(&WhKeyboardLl) -> HookAction + 'static) -> Result<Self, Error> {
+        fn_context!(hooks::Hook::keyboard_ll => SetWindowsHookExW);
+        Self::new(FN_CONTEXT, Kind::KeyboardLl, |c, id| c.keyboard_ll.list.push((id, Rc::new(Subscriber::new(f)))))
+    }
+
+    /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/lowlevelmouseproc)\]
+    /// SetWindowsHookExW(WH_MOUSE_LL, ...)
+    ///
+    /// Call `f` for all mouse input to the desktop, before it's delivered to any thread.
+    ///
+    /// ### Message Loop and Timeout Requirements
+    /// *   `f` is called via the installing thread's message queue: said thread **must** pump messages (e.g. [get_message_w] / [peek_message_w]) while the hook is installed, or input will lag system-wide.
+    /// *   `f` must return quickly.  If callbacks exceed `LowLevelHooksTimeout` (at most 1 second on Windows 10 1709+), input is passed along without waiting for them,
+    ///     and on Windows 7+ the hook may be **silently removed**.  Debug builds warn on stderr when callbacks are slow.
+    ///
+    /// ### Errors
+    /// *   [ERROR::HOOK_NEEDS_HMOD]    (unexpected)
+    /// *   [ERROR::ACCESS_DENIED]      possibly, in restricted environments
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// use hwnd::hooks::*;
+    ///
+    /// let hook = Hook::mouse_ll(|e| {
+    ///     if e.msg == WM::LBUTTONDOWN { println!("click at {:?}", e.pt) }
+    ///     HookAction::CallNext
+    /// }).unwrap();
+    /// // ...pump messages...
+    /// drop(hook);
+    /// ```
+    pub fn mouse_ll(f: impl FnMut(&WhMouseLl) -> HookAction + 'static) -> Result<Self, Error> {
+        fn_context!(hooks::Hook::mouse_ll => SetWindowsHookExW);
+        Self::new(FN_CONTEXT, Kind::MouseLl, |c, id| c.mouse_ll.list.push((id, Rc::new(Subscriber::new(f)))))
+    }
+
+    /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/cbtproc)\]
+    /// SetWindowsHookExW(WH_CBT, ..., get_current_thread_id())
+    ///
+    /// Call `f` before windows of the current thread are created, destroyed, activated, moved, etc. - returning [HookAction::Block] prevents said operation.
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// # use std::ptr::*;
+    /// use hwnd::hooks::*;
+    ///
+    /// let hook = Hook::cbt(|e| match e {
+    ///     WhCbt::CreateWnd { .. } => HookAction::Block,
+    ///     _                       => HookAction::CallNext,
+    /// }).unwrap();
+    ///
+    /// let r = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) };
+    /// assert!(r.is_err());
+    /// ```
+    pub fn cbt(f: impl FnMut(WhCbt) -> HookAction + 'static) -> Result<Self, Error> {
+        fn_context!(hooks::Hook::cbt => SetWindowsHookExW);
+        Self::new(FN_CONTEXT, Kind::Cbt, |c, id| c.cbt.list.push((id, Rc::new(Subscriber::new(f)))))
+    }
+
+    /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/getmsgproc)\]
+    /// SetWindowsHookExW(WH_GETMESSAGE, ..., get_current_thread_id())
+    ///
+    /// Call `f` whenever [get_message_w] or [peek_message_w] is about to return a message on the current thread.
+    /// `f` may modify the message.
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// use hwnd::hooks::*;
+    ///
+    /// let hook = Hook::get_message(|e| {
+    ///     if e.msg.message == WM::QUIT { println!("quitting with exit code {}", e.msg.wparam) }
+    /// }).unwrap();
+    /// ```
+    pub fn get_message(f: impl FnMut(WhGetMessage) + 'static) -> Result<Self, Error> {
+        fn_context!(hooks::Hook::get_message => SetWindowsHookExW);
+        Self::new(FN_CONTEXT, Kind::GetMessage, |c, id| c.get_message.list.push((id, Rc::new(Subscriber::new(f)))))
+    }
+
+    /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/callwndproc)\]
+    /// SetWindowsHookExW(WH_CALLWNDPROC, ..., get_current_thread_id())
+    ///
+    /// Call `f` before any message is sent to a window of the current thread.
+    /// See also [on_message], which shares a hook with [assoc](crate::assoc).
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// use hwnd::hooks::*;
+    ///
+    /// let hook = Hook::call_wnd_proc(|e| println!("{:?}", e.message.msg)).unwrap();
+    /// ```
+    pub fn call_wnd_proc(f: impl FnMut(&WhCallWndProc) + 'static) -> Result<Self, Error> {
+        fn_context!(hooks::Hook::call_wnd_proc => SetWindowsHookExW);
+        Self::new(FN_CONTEXT, Kind::CallWndProc, |c, id| c.call_wnd_proc.list.push((id, Rc::new(Subscriber::new(f)))))
+    }
+
+    fn new(context: &'static ErrorContext, kind: Kind, push: impl FnOnce(&mut Chains, u64)) -> Result<Self, Error> {
+        CHAINS.with(|c| {
+            let mut c = c.borrow_mut(); // never held while calling user code
+            let c = &mut *c;
+            let hhook = match kind {
+                Kind::KeyboardLl    => &mut c.keyboard_ll.hhook,
+                Kind::MouseLl       => &mut c.mouse_ll.hhook,
+                Kind::Cbt           => &mut c.cbt.hhook,
+                Kind::GetMessage    => &mut c.get_message.hhook,
+                Kind::CallWndProc   => &mut c.call_wnd_proc.hhook,
+            };
+            if hhook.is_null() {
+                let (id, proc, low_level) = match kind {
+                    Kind::KeyboardLl    => (WH_KEYBOARD_LL, wh_keyboard_ll  as HookProc, true ),
+                    Kind::MouseLl       => (WH_MOUSE_LL,    wh_mouse_ll     as HookProc, true ),
+                    Kind::Cbt           => (WH_CBT,         wh_cbt          as HookProc, false),
+                    Kind::GetMessage    => (WH_GETMESSAGE,  wh_getmessage   as HookProc, false),
+                    Kind::CallWndProc   => (WH_CALLWNDPROC, wh_callwndproc  as HookProc, false),
+                };
+                // low level hooks are global (thread 0), and require a module handle
+                let (hmod, thread) = if low_level { (unsafe { GetModuleHandleW(null()) }, 0) } else { (null_mut(), get_current_thread_id()) };
+                let h = unsafe { SetWindowsHookExW(id, Some(proc), hmod, thread) };
+                if h.is_null() { return Err(Error::new_gle().with_context(context)) }
+                *hhook = h;
+            }
+            let id = c.next_id;
+            c.next_id += 1;
+            push(c, id);
+            Ok(Self { kind, id, pd: PhantomData })
+        })
+    }
+}
+
+impl Drop for Hook {
+    fn drop(&mut self) {
+        let (kind, id) = (self.kind, self.id);
+        let removed = CHAINS.try_with(|c| {
+            let mut c = c.try_borrow_mut().ok()?;
+            match kind {
+                Kind::KeyboardLl    => c.keyboard_ll    .remove(id),
+                Kind::MouseLl       => c.mouse_ll       .remove(id),
+                Kind::Cbt           => c.cbt            .remove(id),
+                Kind::GetMessage    => c.get_message    .remove(id),
+                Kind::CallWndProc   => c.call_wnd_proc  .remove(id),
+            }
+        }).ok().flatten();
+        drop(removed); // dropped without holding any borrows
+    }
+}
+
+impl std::fmt::Debug for Hook {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result { write!(fmt, "Hook({:?} #{})", self.kind, self.id) }
+}
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] enum Kind {
+    KeyboardLl,
+    MouseLl,
+    Cbt,
+    GetMessage,
+    CallWndProc,
+}
+
+type HookProc       = unsafe extern "system" fn(i32, WPARAM, LPARAM) -> LRESULT;
+type KeyboardLlFn   = dyn FnMut(&WhKeyboardLl) -> HookAction;
+type MouseLlFn      = dyn FnMut(&WhMouseLl) -> HookAction;
+type CbtFn          = dyn FnMut(WhCbt) -> HookAction;
+type GetMessageFn   = dyn FnMut(WhGetMessage);
+type CallWndProcFn  = dyn FnMut(&WhCallWndProc);
+
+struct Chain<F: ?Sized> {
+    hhook:  HHOOK,
+    list:   List<F>,
+}
+
+impl<F: ?Sized> Default for Chain<F> {
+    fn default() -> Self { Self { hhook: null_mut(), list: Vec::new() } }
+}
+
+impl<F: ?Sized + 'static> Chain<F> {
+    /// Remove callback `id`, unhooking if it was the last one.
+    fn remove(&mut self, id: u64) -> Option<Box<dyn Any>> {
+        let removed = remove(&mut self.list, id);
+        if self.list.is_empty() { self.unhook() }
+        removed
+    }
+}
+
+impl<F: ?Sized> Chain<F> {
+    fn unhook(&mut self) {
+        if self.hhook.is_null() { return }
+        let unhooked = unsafe { UnhookWindowsHookEx(self.hhook) } != 0;
+        debug_assert!(unhooked);
+        self.hhook = null_mut();
+    }
+}
+
+impl<F: ?Sized> Drop for Chain<F> {
+    fn drop(&mut self) { self.unhook() } // thread exit with forgotten hooks
+}
+
+#[derive(Default)] struct Chains {
+    next_id:        u64,
+    keyboard_ll:    Chain<KeyboardLlFn>,
+    mouse_ll:       Chain<MouseLlFn>,
+    cbt:            Chain<CbtFn>,
+    get_message:    Chain<GetMessageFn>,
+    call_wnd_proc:  Chain<CallWndProcFn>,
+}
+
+thread_local! { static CHAINS : RefCell<Chains> = Default::default(); }
+
+/// Call every callback of `chain` (in installation order) until one returns [HookAction::Block], reporting panics for `hwnd` (if any.)
+/// Returns `true` if blocked.
+fn dispatch<F: ?Sized>(name: &str, hwnd: HWnd, chain: impl FnOnce(&Chains) -> &Chain<F>, mut call: impl FnMut(&mut F) -> HookAction) -> bool {
+    let subs = CHAINS.try_with(|c| match c.try_borrow() {
+        Ok(c)   => snapshot(&chain(&c).list),
+        Err(_)  => Vec::new(),
+    }).unwrap_or_default();
+    call_each(name, hwnd, subs, |f| call(f) == HookAction::Block)
+}
+
+/// Debug builds: warn if low level hook callbacks are at risk of `LowLevelHooksTimeout`.
+fn check_ll_duration(name: &str, start: Instant) {
+    let elapsed = start.elapsed();
+    if elapsed >= Duration::from_millis(200) {
+        eprintln!("hwnd::hooks: {name} callbacks took {elapsed:?} - low level hooks exceeding LowLevelHooksTimeout (at most 1s) are skipped, and may be silently removed");
+    }
+}
+
+// Hook procedures: never unwind (call_each catches panics), and use CallNextHookEx(nullptr, ...) (hhk is ignored) to avoid TLS access during thread teardown.
+
+unsafe extern "system" fn wh_keyboard_ll(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    let _gle = LastErrorGuard::new(); // don't clobber the last error of e.g. the wndproc we're hooking
+    if code == HC_ACTION {
+        let start = Instant::now();
+        let kb = unsafe { &*(lparam as *const KBDLLHOOKSTRUCT) };
+        let e = WhKeyboardLl { msg: WM32::from(wparam as u32), vk_code: kb.vkCode, scan_code: kb.scanCode, flags: kb.flags, time: kb.time, extra_info: kb.dwExtraInfo };
+        let blocked = dispatch("WH_KEYBOARD_LL", HWnd::NULL, |c| &c.keyboard_ll, |f| f(&e));
+        if cfg!(debug_assertions) { check_ll_duration("WH_KEYBOARD_LL", start) }
+        if blocked { return 1 }
+    }
+    unsafe { CallNextHookEx(null_mut(), code, wparam, lparam) }
+}
+
+unsafe extern "system" fn wh_mouse_ll(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    let _gle = LastErrorGuard::new(); // don't clobber the last error of e.g. the wndproc we're hooking
+    if code == HC_ACTION {
+        let start = Instant::now();
+        let ms = unsafe { &*(lparam as *const MSLLHOOKSTRUCT) };
+        let e = WhMouseLl { msg: WM32::from(wparam as u32), pt: ms.pt.into(), mouse_data: ms.mouseData, flags: ms.flags, time: ms.time, extra_info: ms.dwExtraInfo };
+        let blocked = dispatch("WH_MOUSE_LL", HWnd::NULL, |c| &c.mouse_ll, |f| f(&e));
+        if cfg!(debug_assertions) { check_ll_duration("WH_MOUSE_LL", start) }
+        if blocked { return 1 }
+    }
+    unsafe { CallNextHookEx(null_mut(), code, wparam, lparam) }
+}
+
+unsafe extern "system" fn wh_cbt(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    let _gle = LastErrorGuard::new(); // don't clobber the last error of e.g. the wndproc we're hooking
+    if code >= 0 {
+        let hwnd = HWnd::from(wparam as HWND);
+        let e = match code {
+            HCBT_ACTIVATE       => { let a = unsafe { &*(lparam as *const CBTACTIVATESTRUCT) }; WhCbt::Activate { hwnd, mouse: a.fMouse != 0, active: a.hWndActive.into() } },
+            HCBT_CLICKSKIPPED   => { let m = unsafe { &*(lparam as *const MOUSEHOOKSTRUCT) }; WhCbt::ClickSkipped { msg: WM32::from(wparam as u32), pt: m.pt.into(), hwnd: m.hwnd.into(), hit_test: m.wHitTestCode } },
+            HCBT_CREATEWND      => { let c = unsafe { &*(lparam as *const CBT_CREATEWNDW) }; WhCbt::CreateWnd { hwnd, cs: unsafe { &*c.lpcs }, insert_after: c.hwndInsertAfter.into() } },
+            HCBT_DESTROYWND     => WhCbt::DestroyWnd { hwnd },
+            HCBT_KEYSKIPPED     => WhCbt::KeySkipped { vk: wparam as u32, flags: lparam as u32 },
+            HCBT_MINMAX         => WhCbt::MinMax { hwnd, sw: SW::ShowWindowCmd::from((lparam & 0xFFFF) as i32) },
+            HCBT_MOVESIZE       => WhCbt::MoveSize { hwnd, rect: unsafe { *(lparam as *const RECT) }.into() },
+            HCBT_QS             => WhCbt::Qs,
+            HCBT_SETFOCUS       => WhCbt::SetFocus { hwnd, lost: HWnd::from(lparam as HWND) },
+            HCBT_SYSCOMMAND     => WhCbt::SysCommand { command: wparam, lparam },
+            _                   => WhCbt::Other { code, wparam, lparam },
+        };
+        let target = match e {
+            WhCbt::Activate { hwnd, .. } | WhCbt::ClickSkipped { hwnd, .. } | WhCbt::CreateWnd { hwnd, .. } | WhCbt::DestroyWnd { hwnd }
+            | WhCbt::MinMax { hwnd, .. } | WhCbt::MoveSize { hwnd, .. } | WhCbt::SetFocus { hwnd, .. } => hwnd,
+            WhCbt::KeySkipped { .. } | WhCbt::Qs | WhCbt::SysCommand { .. } | WhCbt::Other { .. } => HWnd::NULL,
+        };
+        if dispatch("WH_CBT", target, |c| &c.cbt, |f| f(e)) { return 1 }
+    }
+    unsafe { CallNextHookEx(null_mut(), code, wparam, lparam) }
+}
+
+unsafe extern "system" fn wh_getmessage(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    let _gle = LastErrorGuard::new(); // don't clobber the last error of e.g. the wndproc we're hooking
+    if code == HC_ACTION {
+        let removed = wparam == PM_REMOVE as WPARAM;
+        let msg = unsafe { &mut *(lparam as *mut Msg) }; // Msg is layout-compatible with MSG
+        dispatch("WH_GETMESSAGE", msg.hwnd, |c| &c.get_message, |f| { f(WhGetMessage { removed, msg: &mut *msg }); HookAction::CallNext });
+    }
+    unsafe { CallNextHookEx(null_mut(), code, wparam, lparam) }
+}
+
+unsafe extern "system" fn wh_callwndproc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    let _gle = LastErrorGuard::new(); // don't clobber the last error of e.g. the wndproc we're hooking
+    if code == HC_ACTION {
+        let call = unsafe { &*(lparam as *const CWPSTRUCT) };
+        let e = WhCallWndProc { from_current_thread: wparam != 0, message: SentMessage { hwnd: call.hwnd.into(), msg: WM32::from(call.message), wparam: call.wParam, lparam: call.lParam } };
+        dispatch("WH_CALLWNDPROC", e.message.hwnd, |c| &c.call_wnd_proc, |f| { f(&e); HookAction::CallNext });
+    }
+    unsafe { CallNextHookEx(null_mut(), code, wparam, lparam) }
+}
+
+
+
+#[test] fn cbt() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let hook = Hook::cbt({ let log = log.clone(); move |e| {
+        match e {
+            WhCbt::CreateWnd  { hwnd, .. } => log.borrow_mut().push(("create",  hwnd)),
+            WhCbt::DestroyWnd { hwnd     } => log.borrow_mut().push(("destroy", hwnd)),
+            _                              => {},
+        }
+        HookAction::CallNext
+    }}).unwrap();
+
+    let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+    unsafe { destroy_window(hwnd) }.unwrap();
+    assert_eq!(*log.borrow(), [("create", hwnd), ("destroy", hwnd)]);
+
+    drop(hook);
+    log.borrow_mut().clear();
+    let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+    unsafe { destroy_window(hwnd) }.unwrap();
+    assert!(log.borrow().is_empty());
+}
+
+#[test] fn cbt_block_and_panic() {
+    let _panic = Hook::cbt(|_| panic!("cbt callback panicked (expected by test)")).unwrap();
+    let _block = Hook::cbt(|e| if let WhCbt::CreateWnd { .. } = e { HookAction::Block } else { HookAction::CallNext }).unwrap();
+    let r = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) };
+    assert!(r.is_err(), "window creation should have been blocked (despite the earlier callback panicing)");
+}
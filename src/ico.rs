@@ -0,0 +1,194 @@
+//! Pure `.ico` / `.cur` directory parsing and size selection
+//!
+//! `.ico` and `.cur` files start with an ICONDIR header listing several images of different sizes and color depths.
+//! [IconDir::best] picks the one closest to a requested size and color depth (like `LookupIconIdFromDirectoryEx`), and [IconDir::image] returns its bytes, as accepted by [create_icon_from_resource_ex].
+//! [create_icon_from_ico] does all of the above.
+//!
+//! ### Example
+//! ```
+//! # use hwnd::*;
+//! # use hwnd::ico::*;
+//! # let ico = [
+//! #     &[0u8, 0, 1, 0, 2, 0][..],
+//! #     &[16, 16, 0, 0, 1, 0, 32, 0], &(40u32).to_le_bytes(), &(38u32).to_le_bytes(),
+//! #     &[32, 32, 0, 0, 1, 0, 32, 0], &(40u32).to_le_bytes(), &(78u32).to_le_bytes(),
+//! #     &[40, 0, 0, 0, 16, 0, 0, 0, 32, 0, 0, 0, 1, 0, 32, 0], &[0; 24],
+//! #     &[40, 0, 0, 0, 32, 0, 0, 0, 64, 0, 0, 0, 1, 0, 32, 0], &[0; 24],
+//! # ].concat();
+//! // let ico = std::fs::read("app.ico").unwrap();
+//! let dir = IconDir::parse(&ico).unwrap();
+//! assert_eq!(dir.kind(), IconKind::Icon);
+//! assert_eq!(dir.entries().len(), 2);
+//!
+//! let small = dir.best(16, 16, 32).unwrap();
+//! assert_eq!((small.width, small.height, small.bit_count), (16, 16, 32));
+//!
+//! let large = dir.best_for_dpi(16, 192, 32).unwrap(); // 200% scaling
+//! assert_eq!((large.width, large.height), (32, 32));
+//! assert_eq!(dir.image(large).len(), 40);
+//! ```
+
+use crate::*;
+
+
+
+/// Whether an [IconDir] contains icons (`.ico`) or cursors (`.cur`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub enum IconKind {
+    /// `.ico` (ICONDIR type `1`)
+    Icon,
+    /// `.cur` (ICONDIR type `2`): entries have hotspots
+    Cursor,
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/previous-versions/ms997538(v=msdn.10))\]
+/// ICONDIRENTRY
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] #[non_exhaustive] pub struct IconDirEntry {
+    /// Width in pixels (`256` if the directory specifies `0`)
+    pub width:      u32,
+
+    /// Height in pixels (`256` if the directory specifies `0`)
+    pub height:     u32,
+
+    /// Bits per pixel: read from the image itself if possible (PNG images are treated as `32`), since directories often leave this `0`
+    pub bit_count:  u16,
+
+    /// The cursor's hotspot ([IconKind::Cursor] only)
+    pub hotspot:    Option<Point>,
+
+    /// The image is PNG compressed (rather than a BITMAPINFOHEADER + XOR and AND masks)
+    pub png:        bool,
+
+    range:          (u32, u32),
+}
+
+/// A parsed `.ico` or `.cur` file, borrowing its bytes
+#[derive(Clone, Debug)] pub struct IconDir<'a> {
+    data:       &'a [u8],
+    kind:       IconKind,
+    entries:    Vec<IconDirEntry>,
+}
+
+impl<'a> IconDir<'a> {
+    /// Parse the ICONDIR header and ICONDIRENTRY list of a `.ico` or `.cur` file.
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_DATA]   if `data` isn't an `.ico` or `.cur` file, has no images, or is truncated
+    pub fn parse(data: &'a [u8]) -> Result<Self, Error> {
+        fn_context!(ico::IconDir::parse);
+        let invalid = || fn_error!(ERROR::INVALID_DATA);
+        let u16_at = |o: usize| data.get(o .. o + 2).map(|b| u16::from_le_bytes([b[0], b[1]])).ok_or_else(invalid);
+
+        if u16_at(0)? != 0 { return Err(invalid()) }
+        let kind = match u16_at(2)? { 1 => IconKind::Icon, 2 => IconKind::Cursor, _ => return Err(invalid()) };
+        let count = usize::from(u16_at(4)?);
+        if count == 0 { return Err(invalid()) }
+
+        let mut entries = Vec::with_capacity(count);
+        for i in 0 .. count {
+            let e = data.get(6 + 16 * i ..).and_then(|e| e.get(..16)).ok_or_else(invalid)?;
+            let dim = |b: u8| if b == 0 { 256 } else { u32::from(b) };
+            let (width, height, color_count) = (dim(e[0]), dim(e[1]), e[2]);
+            let (planes_or_x, bits_or_y) = (u16::from_le_bytes([e[4], e[5]]), u16::from_le_bytes([e[6], e[7]]));
+            let (size, offset) = (u32::from_le_bytes([e[8], e[9], e[10], e[11]]), u32::from_le_bytes([e[12], e[13], e[14], e[15]]));
+
+            let range = offset.checked_add(size).filter(|end| *end as usize <= data.len() && size != 0).map(|end| (offset, end)).ok_or_else(invalid)?;
+            let image = &data[range.0 as usize .. range.1 as usize];
+            let png = image.starts_with(b"\x89PNG\r\n\x1a\n");
+            let bit_count = if png { 32 } else if let Some(b) = image.get(14 .. 16) { u16::from_le_bytes([b[0], b[1]]) } else if kind == IconKind::Icon && bits_or_y != 0 { bits_or_y } else {
+                match color_count { 0 => 0, 2 => 1, c => 8 - (c - 1).leading_zeros() as u16 } // e.g. 16 colors → 4 bits
+            };
+            let hotspot = (kind == IconKind::Cursor).then(|| Point { x: planes_or_x.into(), y: bits_or_y.into() });
+            entries.push(IconDirEntry { width, height, bit_count, hotspot, png, range });
+        }
+        Ok(Self { data, kind, entries })
+    }
+
+    /// Icons or cursors?
+    pub fn kind(&self) -> IconKind { self.kind }
+
+    /// Every image, in directory order (never empty.)
+    pub fn entries(&self) -> &[IconDirEntry] { &self.entries }
+
+    /// The bytes of `entry`'s image (a BITMAPINFOHEADER followed by the XOR and AND masks, or a PNG), without any cursor hotspot.
+    pub fn image(&self, entry: &IconDirEntry) -> &'a [u8] {
+        self.data.get(entry.range.0 as usize .. entry.range.1 as usize).unwrap_or_default()
+    }
+
+    /// Pick the entry closest to `cx` by `cy` pixels and `depth` bits per pixel, the same way `LookupIconIdFromDirectoryEx` does.
+    ///
+    /// `depth` is the display's bits per pixel (usually `32`), or `1` for [LR::MONOCHROME].
+    /// If `cx` or `cy` is `0`, the first entry's width or height is used instead.
+    ///
+    /// Each entry is scored by how far its width, height, and bit count are from those requested, and the first entry with the lowest score wins.
+    /// Entries smaller than requested count double (scaling down looks better than scaling up), as do entries with more colors than requested (which would lose information.)
+    pub fn best(&self, cx: u32, cy: u32, depth: u16) -> Option<&IconDirEntry> {
+        let first = self.entries.first()?;
+        let cx = if cx == 0 { first.width  } else { cx };
+        let cy = if cy == 0 { first.height } else { cy };
+        let diff = |have: u32, want: u32, bigger_is_better: bool| if (have >= want) == bigger_is_better { have.abs_diff(want) } else { have.abs_diff(want).saturating_mul(2) };
+        let score = |e: &IconDirEntry| diff(e.width, cx, true).saturating_add(diff(e.height, cy, true)).saturating_add(diff(e.bit_count.into(), depth.into(), false));
+        self.entries.iter().min_by_key(|e| score(e)) // min_by_key returns the first minimum
+    }
+
+    /// Pick the entry closest to a `size` &times; `size` icon (at 96 DPI) scaled for `dpi` (e.g. `144` at 150% scaling), and `depth` bits per pixel.
+    ///
+    /// Equivalent to <code>[best](Self::best)\(size * dpi / 96, size * dpi / 96, depth\)</code> (rounded.)
+    pub fn best_for_dpi(&self, size: u32, dpi: u32, depth: u16) -> Option<&IconDirEntry> {
+        let scaled = u32::try_from((u64::from(size) * u64::from(dpi) + 48) / 96).unwrap_or(u32::MAX);
+        self.best(scaled, scaled, depth)
+    }
+}
+
+
+
+#[test] fn parse() {
+    fn entry(w: u8, h: u8, colors: u8, planes_or_x: u16, bits_or_y: u16, size: u32, offset: u32) -> Vec<u8> {
+        [&[w, h, colors, 0][..], &planes_or_x.to_le_bytes(), &bits_or_y.to_le_bytes(), &size.to_le_bytes(), &offset.to_le_bytes()].concat()
+    }
+    fn bmp(w: u32, h: u32, bits: u16) -> Vec<u8> {
+        [&40u32.to_le_bytes()[..], &w.to_le_bytes(), &(2*h).to_le_bytes(), &1u16.to_le_bytes(), &bits.to_le_bytes(), &[0; 24]].concat()
+    }
+
+    let images = [bmp(16, 16, 4), bmp(16, 16, 32), bmp(32, 32, 8), b"\x89PNG\r\n\x1a\n...".to_vec(), bmp(48, 48, 32)];
+    let mut offset = 6 + 16 * images.len() as u32;
+    let mut ico = vec![0, 0, 1, 0, images.len() as u8, 0];
+    for (image, (w, h)) in images.iter().zip([(16, 16), (16, 16), (32, 32), (0, 0), (48, 48)]) {
+        ico.extend(entry(w, h, 0, 1, 0, image.len() as _, offset)); // bit counts deliberately left 0
+        offset += image.len() as u32;
+    }
+    for image in images.iter() { ico.extend(image) }
+
+    let dir = IconDir::parse(&ico).unwrap();
+    assert_eq!(dir.kind(), IconKind::Icon);
+    let e = dir.entries();
+    assert_eq!(e.iter().map(|e| (e.width, e.height, e.bit_count, e.png)).collect::<Vec<_>>(), [(16, 16, 4, false), (16, 16, 32, false), (32, 32, 8, false), (256, 256, 32, true), (48, 48, 32, false)]);
+    assert!(e.iter().all(|e| e.hotspot.is_none()));
+    assert_eq!(dir.image(&e[3]), b"\x89PNG\r\n\x1a\n...");
+
+    assert_eq!(dir.best(16, 16, 32),        Some(&e[1]), "exact size and depth");
+    assert_eq!(dir.best(16, 16, 1),         Some(&e[0]), "monochrome: prefer fewer colors");
+    assert_eq!(dir.best(20, 20, 32),        Some(&e[1]), "closest size");
+    assert_eq!(dir.best(32, 32, 32),        Some(&e[2]), "exact size beats color depth");
+    assert_eq!(dir.best(36, 36, 32),        Some(&e[4]), "scaling 48x48 down beats scaling 32x32 up");
+    assert_eq!(dir.best(0, 0, 32),          Some(&e[1]), "0x0 uses the first entry's size");
+    assert_eq!(dir.best(1000, 1000, 32),    Some(&e[3]));
+    assert_eq!(dir.best_for_dpi(32, 96, 32),  Some(&e[2]));
+    assert_eq!(dir.best_for_dpi(32, 144, 32), Some(&e[4]), "150%: 48x48");
+    assert_eq!(dir.best_for_dpi(16, 120, 32), Some(&e[1]), "125%: 20x20");
+
+    // cursors
+    let image = bmp(32, 32, 32);
+    let cur = [&[0, 0, 2, 0, 1, 0][..], &entry(32, 32, 0, 5, 7, image.len() as _, 22), &image].concat();
+    let dir = IconDir::parse(&cur).unwrap();
+    assert_eq!(dir.kind(), IconKind::Cursor);
+    assert_eq!(dir.entries()[0].hotspot, Some(Point { x: 5, y: 7 }));
+    assert_eq!(dir.entries()[0].bit_count, 32);
+
+    // malformed
+    for bad in [&[][..], &[0, 0, 1, 0], &[0, 0, 1, 0, 0, 0], &[0, 0, 3, 0, 1, 0], &[1, 0, 1, 0, 1, 0], &ico[..ico.len()-1], &cur[..6+15]] {
+        assert_eq!(IconDir::parse(bad).unwrap_err(), ERROR::INVALID_DATA, "{bad:?}");
+    }
+    let mut zero_size = cur.clone();
+    zero_size[6+8 .. 6+12].copy_from_slice(&0u32.to_le_bytes());
+    assert_eq!(IconDir::parse(&zero_size).unwrap_err(), ERROR::INVALID_DATA);
+}
@@ -0,0 +1,364 @@
+//! Typed messages that can be sent without `unsafe`
+//!
+//! [send_message_w], [post_message_w], etc. are `unsafe` because `wparam` and `lparam` might need to be valid pointers,
+//! depending on the message and the receiving window.
+//! The descriptors in this module encode their parameter types instead, so [send], [send_timeout], [post], and [send_notify] can be safe:
+//! *   Pointer-free messages ([Null], [Close], [SetRedraw], [GetTextLength], and [Raw]) implement [Postable], and can be sent, posted, or notified.
+//! *   Pointer-carrying messages ([SetText], [GetText]) own their buffers, and can only be [send]t: asynchronous functions would outlive the buffers.
+//!
+//! ### Example
+//! ```
+//! # use hwnd::*;
+//! # use std::ptr::*;
+//! # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+//! msg::send(hwnd, msg::SetRedraw(false)).unwrap();
+//! msg::send(hwnd, msg::SetText::new("Title").unwrap()).unwrap();
+//! assert_eq!(5,       msg::send(hwnd, msg::GetTextLength).unwrap());
+//! assert_eq!("Title", msg::send(hwnd, msg::GetText::with_capacity(16)).unwrap());
+//! msg::post(hwnd, msg::Raw::new(WM::SYSCOMMAND, 0xF060, 0).unwrap()).unwrap(); // SC_CLOSE
+//!
+//! let wm = WM::REGISTERED("com.example.hwnd.WM_EXAMPLE").unwrap();
+//! // SAFETY: WM_EXAMPLE is only ever handled by this example, which treats its parameters as integers
+//! msg::post(hwnd, unsafe { msg::Raw::new_unchecked(wm, 2, 3) }).unwrap();
+//! ```
+
+use crate::*;
+use crate::SMTO::SendMessageTimeOutFlags;
+use std::ffi::*;
+use std::os::windows::ffi::*;
+
+
+
+/// A typed message that can be [send]t safely.
+///
+/// ### Safety
+/// *   [params](Self::params) may only return pointers to data owned or borrowed by `self`, which [send] keeps alive until the message has been processed.
+/// *   Those pointers must be valid for whatever the receiving window is documented to do with them (e.g. [WM::GETTEXT] writes `wparam` units to `lparam`.)
+/// *   If the message crosses processes, the system must marshal those pointers (see [MessageInfo::marshaled].)
+///     [send] rejects messages the catalogue knows aren't marshaled, but cannot check app-defined messages.
+pub unsafe trait Message {
+    /// The decoded `LRESULT`
+    type Result;
+
+    /// `(msg, wparam, lparam)`
+    fn params(&mut self) -> (WM32, WPARAM, LPARAM);
+
+    /// Decode the `LRESULT` returned by the receiving window
+    fn result(self, lresult: LRESULT) -> Self::Result;
+}
+
+/// A [Message] without pointer parameters, which can also be [post]ed, [send_notify]ed, or sent with a [send_timeout].
+///
+/// ### Safety
+/// *   [Message::params] must not return pointers, or values receivers might reasonably treat as pointers.
+pub unsafe trait Postable : Message {}
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-null)\]
+/// WM_NULL
+///
+/// Does nothing: useful for checking if a window is responsive.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)] pub struct Null;
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-close)\]
+/// WM_CLOSE
+///
+/// Asks a window to close (the default window procedure calls [destroy_window].)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)] pub struct Close;
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/gdi/wm-setredraw)\]
+/// WM_SETREDRAW
+///
+/// Allow (`true`) or prevent (`false`) changes in a window from being redrawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)] pub struct SetRedraw(pub bool);
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-gettextlength)\]
+/// WM_GETTEXTLENGTH
+///
+/// Retrieves the length of a window's text, in units (excluding the terminal `\0`.)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)] pub struct GetTextLength;
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settext)\]
+/// WM_SETTEXT
+///
+/// Sets a window's text, from an owned `\0`-terminated buffer.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)] pub struct SetText { text: Vec<u16> }
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-gettext)\]
+/// WM_GETTEXT
+///
+/// Retrieves a window's text, into an owned buffer.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)] pub struct GetText { buffer: Vec<u16> }
+
+/// A pointer-free message with arbitrary integer parameters.
+///
+/// [Raw::new] only accepts an explicit allowlist of system requests and input messages, whose parameters are known to be plain integers
+/// (e.g. [WM::NULL], [WM::SYSCOMMAND], [WM::KEYDOWN], [WM::MOUSEMOVE], ...)
+///
+/// Everything else requires [Raw::new_unchecked]:
+/// *   Other system messages, which might be notifications receivers trust (e.g. spoofing [WM::NCDESTROY] would trigger teardown of a live window), or carry pointers or opaque values (e.g. [WM::INITDIALOG].)
+/// *   Messages in the [WM::USER] range, which are defined by each window class (e.g. edit and list view controls), and frequently take pointers.
+/// *   App-defined messages ([WM::APP]`(n)`), which are frequently used to pass pointers within an application.
+/// *   Registered messages ([WM::REGISTERED], `0xC000 ..= 0xFFFF`), which are defined by whichever modules register them, and frequently take pointers
+///     (e.g. commdlg's `FINDMSGSTRING`, or `SHELLHOOK`'s `HSHELL_GETMINRECT`.)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub struct Raw { wm: WM32, wparam: WPARAM, lparam: LPARAM }
+
+impl SetText {
+    /// Encode `text` as UTF-16.
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_PARAMETER]      If `text` contains interior `\0`s
+    pub fn new(text: impl AsRef<OsStr>) -> Result<Self, Error> {
+        fn_context!(msg::SetText::new => WM_SETTEXT);
+        let mut text = text.as_ref().encode_wide().collect::<Vec<_>>();
+        if text.contains(&0) { return Err(fn_param_error!(text, ERROR::INVALID_PARAMETER)) }
+        text.push(0);
+        Ok(Self { text })
+    }
+}
+
+impl GetText {
+    /// Retrieve at most `capacity` units of text (excluding the terminal `\0`.)
+    pub fn with_capacity(capacity: usize) -> Self { Self { buffer: vec![0; capacity.saturating_add(1)] } }
+}
+
+impl Raw {
+    /// `None` if `wm` might take pointer parameters, or otherwise isn't known to be safe to send (see [Raw].)
+    pub fn new(wm: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM) -> Option<Self> {
+        let wm = wm.into();
+        SAFE_SYSTEM_MESSAGES.contains(&wm).then_some(Self { wm, wparam, lparam })
+    }
+
+    /// Create a message with arbitrary integer parameters, without checking `wm`.
+    ///
+    /// ### Safety
+    /// *   Every window this is sent or posted to must treat `wparam` and `lparam` as plain integers for `wm` (never as pointers, or trusted values like the address of a callback.)
+    /// *   `wm` must not be a notification receivers rely on only the system sending (e.g. [WM::DESTROY] or [WM::NCDESTROY].)
+    pub unsafe fn new_unchecked(wm: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM) -> Self { Self { wm: wm.into(), wparam, lparam } }
+
+    pub fn wm(&self) -> WM32 { self.wm }
+    pub fn wparam(&self) -> WPARAM { self.wparam }
+    pub fn lparam(&self) -> LPARAM { self.lparam }
+}
+
+/// System messages [Raw::new] accepts: requests and input, whose parameters are only ever integers
+const SAFE_SYSTEM_MESSAGES : &[WM32] = &[
+    WM::NULL, WM::CLOSE, WM::SETREDRAW, WM::GETTEXTLENGTH, WM::CANCELMODE, WM::SYSCOMMAND,
+    WM::KEYDOWN, WM::KEYUP, WM::CHAR, WM::DEADCHAR, WM::SYSKEYDOWN, WM::SYSKEYUP, WM::SYSCHAR, WM::SYSDEADCHAR, WM::UNICHAR,
+    WM::MOUSEMOVE, WM::MOUSEWHEEL, WM::MOUSEHWHEEL,
+    WM::LBUTTONDOWN, WM::LBUTTONUP, WM::LBUTTONDBLCLK,
+    WM::RBUTTONDOWN, WM::RBUTTONUP, WM::RBUTTONDBLCLK,
+    WM::MBUTTONDOWN, WM::MBUTTONUP, WM::MBUTTONDBLCLK,
+    WM::XBUTTONDOWN, WM::XBUTTONUP, WM::XBUTTONDBLCLK,
+];
+
+unsafe impl Message for Null            { type Result = ();         fn params(&mut self) -> (WM32, WPARAM, LPARAM) { (WM::NULL,             0,              0) } fn result(self, _: LRESULT) {} }
+unsafe impl Message for Close           { type Result = ();         fn params(&mut self) -> (WM32, WPARAM, LPARAM) { (WM::CLOSE,            0,              0) } fn result(self, _: LRESULT) {} }
+unsafe impl Message for SetRedraw       { type Result = ();         fn params(&mut self) -> (WM32, WPARAM, LPARAM) { (WM::SETREDRAW,        self.0 as _,    0) } fn result(self, _: LRESULT) {} }
+unsafe impl Message for GetTextLength   { type Result = usize;      fn params(&mut self) -> (WM32, WPARAM, LPARAM) { (WM::GETTEXTLENGTH,    0,              0) } fn result(self, lr: LRESULT) -> usize { lr as usize } }
+unsafe impl Message for Raw             { type Result = LRESULT;    fn params(&mut self) -> (WM32, WPARAM, LPARAM) { (self.wm, self.wparam, self.lparam) } fn result(self, lr: LRESULT) -> LRESULT { lr } }
+
+unsafe impl Postable for Null           {}
+unsafe impl Postable for Close          {}
+unsafe impl Postable for SetRedraw      {}
+unsafe impl Postable for GetTextLength  {}
+unsafe impl Postable for Raw            {}
+
+unsafe impl Message for SetText {
+    /// `true` if the text was set
+    type Result = bool;
+    fn params(&mut self) -> (WM32, WPARAM, LPARAM) { (WM::SETTEXT, 0, self.text.as_ptr() as LPARAM) }
+    fn result(self, lr: LRESULT) -> bool { lr != 0 }
+}
+
+unsafe impl Message for GetText {
+    type Result = OsString;
+    fn params(&mut self) -> (WM32, WPARAM, LPARAM) { (WM::GETTEXT, self.buffer.len(), self.buffer.as_mut_ptr() as LPARAM) }
+    fn result(self, lr: LRESULT) -> OsString {
+        let n = (lr as usize).min(self.buffer.len().saturating_sub(1)); // don't trust other processes
+        OsString::from_wide(&self.buffer[..n])
+    }
+}
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-sendmessagew)\]
+/// SendMessageW
+///
+/// Sends a typed message to `hwnd` and waits for it to be processed.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  If `hwnd` is invalid
+/// *   [ERROR::INVALID_PARAMETER]      If `msg` has pointers which aren't marshaled, and `hwnd` belongs to another process (or is [HWnd::BROADCAST].)
+/// *   [ERROR::ACCESS_DENIED]          When a message is blocked by [UIPI](https://en.wikipedia.org/wiki/User_Interface_Privilege_Isolation)
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), abistr::cstr16!("Title"), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+/// msg::send(hwnd, msg::Null).unwrap();
+/// msg::send(get_desktop_window(), msg::Null).unwrap();
+/// assert_eq!("Title", msg::send(hwnd, msg::GetText::with_capacity(64)).unwrap());
+/// assert_eq!("Ti",    msg::send(hwnd, msg::GetText::with_capacity(2)).unwrap());
+/// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, msg::send(!42usize as HWND, msg::Null).unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [send_message_w] (unsafe, untyped)
+/// *   [send_timeout]
+pub fn send<M: Message>(hwnd: impl TryInto<HWnd>, mut msg: M) -> Result<M::Result, Error> {
+    fn_context!(msg::send => SendMessageW);
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?;
+    let (wm, wparam, lparam) = msg.params();
+    check_marshaled(hwnd, wm)?;
+    // SAFETY: per `Message`'s contract, any pointers borrow `msg`, which outlives the (synchronous) send
+    let lr = unsafe { send_message_w(hwnd, wm, wparam, lparam) }?;
+    Ok(msg.result(lr))
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-sendmessagetimeoutw)\]
+/// SendMessageTimeoutW
+///
+/// Sends a typed message to `hwnd` and waits for it to be processed for up to `timeout` milliseconds.
+/// Only [Postable] messages are accepted: a message that times out may still be processed later, after `msg` has been dropped.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  If `hwnd` is invalid
+/// *   [ERROR::TIMEOUT]                `hwnd` didn't respond within `timeout` milliseconds.
+/// *   [ERROR::ACCESS_DENIED]          When a message is blocked by [UIPI](https://en.wikipedia.org/wiki/User_Interface_Privilege_Isolation)
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+/// msg::send_timeout(hwnd, msg::Null, SMTO::ABORTIFHUNG, 100).unwrap();
+/// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, msg::send_timeout(!42usize as HWND, msg::Null, SMTO::NORMAL, 100).unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [send_message_timeout_w] (unsafe, untyped)
+/// *   [send]
+pub fn send_timeout<M: Postable>(hwnd: impl TryInto<HWnd>, mut msg: M, flags: impl Into<SendMessageTimeOutFlags>, timeout: u32) -> Result<M::Result, Error> {
+    fn_context!(msg::send_timeout => SendMessageTimeoutW);
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?;
+    let (wm, wparam, lparam) = msg.params();
+    // SAFETY: `Postable` messages don't have pointers
+    let lr = unsafe { send_message_timeout_w(hwnd, wm, wparam, lparam, flags, timeout) }?;
+    Ok(msg.result(lr))
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-postmessagew)\]
+/// PostMessageW
+///
+/// Posts a typed message to the message queue of `hwnd`'s thread, without waiting for it to be processed.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  If `hwnd` is invalid
+/// *   [ERROR::ACCESS_DENIED]          When a message is blocked by [UIPI](https://en.wikipedia.org/wiki/User_Interface_Privilege_Isolation)
+/// *   [ERROR::NOT_ENOUGH_QUOTA]       If the message queue is full.  (A message queue can contain at most 10,000 messages.)
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+/// msg::post(hwnd, msg::Close).unwrap();
+/// msg::post(hwnd, msg::Raw::new(WM::SYSCOMMAND, 0xF060, 0).unwrap()).unwrap(); // SC_CLOSE
+/// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, msg::post(!42usize as HWND, msg::Null).unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [post_message_w] (unsafe, untyped)
+/// *   [send_notify]
+pub fn post(hwnd: impl TryInto<HWnd>, mut msg: impl Postable) -> Result<(), Error> {
+    fn_context!(msg::post => PostMessageW);
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?;
+    let (wm, wparam, lparam) = msg.params();
+    // SAFETY: `Postable` messages don't have pointers
+    unsafe { post_message_w(hwnd, wm, wparam, lparam) }
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-sendnotifymessagew)\]
+/// SendNotifyMessageW
+///
+/// Sends a typed message to `hwnd`.
+/// Windows of the current thread process it immediately, other threads' windows have it enqueued without waiting.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  If `hwnd` is invalid
+/// *   [ERROR::ACCESS_DENIED]          When a message is blocked by [UIPI](https://en.wikipedia.org/wiki/User_Interface_Privilege_Isolation)
+/// *   [ERROR::NOT_ENOUGH_QUOTA]       If the message queue is full.  (A message queue can contain at most 10,000 messages.)
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+/// msg::send_notify(hwnd, msg::SetRedraw(true)).unwrap();
+/// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, msg::send_notify(!42usize as HWND, msg::Null).unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [send_notify_message_w] (unsafe, untyped)
+/// *   [post]
+pub fn send_notify(hwnd: impl TryInto<HWnd>, mut msg: impl Postable) -> Result<(), Error> {
+    fn_context!(msg::send_notify => SendNotifyMessageW);
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?;
+    let (wm, wparam, lparam) = msg.params();
+    // SAFETY: `Postable` messages don't have pointers
+    unsafe { send_notify_message_w(hwnd, wm, wparam, lparam) }
+}
+
+/// Reject system messages with unmarshaled pointers if they'd cross processes
+fn check_marshaled(hwnd: HWnd, wm: WM32) -> Result<(), Error> {
+    fn_context!(msg::send => SendMessageW);
+    let Some(info) = wm.info() else { return Ok(()) };
+    if info.marshaled { return Ok(()) }
+    if hwnd == HWnd::BROADCAST { return Err(fn_param_error!(hwnd, ERROR::INVALID_PARAMETER)) }
+    let (_thread, process) = get_window_thread_process_id(hwnd)?;
+    if process != get_current_process_id() { return Err(fn_param_error!(hwnd, ERROR::INVALID_PARAMETER)) }
+    Ok(())
+}
+
+
+
+#[test] fn raw() {
+    assert!(Raw::new(WM::NULL,          0, 0).is_some());
+    assert!(Raw::new(WM::SETREDRAW,     1, 0).is_some());
+    assert!(Raw::new(WM::KEYDOWN,       0x41, 1).is_some());
+
+    assert!(Raw::new(WM::SETTEXT,       0, 0).is_none(), "pointer parameter");
+    assert!(Raw::new(WM::TIMER,         1, 0).is_none(), "TIMERPROC parameter");
+    assert!(Raw::new(WM::INITDIALOG,    0, 0).is_none(), "opaque (usually pointer) parameter");
+    assert!(Raw::new(WM::NCDESTROY,     0, 0).is_none(), "teardown notification");
+    assert!(Raw::new(WM::DESTROY,       0, 0).is_none(), "teardown notification");
+    assert!(Raw::new(WM::USER(1),       0, 0).is_none(), "class-defined");
+    assert!(Raw::new(WM::APP(42),       1, 2).is_none(), "app-defined");
+    assert!(Raw::new(0xC123u32,         1, 2).is_none(), "registered");
+    assert!(Raw::new(0x03FFu32,         0, 0).is_none(), "unknown system message");
+    assert!(Raw::new(0x1_0000u32,       0, 0).is_none(), "reserved");
+
+    for wm in SAFE_SYSTEM_MESSAGES.iter().copied() {
+        let info = wm.info().unwrap_or_else(|| panic!("{wm:?}: missing MessageInfo"));
+        for param in [info.wparam, info.lparam] { assert!(matches!(param, MessageParam::Unused | MessageParam::Value), "{wm:?}: allowlisted, but has {param:?} parameters") }
+    }
+
+    let wm = WM::REGISTERED("hwnd::msg::raw::WM_TEST").unwrap();
+    assert!(Raw::new(wm, 1, 2).is_none(), "registered by this process, but parameters are still up to the registering module(s)");
+    let hwnd = crate::utils::testing::test_window();
+    // SAFETY: WM_TEST is only ever retrieved by this test, which treats its parameters as integers
+    post(hwnd, unsafe { Raw::new_unchecked(wm, 1, 2) }).unwrap();
+    let m = peek_message_w(hwnd, wm, wm, PM::REMOVE).expect("posted WM_TEST");
+    assert_eq!((m.message, m.wparam, m.lparam), (wm, 1, 2));
+    unsafe { destroy_window(hwnd) }.unwrap();
+
+    assert_eq!(ERROR::INVALID_PARAMETER, SetText::new("a\0b").unwrap_err());
+    assert_eq!(SetText::new("ab").unwrap().text, [b'a' as u16, b'b' as u16, 0]);
+}
@@ -0,0 +1,121 @@
+//! Pure pixel format conversions for icons, cursors, and layered windows
+//!
+//! Windows bitmaps store 32-bit pixels as `[b, g, r, a]` (BGRA) bytes, while most image crates produce `[r, g, b, a]` (RGBA.)
+//! Icons and cursors ([create_icon_from_rgba]) use straight alpha, but some APIs (e.g. `AlphaBlend`, `UpdateLayeredWindow`) require premultiplied alpha.
+//!
+//! ### Example
+//! ```
+//! # use hwnd::pixels::*;
+//! let mut pixels = vec![0xFF, 0x80, 0x00, 0x80]; // RGBA: half transparent orange
+//! swap_red_blue(&mut pixels);
+//! assert_eq!(pixels, [0x00, 0x80, 0xFF, 0x80]); // BGRA
+//! premultiply_alpha(&mut pixels);
+//! assert_eq!(pixels, [0x00, 0x40, 0x80, 0x80]); // premultiplied BGRA
+//! ```
+
+#[cfg(doc)] use crate::*;
+
+
+
+/// Convert 32-bit RGBA pixels to BGRA (or vice versa) in place, by swapping the 1st and 3rd byte of every pixel.
+///
+/// ### Panics
+/// *   If `pixels.len()` isn't a multiple of 4
+pub fn swap_red_blue(pixels: &mut [u8]) {
+    assert!(pixels.len().is_multiple_of(4), "swap_red_blue: pixels.len() ({}) isn't a multiple of 4", pixels.len());
+    for px in pixels.chunks_exact_mut(4) { px.swap(0, 2) }
+}
+
+/// Premultiply the color channels of 32-bit RGBA or BGRA pixels (alpha last) by their alpha, in place.
+///
+/// Each channel becomes `round(channel * alpha / 255)`: fully opaque pixels are unchanged, and fully transparent pixels become `[0, 0, 0, 0]`.
+///
+/// ### Panics
+/// *   If `pixels.len()` isn't a multiple of 4
+pub fn premultiply_alpha(pixels: &mut [u8]) {
+    assert!(pixels.len().is_multiple_of(4), "premultiply_alpha: pixels.len() ({}) isn't a multiple of 4", pixels.len());
+    for px in pixels.chunks_exact_mut(4) {
+        let a = u32::from(px[3]);
+        for c in &mut px[..3] { *c = ((u32::from(*c) * a + 127) / 255) as u8 }
+    }
+}
+
+/// Check that no color channel of 32-bit RGBA or BGRA pixels (alpha last) exceeds its alpha, as is always true of premultiplied pixels.
+///
+/// Straight alpha pixels usually fail this check (unless they're opaque), and would be blended incorrectly by e.g. [update_layered_window].
+///
+/// ### Panics
+/// *   If `pixels.len()` isn't a multiple of 4
+pub fn is_premultiplied(pixels: &[u8]) -> bool {
+    assert!(pixels.len().is_multiple_of(4), "is_premultiplied: pixels.len() ({}) isn't a multiple of 4", pixels.len());
+    pixels.chunks_exact(4).all(|px| px[..3].iter().all(|&c| c <= px[3]))
+}
+
+/// Build a monochrome AND mask (as expected by `CreateBitmap(width, height, 1, 1, ...)`) from the alpha channel of 32-bit RGBA or BGRA pixels.
+///
+/// Bits are `1` (transparent: the screen shows through) where alpha is `0`, and `0` (opaque) elsewhere.
+/// Rows are top-down, most significant bit first, with each row padded to a multiple of 16 bits as required by `CreateBitmap`.
+///
+/// ### Panics
+/// *   If `pixels.len() != width * height * 4`
+///
+/// ### Example
+/// ```
+/// # use hwnd::pixels::*;
+/// let pixels = [
+///     [0, 0, 0, 0x00], [0, 0, 0, 0xFF], [0, 0, 0, 0x01],
+///     [0, 0, 0, 0xFF], [0, 0, 0, 0x00], [0, 0, 0, 0x00],
+/// ].concat();
+/// assert_eq!(and_mask(3, 2, &pixels), [
+///     0b1000_0000, 0,
+///     0b0110_0000, 0,
+/// ]);
+/// ```
+pub fn and_mask(width: usize, height: usize, pixels: &[u8]) -> Vec<u8> {
+    assert!(width.checked_mul(height).and_then(|n| n.checked_mul(4)) == Some(pixels.len()), "and_mask: pixels.len() ({}) != {width} * {height} * 4", pixels.len());
+    let stride  = width.div_ceil(16) * 2;
+    let mut mask = vec![0u8; stride * height];
+    if width == 0 { return mask }
+    for (row, mask) in pixels.chunks_exact(width * 4).zip(mask.chunks_exact_mut(stride)) {
+        for (x, px) in row.chunks_exact(4).enumerate() {
+            if px[3] == 0 { mask[x / 8] |= 0x80 >> (x % 8) }
+        }
+    }
+    mask
+}
+
+
+
+#[test] fn premultiply() {
+    for c in 0 ..= 255 {
+        let mut px = [c, c, c, 0xFF];
+        premultiply_alpha(&mut px);
+        assert_eq!(px, [c, c, c, 0xFF], "opaque pixels should be unchanged");
+
+        let mut px = [c, c, c, 0];
+        premultiply_alpha(&mut px);
+        assert_eq!(px, [0, 0, 0, 0], "transparent pixels should be black");
+
+        for a in 0 ..= 255 {
+            let mut px = [c, 0xFF, 0, a];
+            premultiply_alpha(&mut px);
+            let exact = f64::from(c) * f64::from(a) / 255.0;
+            assert!((f64::from(px[0]) - exact).abs() <= 0.5, "{c} * {a} / 255 ≈ {exact}, not {}", px[0]);
+            assert!(px[0] <= a, "premultiplied channels can't exceed alpha");
+            assert_eq!([px[1], px[2], px[3]], [a, 0, a]);
+            assert!(is_premultiplied(&px));
+        }
+    }
+
+    assert!(is_premultiplied(&[]));
+    assert!(is_premultiplied(&[0xFF, 0x80, 0x00, 0xFF]), "opaque pixels are trivially premultiplied");
+    assert!(!is_premultiplied(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x81, 0x00, 0x80]), "a straight alpha, half transparent green");
+
+    let mut px = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+    swap_red_blue(&mut px);
+    assert_eq!(px, [0x56, 0x34, 0x12, 0x78, 0xDE, 0xBC, 0x9A, 0xF0]);
+
+    assert_eq!(and_mask(0, 0, &[]), []);
+    assert_eq!(and_mask(17, 1, &[0; 17 * 4]), [0xFF, 0xFF, 0x80, 0x00], "rows should be padded to 16 bits");
+    assert_eq!(and_mask(1, 3, &[0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0]), [0x80, 0, 0x00, 0, 0x80, 0]);
+}
@@ -61,6 +61,7 @@ impl NameAtomOrZero<'_, u16> { pub fn as_atom_or_cstr_ptr(&self) -> *const u16
 impl NameOrAtom    <'_,  u8> { pub fn as_atom_or_cstr_ptr(&self) -> *const c_char { self.0.get() as _ } }
 impl NameOrAtom    <'_, u16> { pub fn as_atom_or_cstr_ptr(&self) -> *const u16    { self.0.get() as _ } }
 
+impl<'a, U: Unit> From<()                           > for NameAtomOrZero<'a, U> { fn from(_:                        ()) -> Self { Self(0,                   PhantomData) } }
 impl<'a, U: Unit> From<u16                          > for NameAtomOrZero<'a, U> { fn from(atom:                     u16) -> Self { Self(atom as _,          PhantomData) } }
 impl<'a, U: Unit> From<Atom                         > for NameAtomOrZero<'a, U> { fn from(atom:                    Atom) -> Self { Self(atom.to_u16() as _, PhantomData) } }
 impl<'a, U: Unit> From<AtomNonZero                  > for NameAtomOrZero<'a, U> { fn from(atom:             AtomNonZero) -> Self { Self(atom.to_u16() as _, PhantomData) } }
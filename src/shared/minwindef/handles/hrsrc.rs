@@ -0,0 +1,28 @@
+use crate::*;
+use winapi::shared::minwindef::HRSRC;
+use std::fmt::{self, Debug, Formatter};
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-findresourceexw)\]
+/// HRSRC, and the [HModule] it was found in
+///
+/// Returned by [find_resource_ex_w], and accepted by [load_resource].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HRsrc<'m> {
+    pub(crate) hmodule: HModule<'m>,
+    pub(crate) hrsrc:   usize,
+}
+
+impl<'m> HRsrc<'m> {
+    /// ### Safety
+    /// *   `hrsrc` must currently be a valid resource handle of `hmodule`, or null
+    pub unsafe fn from_unchecked(hmodule: HModule<'m>, hrsrc: HRSRC) -> Self { Self { hmodule, hrsrc: hrsrc as _ } }
+
+    /// The module containing the resource.
+    pub fn hmodule(&self) -> HModule<'m> { self.hmodule }
+}
+
+impl From<HRsrc<'_>> for HRSRC { fn from(r: HRsrc) -> Self { r.hrsrc as _ } }
+
+impl Debug for HRsrc<'_> { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "HRsrc({:?}, 0x{:X})", self.hmodule, self.hrsrc) } }
@@ -0,0 +1,47 @@
+use crate::*;
+use winapi::shared::minwindef::HMODULE;
+use winapi::um::libloaderapi::FreeLibrary;
+use std::fmt::{self, Debug, Formatter};
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-freelibrary)\]
+/// An owned, non-null HMODULE from [load_library_ex_w], freed with FreeLibrary when dropped.
+///
+/// Borrow it as a [HModule] / [HInstance] with [hmodule](Self::hmodule) (e.g. for [load_icon_w] or [load_string_w]):
+/// resources borrowed from the module (e.g. by [load_resource]) can't outlive the [Library].
+///
+/// ### Example
+/// ```
+/// # use abistr::*;
+/// # use hwnd::*;
+/// let shell32 = load_library_as_resource_w(cstr16!("shell32.dll")).unwrap();
+/// let folder  = load_icon_w(&shell32, 4).unwrap();
+/// ```
+pub struct Library(HModule<'static>);
+
+impl Library {
+    /// ### Safety
+    /// *   `hmodule` must be a valid, non-null module handle, with a reference count owned by the caller (e.g. from `LoadLibraryExW`.)
+    pub unsafe fn from_raw(hmodule: HMODULE) -> Self { Self(unsafe { HModule::from_unchecked(hmodule) }) }
+
+    /// Release ownership of the module, which will no longer be freed.
+    pub fn into_raw(self) -> HMODULE { std::mem::ManuallyDrop::new(self).0.into() }
+
+    /// Borrow the module.
+    pub fn hmodule(&self) -> HModule<'_> { self.0 }
+
+    /// Never free the module, and borrow it forever.
+    pub fn leak(self) -> HModule<'static> { std::mem::ManuallyDrop::new(self).0 }
+}
+
+impl Drop for Library {
+    fn drop(&mut self) {
+        let _freed = unsafe { FreeLibrary(self.0.into()) };
+        debug_assert!(_freed != 0, "FreeLibrary failed: {:?}", Error::new_gle());
+    }
+}
+
+impl<'a> From<&'a Library> for HModule<'a> { fn from(library: &'a Library) -> Self { library.hmodule() } }
+
+impl Debug for Library { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "Library({:?})", self.0) } }
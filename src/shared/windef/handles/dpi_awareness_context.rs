@@ -0,0 +1,34 @@
+use bytemuck::*;
+use winapi::shared::windef::DPI_AWARENESS_CONTEXT;
+use std::fmt::{self, Debug, Formatter};
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/hidpi/dpi-awareness-context)\]
+/// DPI_AWARENESS_CONTEXT
+///
+/// Either one of the [DPI_AWARENESS_CONTEXT](crate::DPI_AWARENESS_CONTEXT) pseudo-handles,
+/// or an opaque context returned by e.g. [set_thread_dpi_awareness_context](crate::set_thread_dpi_awareness_context) (which compares unequal to said pseudo-handles, even if equivalent.)
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Zeroable)]
+#[repr(transparent)]
+pub struct DpiAwarenessContext(isize);
+
+impl DpiAwarenessContext {
+    #[doc(hidden)] pub const fn from_constant(context: isize) -> Self { Self(context) }
+}
+
+impl From<DpiAwarenessContext> for DPI_AWARENESS_CONTEXT { fn from(c: DpiAwarenessContext) -> Self { c.0 as _ } }
+impl From<DPI_AWARENESS_CONTEXT> for DpiAwarenessContext { fn from(c: DPI_AWARENESS_CONTEXT) -> Self { Self(c as _) } }
+
+impl Debug for DpiAwarenessContext {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self.0 {
+            -1 => write!(fmt, "DPI_AWARENESS_CONTEXT::UNAWARE"),
+            -2 => write!(fmt, "DPI_AWARENESS_CONTEXT::SYSTEM_AWARE"),
+            -3 => write!(fmt, "DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE"),
+            -4 => write!(fmt, "DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2"),
+            -5 => write!(fmt, "DPI_AWARENESS_CONTEXT::UNAWARE_GDISCALED"),
+            n  => write!(fmt, "DpiAwarenessContext(0x{n:X})"),
+        }
+    }
+}
@@ -22,5 +22,6 @@ unsafe impl Zeroable for HCursor<'_> {}
 
 impl From<HCursor<'_>> for HCURSOR { fn from(c: HCursor) -> Self { c.0 as _ } }
 impl From<()> for HCursor<'_> { fn from(_: ()) -> Self { Self(0, PhantomData) } }
+impl<'a> From<HIcon<'a>> for HCursor<'a> { fn from(i: HIcon<'a>) -> Self { Self(HICON::from(i) as _, PhantomData) } } // HCURSOR is a typedef of HICON
 
 impl Debug for HCursor<'_> { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "HCursor(0x{:X})", self.0) } }
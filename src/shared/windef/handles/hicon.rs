@@ -22,5 +22,6 @@ unsafe impl Zeroable for HIcon<'_> {}
 
 impl From<HIcon<'_>> for HICON { fn from(c: HIcon) -> Self { c.0 as _ } }
 impl From<()> for HIcon<'_> { fn from(_: ()) -> Self { Self(0, PhantomData) } }
+impl<'a> From<HCursor<'a>> for HIcon<'a> { fn from(c: HCursor<'a>) -> Self { Self(HCURSOR::from(c) as _, PhantomData) } } // HCURSOR is a typedef of HICON
 
 impl Debug for HIcon<'_> { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "HIcon(0x{:X})", self.0) } }
@@ -0,0 +1,58 @@
+use crate::*;
+use winapi::um::winuser::DestroyIcon;
+use std::fmt::{self, Debug, Formatter};
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-destroyicon)\]
+/// An owned, non-null HICON (or HCURSOR), destroyed with DestroyIcon when dropped.
+///
+/// Borrow it as a [HIcon] / [HCursor] with [as_hicon](Self::as_hicon) / [as_hcursor](Self::as_hcursor).
+/// Window classes ([WndClassW::hicon] etc.) can't borrow it: a window class can outlive any borrow (e.g. if unregistering fails with [ERROR::CLASS_HAS_WINDOWS].)
+/// Instead, either give the icon to a [RegisteredClass] (which destroys it once the class is unregistered), or [leak](Self::leak) it.
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// let icon = create_icon_from_rgba(16, 16, &[0xFF; 16 * 16 * 4], None).unwrap();
+/// let class = WndClassExW { class_name: Some(abistr::cstr16!("OwnedIcon.docs")), wnd_proc: Some(def_window_proc_w), .. Default::default() };
+/// let class = unsafe { RegisteredClass::register_ex_w(&class, ClassIcons { hicon: Some(icon), .. Default::default() }) }.unwrap();
+///
+/// let icon = create_icon_from_rgba(16, 16, &[0xFF; 16 * 16 * 4], None).unwrap();
+/// let leaky = WndClassW { hicon: icon.leak(), .. Default::default() };
+/// ```
+pub struct OwnedIcon(HIcon<'static>);
+
+impl OwnedIcon {
+    /// ### Safety
+    /// *   `hicon` must be a valid, non-null icon or cursor handle, created by e.g. `CreateIconIndirect` (**not** shared, e.g. from [load_icon_w].)
+    /// *   `hicon` must not be destroyed by anything else.
+    pub unsafe fn from_raw(hicon: HICON) -> Self { Self(unsafe { HIcon::from_unchecked(hicon) }) }
+
+    /// Release ownership of the icon, which will no longer be destroyed.
+    pub fn into_raw(self) -> HICON { std::mem::ManuallyDrop::new(self).0.into() }
+
+    /// Borrow the icon.
+    pub fn as_hicon(&self) -> HIcon<'_> { self.0 }
+
+    /// Borrow the icon as a cursor (HCURSOR is a typedef of HICON.)
+    pub fn as_hcursor(&self) -> HCursor<'_> { unsafe { HCursor::from_unchecked(self.0.into()) } }
+
+    /// Never destroy the icon, and borrow it forever (e.g. for [WndClassW::hicon].)
+    pub fn leak(self) -> HIcon<'static> { std::mem::ManuallyDrop::new(self).0 }
+
+    /// Never destroy the cursor, and borrow it forever (e.g. for [WndClassW::hcursor].)
+    pub fn leak_hcursor(self) -> HCursor<'static> { unsafe { HCursor::from_unchecked(self.into_raw()) } }
+}
+
+impl Drop for OwnedIcon {
+    fn drop(&mut self) {
+        let _destroyed = unsafe { DestroyIcon(self.0.into()) };
+        debug_assert!(_destroyed != 0, "DestroyIcon failed: {:?}", Error::new_gle());
+    }
+}
+
+impl<'a> From<&'a OwnedIcon> for HIcon<'a>      { fn from(icon: &'a OwnedIcon) -> Self { icon.as_hicon() } }
+impl<'a> From<&'a OwnedIcon> for HCursor<'a>    { fn from(icon: &'a OwnedIcon) -> Self { icon.as_hcursor() } }
+
+impl Debug for OwnedIcon { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "OwnedIcon({:?})", self.0) } }
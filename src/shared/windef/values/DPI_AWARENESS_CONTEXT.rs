@@ -0,0 +1,22 @@
+//! \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/hidpi/dpi-awareness-context)\]
+//! DPI_AWARENESS_CONTEXT_\* pseudo-handles for [set_thread_dpi_awareness_context]
+#![allow(non_snake_case)]
+
+use crate::*;
+
+
+
+/// DPI_AWARENESS_CONTEXT_UNAWARE &mdash; always 96 DPI: bitmap stretched by the system
+pub const UNAWARE               : DpiAwarenessContext = DpiAwarenessContext::from_constant(-1);
+
+/// DPI_AWARENESS_CONTEXT_SYSTEM_AWARE &mdash; the primary monitor's DPI at login: bitmap stretched by the system on other monitors
+pub const SYSTEM_AWARE          : DpiAwarenessContext = DpiAwarenessContext::from_constant(-2);
+
+/// DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE &mdash; each monitor's DPI: top level windows are notified of changes via [WM::DPICHANGED]
+pub const PER_MONITOR_AWARE     : DpiAwarenessContext = DpiAwarenessContext::from_constant(-3);
+
+/// DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2 &mdash; like [PER_MONITOR_AWARE], but child windows are notified too, and non-client areas are scaled (Windows 10 1703+)
+pub const PER_MONITOR_AWARE_V2  : DpiAwarenessContext = DpiAwarenessContext::from_constant(-4);
+
+/// DPI_AWARENESS_CONTEXT_UNAWARE_GDISCALED &mdash; like [UNAWARE], but GDI content is rendered at a higher resolution (Windows 10 1809+)
+pub const UNAWARE_GDISCALED     : DpiAwarenessContext = DpiAwarenessContext::from_constant(-5);
@@ -0,0 +1,122 @@
+//! Single-instance applications: forward [WM::COPYDATA] to an already running instance
+//!
+//! ### Example
+//! ```rust,no_run
+//! # use hwnd::*;
+//! # use hwnd::single_instance::*;
+//! let args = std::env::args().collect::<Vec<_>>().join("\n");
+//! match claim_or_forward(
+//!     abistr::cstr16!("Local\\com.example.my-app.single-instance"),
+//!     abistr::cstr16!("com.example.my-app.window"), (),
+//!     0, args.as_bytes(), 5000,
+//! ).unwrap() {
+//!     Instance::Primary(_primary) => {
+//!         // register "com.example.my-app.window", create the window, and run a message loop.
+//!         // handle forwarded command lines in the wndproc via `CopyData::from_wm`.
+//!         // keep `_primary` alive until the app exits.
+//!     },
+//!     Instance::Forwarded { .. } => {
+//!         // command line forwarded to the primary instance, exit
+//!     },
+//! }
+//! ```
+
+use crate::*;
+use abistr::{AsCStr, AsOptCStr, TryIntoAsCStr, TryIntoAsOptCStr};
+use winapi::shared::winerror::ERROR_ALREADY_EXISTS;
+use winapi::um::errhandlingapi::GetLastError;
+use winapi::um::handleapi::CloseHandle;
+use winapi::um::synchapi::{CreateMutexW, Sleep};
+use winapi::um::winnt::HANDLE;
+use std::ptr::*;
+use std::time::{Duration, Instant};
+
+
+
+/// The result of [claim_or_forward].
+#[derive(Debug)]
+pub enum Instance {
+    /// This process is the primary instance.
+    Primary(Primary),
+
+    /// Another instance was already running, and `bytes` were forwarded to `target`.
+    Forwarded {
+        /// The primary instance's window.
+        target: HWnd,
+
+        /// The `LRESULT` returned by the primary instance's wndproc for [WM::COPYDATA].
+        result: LRESULT,
+    },
+}
+
+/// Proof that this process is the primary instance.
+/// Keep it alive for as long as the process should remain the primary instance.
+///
+/// Dropping it closes the underlying named mutex, allowing a new primary instance to start.
+#[derive(Debug)]
+pub struct Primary { mutex: HANDLE }
+
+impl Drop for Primary {
+    fn drop(&mut self) {
+        let _closed = unsafe { CloseHandle(self.mutex) };
+        debug_assert!(_closed != 0, "CloseHandle failed: {:?}", Error::new_gle());
+    }
+}
+
+/// Claim the named mutex `mutex_name`, or forward `bytes` to the already running instance's window via [send_copy_data].
+///
+/// The primary instance's window is found with <code>[find_window_ex_w]\(HWnd::NULL, HWnd::NULL, class_name, window_name\)</code>, or if that fails,
+/// <code>[find_window_ex_w]\([HWnd::MESSAGE], HWnd::NULL, class_name, window_name\)</code> (for message-only windows.)
+/// Since the primary instance might still be starting up, the search is retried until `timeout` milliseconds have elapsed,
+/// including while `class_name` isn't registered yet.
+/// Whatever remains of `timeout` is then used for [send_copy_data].
+///
+/// *   `mutex_name` should be unique to the application, e.g. `"Local\\com.example.my-app"` (per session) or `"Global\\com.example.my-app"` (per machine.)
+/// *   `class_name` / `window_name` should match the primary instance's window.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]      If `mutex_name` or `window_name` contain interior `\0`s
+/// *   [ERROR::ACCESS_DENIED]          If the mutex exists but was created by another user
+/// *   [ERROR::FILE_NOT_FOUND]         If the mutex exists, but the primary instance's window couldn't be found within `timeout` milliseconds
+/// *   [ERROR::TIMEOUT]                If the primary instance's window didn't handle [WM::COPYDATA] within `timeout` milliseconds
+/// *   Any error from [send_copy_data]
+pub fn claim_or_forward<'a>(
+    mutex_name:     impl TryIntoAsCStr<u16>,
+    class_name:     impl Into<NameAtomOrZero<'a, u16>>,
+    window_name:    impl TryIntoAsOptCStr<u16>,
+    data:           usize,
+    bytes:          &[u8],
+    timeout:        u32,
+) -> Result<Instance, Error> {
+    fn_context!(single_instance::claim_or_forward => CreateMutexW);
+    debug_poison_last_error();
+    let mutex_name  = mutex_name .try_into().map_err(|_| fn_param_error!(mutex_name,  ERROR::INVALID_PARAMETER))?;
+    let window_name = window_name.try_into().map_err(|_| fn_param_error!(window_name, ERROR::INVALID_PARAMETER))?;
+    let class_name  = class_name.into();
+
+    let mutex = unsafe { CreateMutexW(null_mut(), 0, mutex_name.as_cstr()) };
+    fn_succeeded!(!mutex.is_null())?;
+    let already_exists = unsafe { GetLastError() } == ERROR_ALREADY_EXISTS;
+    let primary = Primary { mutex };
+    if !already_exists { return Ok(Instance::Primary(primary)) }
+    drop(primary);
+
+    let window_name = unsafe { abistr::CStrPtr::<u16>::from_ptr_unbounded(window_name.as_opt_cstr()) }; // borrows `window_name`, which outlives the loop below
+    let deadline = Instant::now() + Duration::from_millis(timeout.into());
+    let find = |parent| match find_window_ex_w(parent, HWnd::NULL, class_name, window_name) {
+        Err(err) if err == ERROR::CANNOT_FIND_WND_CLASS => Ok(HWnd::NULL), // the primary instance hasn't registered its class yet
+        result => result,
+    };
+    let target = loop {
+        let top = find(HWnd::NULL)?;
+        if !top.is_null() { break top }
+        let msg = find(HWnd::MESSAGE)?;
+        if !msg.is_null() { break msg }
+        if Instant::now() >= deadline { return fn_err!(ERROR::FILE_NOT_FOUND) }
+        unsafe { Sleep(10) };
+    };
+
+    let timeout = u32::try_from(deadline.saturating_duration_since(Instant::now()).as_millis()).unwrap_or(timeout).max(1);
+    let result = send_copy_data(target, HWnd::NULL, data, bytes, timeout)?;
+    Ok(Instance::Forwarded { target, result })
+}
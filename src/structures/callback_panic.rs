@@ -0,0 +1,80 @@
+use crate::HWnd;
+use std::any::Any;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::panic::*;
+use std::sync::{Arc, RwLock};
+
+
+
+/// A panic caught by this crate, rather than being allowed to unwind through Win32 (e.g. out of a [hooks](crate::hooks) callback, a [Timer](crate::timer::Timer) callback, or an [assoc](crate::assoc) `Drop`.)
+///
+/// The panic has already been reported by the [std panic hook](std::panic::set_hook) by the time it's caught.
+/// By default, which callback panicked is then also written to stderr.
+/// Install a [set_callback_panic_hook] to handle it differently instead (to log it elsewhere, abort, etc.)
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// set_callback_panic_hook(|panic| {
+///     // e.g. "panic in Timer callback: oops"
+///     println!("{panic}");
+/// });
+/// ```
+pub struct CallbackPanic<'a> {
+    /// What was being done (e.g. `"dropping associated data"`, `"on_message callback"`, `"Timer callback"`)
+    pub context:    &'a str,
+
+    /// The window the callback was invoked for, or [HWnd::NULL]
+    pub hwnd:       HWnd,
+
+    /// The value passed to [panic!] (or [resume_unwind])
+    pub payload:    &'a (dyn Any + Send),
+}
+
+impl CallbackPanic<'_> {
+    /// The panic message, if the payload was a string (as with [panic!].)
+    pub fn message(&self) -> Option<&str> {
+        if let Some(s) = self.payload.downcast_ref::<&str>() { Some(s) } else { self.payload.downcast_ref::<String>().map(|s| s.as_str()) }
+    }
+}
+
+impl Debug for CallbackPanic<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        fmt.debug_struct("CallbackPanic").field("context", &self.context).field("hwnd", &self.hwnd).field("message", &self.message()).finish()
+    }
+}
+
+impl Display for CallbackPanic<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "panic in {}", self.context)?;
+        if !self.hwnd.is_null() { write!(fmt, " for {:?}", self.hwnd)?; }
+        write!(fmt, ": {}", self.message().unwrap_or("Box<dyn Any>"))
+    }
+}
+
+type Hook = Arc<dyn Fn(&CallbackPanic) + Send + Sync>;
+
+fn hook() -> &'static RwLock<Option<Hook>> {
+    lazy_static::lazy_static! { static ref HOOK : RwLock<Option<Hook>> = Default::default(); }
+    &HOOK
+}
+
+/// Replace the process-wide hook called whenever this crate catches a [CallbackPanic].
+///
+/// By default, caught panics are reported to stderr, e.g. `hwnd: panic in Timer callback for HWnd(0x1234): oops`.
+/// The hook is called on the thread that panicked, and panics from the hook itself are caught and ignored.
+pub fn set_callback_panic_hook(hook: impl Fn(&CallbackPanic) + Send + Sync + 'static) {
+    *self::hook().write().unwrap_or_else(|p| p.into_inner()) = Some(Arc::new(hook));
+}
+
+/// Report a panic caught by `catch_unwind` to the [set_callback_panic_hook] (or stderr), then drop it.  Never unwinds.
+pub(crate) fn report_callback_panic(context: &str, hwnd: HWnd, payload: Box<dyn Any + Send>) {
+    let hook = hook().read().ok().and_then(|h| h.clone()); // not held while calling user code
+    let panic = CallbackPanic { context, hwnd, payload: &*payload };
+    match hook {
+        Some(hook)  => { let _ = catch_unwind(AssertUnwindSafe(|| hook(&panic))); },
+        None        => { let _ = catch_unwind(AssertUnwindSafe(|| eprintln!("hwnd: {panic}"))); },
+    }
+    // `payload` itself could panic when dropped - don't let that escape either
+    let _ = catch_unwind(AssertUnwindSafe(move || drop(payload)));
+}
@@ -1,16 +1,54 @@
 use winapi::um::errhandlingapi::GetLastError;
+use winapi::um::winbase::*;
 use winresult::*;
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 
 
 
 /// An error generated by the `hwnd` crate
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Error(pub(crate) ErrorHResultOrCode);
+///
+/// With the opt-in `"error-context"` feature, errors also remember which function failed, and which parameter (if any) was rejected:
+/// ```
+/// # use hwnd::*;
+/// # use winresult::*;
+/// let error = get_window_text_length_w(!42usize as HWND).unwrap_err();
+/// assert_eq!(error, ERROR::INVALID_WINDOW_HANDLE);
+/// # if cfg!(feature = "error-context") {
+/// assert_eq!(error.function(), Some("get_window_text_length_w"));
+/// assert_eq!(error.win32(),    Some("GetWindowTextLengthW"));
+/// assert_eq!(error.to_string(), "get_window_text_length_w (GetWindowTextLengthW): ERROR::INVALID_WINDOW_HANDLE");
+/// # }
+/// ```
+///
+/// Comparisons and hashing only consider the error code, not the context.
+#[derive(Clone, Copy)]
+pub struct Error {
+    code:       ErrorHResultOrCode,
+    #[cfg(feature = "error-context")] context: Option<&'static ErrorContext>,
+}
+
+/// Where an [Error] came from: see `fn_context!` / `fn_param_error!`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct ErrorContext {
+    pub function:   &'static str,
+    pub win32:      Option<&'static str>,
+    pub param:      Option<&'static str>,
+}
+
+impl ErrorContext {
+    pub const fn with_param(&self, param: &'static str) -> Self { Self { param: Some(param), ..*self } }
+}
 
 impl Error {
+    pub(crate) const fn new(code: ErrorHResultOrCode) -> Self {
+        Self { code, #[cfg(feature = "error-context")] context: None }
+    }
+
     pub(crate) fn new_gle() -> Self {
-        Self(ErrorHResultOrCode::from(unsafe { GetLastError() }))
+        Self::new(ErrorHResultOrCode::from(unsafe { GetLastError() }))
     }
 
     pub(crate) fn new_gle_nz() -> Result<(), Self> {
@@ -19,12 +57,99 @@ impl Error {
         Err(e)
     }
 
-    pub const fn to_u32(&self) -> u32 { self.0.to_u32() }
-    pub       fn code(&self) -> Option<ErrorCode> { self.0.to_code().map(|c| ErrorCode::from(c)) }
+    #[allow(unused_variables)]
+    pub(crate) const fn with_context(self, context: &'static ErrorContext) -> Self {
+        #[cfg(feature = "error-context")] return Self { context: Some(context), ..self };
+        #[cfg(not(feature = "error-context"))] return self;
+    }
+
+    pub const fn to_u32(&self) -> u32 { self.code.to_u32() }
+    pub       fn code(&self) -> Option<ErrorCode> { self.code.to_code().map(|c| ErrorCode::from(c)) }
+
+    /// The `hwnd` function that failed (e.g. `"set_timer"`), if known.
+    pub fn function(&self) -> Option<&'static str> { self.context().map(|c| c.function) }
+
+    /// The underlying Win32 API (e.g. `"SetTimer"`), if known.
+    pub fn win32(&self) -> Option<&'static str> { self.context().and_then(|c| c.win32) }
+
+    /// The name of the parameter that was rejected (e.g. `"hwnd"`), if known.
+    pub fn param(&self) -> Option<&'static str> { self.context().and_then(|c| c.param) }
+
+    /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-formatmessagew)\]
+    /// FormatMessageW(FORMAT_MESSAGE_FROM_SYSTEM | FORMAT_MESSAGE_IGNORE_INSERTS, ...)
+    ///
+    /// A human readable description of the error code (e.g. `"Invalid window handle."`), in the user's language.
+    /// Falls back on a built-in (English) table of [common errors](crate::doc::Errors) if the system has no message,
+    /// and on the [Debug] form of the code (e.g. `"ERROR::BUSY"`) if neither does.
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// # use winresult::*;
+    /// let error = get_window_text_length_w(!42usize as HWND).unwrap_err();
+    /// println!("{}", error.message()); // e.g. "Invalid window handle."
+    /// assert!(!error.message().is_empty());
+    /// ```
+    pub fn message(&self) -> Cow<'static, str> {
+        if let Some(message) = self.system_message() { return message.into() }
+        if let Some(message) = fallback_message(self.to_u32()) { return message.into() }
+        format!("{:?}", self.code).into()
+    }
+
+    fn system_message(&self) -> Option<String> {
+        struct LocalAlloc(*mut u16);
+        impl Drop for LocalAlloc { fn drop(&mut self) { if !self.0.is_null() { unsafe { LocalFree(self.0.cast()) }; } } }
+
+        let mut buffer = LocalAlloc(std::ptr::null_mut());
+        let flags = FORMAT_MESSAGE_ALLOCATE_BUFFER | FORMAT_MESSAGE_FROM_SYSTEM | FORMAT_MESSAGE_IGNORE_INSERTS;
+        // SAFETY: with FORMAT_MESSAGE_ALLOCATE_BUFFER, lpBuffer is really a `*mut LPWSTR` that receives a LocalAlloc()ed buffer
+        let n = unsafe { FormatMessageW(flags, std::ptr::null(), self.to_u32(), 0, (&mut buffer.0 as *mut *mut u16).cast(), 0, std::ptr::null_mut()) };
+        if n == 0 || buffer.0.is_null() { return None }
+        // SAFETY: FormatMessageW wrote `n` units (excluding `\0`) to `buffer`
+        let units = unsafe { std::slice::from_raw_parts(buffer.0, n as usize) };
+        let message = String::from_utf16_lossy(units);
+        let message = message.trim_end();
+        (!message.is_empty()).then(|| message.into())
+    }
+
+    fn context(&self) -> Option<&'static ErrorContext> {
+        #[cfg(feature = "error-context")] return self.context;
+        #[cfg(not(feature = "error-context"))] return None;
+    }
+}
+
+impl Debug   for Error { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "hwnd::Error(")?; self.fmt_context(fmt)?; write!(fmt, ")") }}
+impl Display for Error {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        if self.context().is_none() { write!(fmt, "error handling HWNDs: ")?; }
+        self.fmt_context(fmt)
+    }
+}
+
+impl Error {
+    fn fmt_context(&self, fmt: &mut Formatter) -> fmt::Result {
+        if let Some(c) = self.context() {
+            write!(fmt, "{}", c.function)?;
+            if let Some(win32) = c.win32 { write!(fmt, " ({win32})")?; }
+            write!(fmt, ": ")?;
+            if let Some(param) = c.param { write!(fmt, "{param}: ")?; }
+        }
+        write!(fmt, "{:?}", self.code)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for std::io::Error {
+    /// [std::io::Error::from_raw_os_error]`(error.to_u32())`: the [Error::function] / [Error::param] context is lost.
+    fn from(error: Error) -> Self { std::io::Error::from_raw_os_error(error.to_u32() as i32) }
 }
 
-impl Debug   for Error { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "hwnd::Error({:?})",           self.0) }}
-impl Display for Error { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "error handling HWNDs: {:?}",  self.0) }}
+impl PartialEq          for Error { fn eq(&self, other: &Self) -> bool { self.to_u32() == other.to_u32() } }
+impl Eq                 for Error {}
+impl PartialOrd         for Error { fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) } }
+impl Ord                for Error { fn cmp(&self, other: &Self) -> Ordering { self.code.cmp(&other.code) } }
+impl Hash               for Error { fn hash<H: Hasher>(&self, state: &mut H) { self.code.hash(state) } }
 
 impl PartialEq<ErrorCode            > for Error { fn eq(&self, other: &ErrorCode            ) -> bool { self.to_u32() == other.to_u32() } }
 impl PartialEq<HResultError         > for Error { fn eq(&self, other: &HResultError         ) -> bool { self.to_u32() == other.to_u32() } }
@@ -33,3 +158,90 @@ impl PartialEq<ErrorHResultOrCode   > for Error { fn eq(&self, other: &ErrorHRes
 impl PartialEq<Error> for ErrorCode             { fn eq(&self, other: &Error) -> bool { self.to_u32() == other.to_u32() } }
 impl PartialEq<Error> for HResultError          { fn eq(&self, other: &Error) -> bool { self.to_u32() == other.to_u32() } }
 impl PartialEq<Error> for ErrorHResultOrCode    { fn eq(&self, other: &Error) -> bool { self.to_u32() == other.to_u32() } }
+
+
+
+/// English messages for [common errors](crate::doc::Errors), for when FormatMessageW fails
+fn fallback_message(code: u32) -> Option<&'static str> {
+    FALLBACK_MESSAGES.iter().find(|(c, _)| c.to_u32() == code).map(|&(_, message)| message)
+}
+
+const FALLBACK_MESSAGES : &[(ErrorCode, &str)] = &[
+    (ERROR::SUCCESS,                    "The operation completed successfully."),
+    (ERROR::ACCESS_DENIED,              "Access is denied."),
+    (ERROR::ALREADY_EXISTS,             "Cannot create a file when that file already exists."),
+    (ERROR::CALL_NOT_IMPLEMENTED,       "This function is not supported on this system."),
+    (ERROR::CANNOT_FIND_WND_CLASS,      "Cannot find window class."),
+    (ERROR::CLASS_ALREADY_EXISTS,       "Class already exists."),
+    (ERROR::CLASS_DOES_NOT_EXIST,       "Class does not exist."),
+    (ERROR::DATATYPE_MISMATCH,          "Data of this type is not supported."),
+    (ERROR::DC_NOT_FOUND,               "Invalid device context (DC) handle."),
+    (ERROR::FILE_NOT_FOUND,             "The system cannot find the file specified."),
+    (ERROR::HOOK_NOT_INSTALLED,         "The hook procedure is not installed."),
+    (ERROR::INVALID_CURSOR_HANDLE,      "Invalid cursor handle."),
+    (ERROR::INVALID_DATA,               "The data is invalid."),
+    (ERROR::INVALID_INDEX,              "Invalid index."),
+    (ERROR::INVALID_PARAMETER,          "The parameter is incorrect."),
+    (ERROR::INVALID_THREAD_ID,          "Invalid thread identifier."),
+    (ERROR::INVALID_WINDOW_HANDLE,      "Invalid window handle."),
+    (ERROR::MESSAGE_SYNC_ONLY,          "The message can be used only with synchronous operations."),
+    (ERROR::MOD_NOT_FOUND,              "The specified module could not be found."),
+    (ERROR::NOT_ENOUGH_MEMORY,          "Not enough memory resources are available to process this command."),
+    (ERROR::NOT_ENOUGH_QUOTA,           "Not enough quota is available to process this command."),
+    (ERROR::PROC_NOT_FOUND,             "The specified procedure could not be found."),
+    (ERROR::RESOURCE_DATA_NOT_FOUND,    "The specified image file did not contain a resource section."),
+    (ERROR::RESOURCE_LANG_NOT_FOUND,    "The specified resource language ID cannot be found in the image file."),
+    (ERROR::RESOURCE_NAME_NOT_FOUND,    "The specified resource name cannot be found in the image file."),
+    (ERROR::RESOURCE_TYPE_NOT_FOUND,    "The specified resource type cannot be found in the image file."),
+    (ERROR::TIMEOUT,                    "This operation returned because the timeout period expired."),
+    (ERROR::WINDOW_OF_OTHER_THREAD,     "Invalid window; it belongs to other thread."),
+];
+
+
+
+#[test] fn context() {
+    fn set_timer_like(hwnd: Option<HWND>) -> Result<(), Error> {
+        fn_context!(set_timer => SetTimer);
+        let _hwnd = hwnd.ok_or(fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?;
+        fn_err!(ERROR::ACCESS_DENIED)
+    }
+    use winapi::shared::windef::HWND;
+
+    let param = set_timer_like(None).unwrap_err();
+    let other = set_timer_like(Some(std::ptr::null_mut())).unwrap_err();
+    assert_eq!(param, ERROR::INVALID_WINDOW_HANDLE);
+    assert_eq!(other, ERROR::ACCESS_DENIED);
+    assert_eq!(param, Error::new(ERROR::INVALID_WINDOW_HANDLE.into()), "context doesn't affect equality");
+
+    if cfg!(feature = "error-context") {
+        assert_eq!(param.to_string(),   "set_timer (SetTimer): hwnd: ERROR::INVALID_WINDOW_HANDLE");
+        assert_eq!(format!("{param:?}"),"hwnd::Error(set_timer (SetTimer): hwnd: ERROR::INVALID_WINDOW_HANDLE)");
+        assert_eq!(other.to_string(),   "set_timer (SetTimer): ERROR::ACCESS_DENIED");
+        assert_eq!((param.function(), param.win32(), param.param()), (Some("set_timer"), Some("SetTimer"), Some("hwnd")));
+        assert_eq!(std::mem::size_of::<Error>(), 2 * std::mem::size_of::<usize>());
+    } else {
+        assert_eq!(param.to_string(),   "error handling HWNDs: ERROR::INVALID_WINDOW_HANDLE");
+        assert_eq!(std::mem::size_of::<Error>(), 4);
+    }
+    assert_eq!(Error::new(ERROR::ACCESS_DENIED.into()).to_string(), "error handling HWNDs: ERROR::ACCESS_DENIED");
+}
+
+#[test] fn messages() {
+    for row in include_str!("../../doc/errors.md").lines().filter(|l| l.starts_with("| ERROR::")) {
+        let name = row.split('|').nth(1).unwrap().trim();
+        assert!(FALLBACK_MESSAGES.iter().any(|(code, _)| format!("{code:?}") == name), "doc/errors.md lists {name}, but it has no fallback message");
+    }
+
+    assert_eq!(fallback_message(ERROR::INVALID_WINDOW_HANDLE.to_u32()), Some("Invalid window handle."));
+    assert_eq!(fallback_message(ERROR::BUSY.to_u32()), None);
+
+    let error = Error::new(ERROR::INVALID_WINDOW_HANDLE.into());
+    assert!(!error.message().is_empty());
+    assert!(!Error::new(ERROR::BUSY.into()).message().is_empty());
+
+    let io = std::io::Error::from(error);
+    assert_eq!(io.raw_os_error(), Some(1400));
+    let _ : &dyn std::error::Error = &error;
+    fn io_result() -> std::io::Result<()> { Err(Error::new(ERROR::ACCESS_DENIED.into()))? }
+    assert_eq!(io_result().unwrap_err().raw_os_error(), Some(5));
+}
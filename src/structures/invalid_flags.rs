@@ -0,0 +1,44 @@
+use std::fmt::{self, Display, Formatter};
+
+
+
+/// A flag value (e.g. [WindowStyle](crate::WS::WindowStyle), [SetWindowPosFlags](crate::SWP::SetWindowPosFlags)) contains a documented-invalid combination of flags.
+///
+/// Returned by e.g. [`WindowStyle::validate`](crate::WS::WindowStyle::validate).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub struct InvalidFlags {
+    pub(crate) ty:      &'static str,
+    pub(crate) flags:   &'static str,
+    pub(crate) reason:  &'static str,
+}
+
+impl InvalidFlags {
+    /// The invalid combination of flags, e.g. `"WS::CHILD | WS::POPUP"`
+    pub fn flags(&self) -> &'static str { self.flags }
+
+    /// Why the combination is invalid, e.g. `"WS_CHILD cannot be used with the WS_POPUP style"`
+    pub fn reason(&self) -> &'static str { self.reason }
+}
+
+impl Display for InvalidFlags {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "invalid {}: {}: {}", self.ty, self.flags, self.reason)
+    }
+}
+
+impl std::error::Error for InvalidFlags {}
+
+#[test] fn validate() {
+    use crate::*;
+
+    assert_eq!(Ok(()), (WS::CHILD | WS::VISIBLE | WS::TABSTOP).validate());
+    assert_eq!(Ok(()), (WS::POPUPWINDOW | WS::CAPTION).validate());
+    let err = (WS::CHILDWINDOW | WS::POPUPWINDOW).validate().unwrap_err();
+    assert_eq!(err.flags(), "WS::CHILD | WS::POPUP");
+    assert_eq!(err.to_string(), "invalid WindowStyle: WS::CHILD | WS::POPUP: WS_CHILD cannot be used with the WS_POPUP style");
+
+    assert_eq!(Ok(()), (SWP::NOSIZE | SWP::SHOWWINDOW).validate());
+    assert!((SWP::SHOWWINDOW | SWP::HIDEWINDOW).validate().is_err());
+    assert_eq!(Ok(()), (RDW::INVALIDATE | RDW::ERASE | RDW::UPDATENOW).validate());
+    assert!((RDW::ERASE | RDW::NOERASE).validate().is_err());
+    assert_eq!(Ok(()), WPF::WindowPlacementFlags::from(!0).validate());
+}
@@ -0,0 +1,42 @@
+use winapi::um::errhandlingapi::{GetLastError, SetLastError};
+#[cfg(doc)] use crate::*;
+
+
+
+/// Saves [GetLastError](https://learn.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-getlasterror) when created,
+/// and restores it with [SetLastError](https://learn.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-setlasterror) when dropped.
+///
+/// Useful in callbacks (wndprocs, timer procs, hooks, [Drop] impls run by [assoc](crate::assoc), ...) which shouldn't clobber the last error of whatever invoked them.
+/// For example, [send_message_w] relies on the last error set by the receiving wndproc to distinguish `0` from failure.
+/// This crate's own hook procedures use a [LastErrorGuard].
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use winapi::um::errhandlingapi::*;
+/// unsafe { SetLastError(ERROR::ACCESS_DENIED.to_u32()) };
+/// {
+///     let guard = LastErrorGuard::new();
+///     assert_eq!(guard.saved(), ERROR::ACCESS_DENIED.to_u32());
+///     let _ = get_window_text_length_w(!42usize as HWND); // clobbers the last error
+/// }
+/// assert_eq!(unsafe { GetLastError() }, ERROR::ACCESS_DENIED.to_u32());
+/// ```
+#[must_use = "the last error is restored when the guard is dropped"]
+#[derive(Debug)]
+pub struct LastErrorGuard { saved: u32 }
+
+impl LastErrorGuard {
+    /// Save the current thread's last error
+    pub fn new() -> Self { Self { saved: unsafe { GetLastError() } } }
+
+    /// The last error that will be restored
+    pub fn saved(&self) -> u32 { self.saved }
+}
+
+impl Default for LastErrorGuard { fn default() -> Self { Self::new() } }
+
+impl Drop for LastErrorGuard {
+    fn drop(&mut self) { unsafe { SetLastError(self.saved) } }
+}
@@ -0,0 +1,24 @@
+use std::fmt::{self, Display, Formatter};
+
+
+
+/// A flag or enum value (e.g. [WindowStyle](crate::WS::WindowStyle), [WM32](crate::WM32)) couldn't be [parsed](str::parse).
+///
+/// Values are parsed from their [`Debug`] representations (e.g. `"WS::OVERLAPPEDWINDOW | WS::VISIBLE"`, `"WM_USER+5"`),
+/// their C spellings (e.g. `"WS_VISIBLE"`, `"WM_CREATE"`), and integers (e.g. `"0x10000000"`, `"-16"`.)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)] pub struct ParseError {
+    pub(crate) ty:      &'static str,
+    pub(crate) term:    String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        if self.term.is_empty() {
+            write!(fmt, "unable to parse {}: expected a value", self.ty)
+        } else {
+            write!(fmt, "unable to parse {}: unrecognized `{}`", self.ty, self.term)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,234 @@
+//! Owned timers that call closures
+//!
+//! A safe alternative to <code>[set](set_timer)\[[_coalescable](set_coalescable_timer)\][_timer](set_timer)</code> + [kill_timer]:
+//! [Timer] allocates its own unique `id_event`, routes [WM::TIMER] to a closure, and kills the timer when dropped.
+//!
+//! ### Example
+//! ```
+//! # use hwnd::*;
+//! use hwnd::timer::Timer;
+//! use std::cell::Cell;
+//! use std::rc::Rc;
+//!
+//! let ticks = Rc::new(Cell::new(0));
+//! let timer = Timer::new(10, { let ticks = ticks.clone(); move || {
+//!     ticks.set(ticks.get() + 1);
+//!     if ticks.get() == 3 { post_quit_message(0) }
+//! }}).unwrap();
+//!
+//! // ...message loop...
+//! let mut msg = Msg::zeroed();
+//! while get_message_w(&mut msg, HWnd::NULL, 0, 0).unwrap() {
+//!     translate_message(&msg);
+//!     let _ = unsafe { dispatch_message_w(&msg) };
+//! }
+//!
+//! drop(timer); // kill_timer
+//! assert_eq!(ticks.get(), 3);
+//! ```
+
+use crate::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::panic::*;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+
+
+/// A timer that calls a closure every `elapse_ms` milliseconds, killed when dropped.
+///
+/// Timers are per-thread: the closure is only ever called on the thread that created the [Timer],
+/// from within [dispatch_message_w] (or similar) while said thread pumps messages.
+/// Closures that panic are contained: the panic is reported (see [CallbackPanic]), and the timer keeps running.
+/// Closures aren't reentered: if a closure pumps messages itself, its own [WM::TIMER]s are skipped until it returns.
+///
+/// ### Interval
+/// `elapse_ms` is clamped to <code>[USER_TIMER::MINIMUM] ..= [USER_TIMER::MAXIMUM]</code>, as Windows would.
+/// Timers are low priority: [WM::TIMER] is only generated when the message queue is otherwise empty, and never queued more than once.
+/// Windows may additionally throttle timers of hidden, minimized, or cloaked windows, and of background processes (power throttling.)
+/// A closure should measure elapsed time rather than count ticks, if it matters.
+#[must_use = "the timer is killed when the Timer is dropped"]
+pub struct Timer {
+    hwnd:       HWnd,
+    id_event:   usize,
+    elapse_ms:  u32,
+    pd:         PhantomData<*const ()>, // !Send, !Sync: closures are per-thread
+}
+
+impl Timer {
+    /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-settimer)\]
+    /// SetTimer(nullptr, 0, elapse_ms, ...)
+    ///
+    /// Call `f` every `elapse_ms` milliseconds (with [TIMERV::DEFAULT_COALESCING].)
+    ///
+    /// ### Errors
+    /// *   [ERROR::NOT_ENOUGH_QUOTA]   possibly, if the thread or process has too many timers
+    ///
+    /// ### Example
+    /// See [the module documentation](self).
+    pub fn new(elapse_ms: u32, f: impl FnMut() + 'static) -> Result<Self, Error> {
+        fn_context!(timer::Timer::new => SetCoalescableTimer);
+        Self::create(FN_CONTEXT, HWnd::NULL, elapse_ms, TIMERV::DEFAULT_COALESCING, Box::new(f))
+    }
+
+    /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcoalescabletimer)\]
+    /// SetCoalescableTimer(nullptr, 0, elapse_ms, ..., tolerance_delay_ms)
+    ///
+    /// Call `f` every `elapse_ms` milliseconds, allowing Windows to delay it by up to `tolerance_delay_ms` to coalesce it with other timers.
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_PARAMETER]  &mdash; `tolerance_delay_ms` isn't [TIMERV::DEFAULT_COALESCING], [TIMERV::NO_COALESCING], or `1 ..= 0x7FFFFFF5`
+    /// *   [ERROR::NOT_ENOUGH_QUOTA]   possibly, if the thread or process has too many timers
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// # use winresult::ERROR;
+    /// use hwnd::timer::Timer;
+    ///
+    /// let timer = Timer::coalescable(1000, 100, || println!("tick")).unwrap();
+    /// assert_eq!(ERROR::INVALID_PARAMETER, Timer::coalescable(1000, 0xFFFFFFFE, || {}).unwrap_err());
+    /// ```
+    pub fn coalescable(elapse_ms: u32, tolerance_delay_ms: u32, f: impl FnMut() + 'static) -> Result<Self, Error> {
+        fn_context!(timer::Timer::coalescable => SetCoalescableTimer);
+        Self::create(FN_CONTEXT, HWnd::NULL, elapse_ms, tolerance_delay_ms, Box::new(f))
+    }
+
+    /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcoalescabletimer)\]
+    /// SetCoalescableTimer(hwnd, ..., elapse_ms, ..., tolerance_delay_ms)
+    ///
+    /// Call `f` every `elapse_ms` milliseconds, with a timer associated with `hwnd`.
+    /// The timer is killed early if `hwnd` is destroyed, and may be throttled by Windows while `hwnd` is hidden or minimized.
+    /// `hwnd`'s [WndProc] never sees the [WM::TIMER]s of this timer.
+    ///
+    /// ### Errors
+    /// *   [ERROR::INVALID_WINDOW_HANDLE]  &mdash; `hwnd` is null or invalid
+    /// *   [ERROR::ACCESS_DENIED]          &mdash; `hwnd` belongs to another thread (whose messages loops wouldn't call `f`)
+    /// *   [ERROR::INVALID_PARAMETER]      &mdash; `tolerance_delay_ms` isn't [TIMERV::DEFAULT_COALESCING], [TIMERV::NO_COALESCING], or `1 ..= 0x7FFFFFF5`
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// # use winresult::ERROR;
+    /// # use std::ptr::null_mut;
+    /// use hwnd::timer::Timer;
+    ///
+    /// let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+    /// let timer = Timer::with_window(hwnd, 100, TIMERV::DEFAULT_COALESCING, || println!("tick")).unwrap();
+    /// assert_eq!(timer.hwnd(), hwnd);
+    ///
+    /// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, Timer::with_window(HWnd::NULL,              100, 0, || {}).unwrap_err());
+    /// assert_eq!(ERROR::ACCESS_DENIED,         Timer::with_window(get_desktop_window(),    100, 0, || {}).unwrap_err());
+    /// ```
+    pub fn with_window(hwnd: impl TryInto<HWnd>, elapse_ms: u32, tolerance_delay_ms: u32, f: impl FnMut() + 'static) -> Result<Self, Error> {
+        fn_context!(timer::Timer::with_window => SetCoalescableTimer);
+        let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?;
+        if hwnd.is_null() { return Err(fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE)) }
+        let thread = get_window_thread_id(hwnd).map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?;
+        if thread != get_current_thread_id() { return Err(fn_param_error!(hwnd, ERROR::ACCESS_DENIED)) }
+        Self::create(FN_CONTEXT, hwnd, elapse_ms, tolerance_delay_ms, Box::new(f))
+    }
+
+    /// The window this timer is associated with, or [HWnd::NULL] for thread timers.
+    pub fn hwnd(&self) -> HWnd { self.hwnd }
+
+    /// The `id_event` this timer was allocated, as passed to [kill_timer] when dropped.
+    pub fn id_event(&self) -> usize { self.id_event }
+
+    /// The requested interval, after clamping to <code>[USER_TIMER::MINIMUM] ..= [USER_TIMER::MAXIMUM]</code>.
+    pub fn elapse_ms(&self) -> u32 { self.elapse_ms }
+
+    fn create(context: &'static ErrorContext, hwnd: HWnd, elapse_ms: u32, tolerance_delay_ms: u32, f: Box<TimerFn>) -> Result<Self, Error> {
+        let elapse_ms   = elapse_ms.clamp(USER_TIMER::MINIMUM, USER_TIMER::MAXIMUM);
+        let f           = Rc::new(RefCell::new(f));
+        // thread timers get an id_event allocated by SetTimer.  Window timers get one from a counter, which (unlike e.g. the address of `f`)
+        // is never reused by a later timer, so [WM::TIMER]s already queued for a killed timer can't reach a new closure.
+        let id_event    = if hwnd.is_null() { 0 } else { NEXT_WINDOW_ID_EVENT.fetch_add(1, Relaxed) };
+        // SAFETY: `id_event` is unique (see above), and only ever passed to our own `timer_proc`
+        let tid         = unsafe { set_coalescable_timer(hwnd, id_event, elapse_ms, Some(timer_proc), tolerance_delay_ms) }.map_err(|e| e.with_context(context))?;
+        let id_event    = if hwnd.is_null() { tid } else { id_event };
+        TIMERS.with(|t| t.borrow_mut().insert((hwnd, id_event), f)); // never held while calling user code
+        Ok(Self { hwnd, id_event, elapse_ms, pd: PhantomData })
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        let _ = kill_timer(self.hwnd, self.id_event); // fails if `hwnd` was already destroyed (which killed the timer)
+        let removed = TIMERS.try_with(|t| t.try_borrow_mut().ok()?.remove(&(self.hwnd, self.id_event))).ok().flatten();
+        drop(removed); // dropped without holding any borrows
+    }
+}
+
+impl std::fmt::Debug for Timer {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(fmt, "Timer({:?} #{}, every {}ms)", self.hwnd, self.id_event, self.elapse_ms)
+    }
+}
+
+
+
+type TimerFn   = dyn FnMut();
+type Timers    = HashMap<(HWnd, usize), Rc<RefCell<Box<TimerFn>>>>;
+
+thread_local! { static TIMERS : RefCell<Timers> = Default::default(); }
+
+/// Starts high to stay clear of the small `id_event`s apps typically pass to [set_timer] for their own window timers.
+static NEXT_WINDOW_ID_EVENT : AtomicUsize = AtomicUsize::new(0x8000_0000);
+
+// Never unwinds: panics are caught and reported.
+extern "system" fn timer_proc(hwnd: HWnd, _msg: WM32, id_event: usize, _tick_count_ms: u32) {
+    let _gle = LastErrorGuard::new(); // don't clobber the last error of whoever is dispatching messages
+    let f = TIMERS.try_with(|t| t.try_borrow().ok()?.get(&(hwnd, id_event)).cloned()).ok().flatten();
+    let Some(f) = f else { return }; // killed, but WM::TIMER was already enqueued
+    let Ok(mut f) = f.try_borrow_mut() else { return }; // reentrant
+    if let Err(panic) = catch_unwind(AssertUnwindSafe(&mut **f)) {
+        report_callback_panic("Timer callback", hwnd, panic);
+    }
+}
+
+
+
+#[test] fn timer() {
+    use std::cell::Cell;
+    use std::ptr::null_mut;
+
+    let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+
+    thread_local! { static REPORTED : Cell<usize> = Default::default(); } // set_callback_panic_hook is process-wide: only count this thread's panics
+    set_callback_panic_hook(|panic| if panic.context == "Timer callback" && panic.message() == Some("contained") { REPORTED.with(|r| r.set(r.get() + 1)) });
+
+    let ticks   = Rc::new(Cell::new(0));
+    let calls   = Rc::new(Cell::new(0));
+    let panics  = Timer::new(0, { let calls = calls.clone(); move || { calls.set(calls.get() + 1); panic!("contained") }}).unwrap(); // clamped to USER_TIMER::MINIMUM
+    assert_eq!(panics.elapse_ms(), USER_TIMER::MINIMUM);
+
+    let this = Rc::new(Cell::new(None::<Timer>));
+    this.set(Some(Timer::with_window(hwnd, 10, TIMERV::DEFAULT_COALESCING, { let ticks = ticks.clone(); let calls = calls.clone(); let this = this.clone(); move || {
+        ticks.set(ticks.get() + 1);
+        if ticks.get() >= 3 && calls.get() >= 2 {
+            drop(this.take()); // kill self from within the callback
+            post_quit_message(0);
+        }
+    }}).unwrap()));
+    assert_ne!(panics.id_event(), 0);
+
+    let mut msg = Msg::zeroed();
+    while get_message_w(&mut msg, HWnd::NULL, 0, 0).unwrap() {
+        translate_message(&msg);
+        let _ = unsafe { dispatch_message_w(&msg) };
+    }
+    assert!(ticks.get() >= 3);
+    assert!(calls.get() >= 2, "`panics` should keep running after panicking");
+    assert_eq!(REPORTED.with(|r| r.get()), calls.get(), "every panic should have been caught and reported");
+    assert_eq!(TIMERS.with(|t| t.borrow().len()), 1, "only `panics` should remain");
+
+    drop(panics);
+    assert!(TIMERS.with(|t| t.borrow().is_empty()));
+
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE, Timer::with_window(!42usize as HWND,     10, 0, || {}).unwrap_err());
+    assert_eq!(ERROR::ACCESS_DENIED,         Timer::with_window(get_desktop_window(), 10, 0, || {}).unwrap_err());
+    unsafe { destroy_window(hwnd) }.unwrap();
+}
@@ -0,0 +1,305 @@
+//! Spy++-style message tracing: record nested send/post/dispatch trees for the current thread's windows.
+//!
+//! A [Tracer] observes, via [hooks]:
+//! *   `WH_CBT` events (e.g. `HCBT_CREATEWND`, `HCBT_DESTROYWND`) as leaf [Entry]s.
+//! *   Sent messages (`WH_CALLWNDPROC` / `WH_CALLWNDPROCRET`), nesting anything that happens before the message returns.
+//! *   Posted messages, when removed from the queue by [get_message_w] / [peek_message_w] (`WH_GETMESSAGE`).
+//!     These nest anything that happens until the next posted message is retrieved (typically: until [dispatch_message_w] returns.)
+//!
+//! A [Trace] can be formatted as an indented text tree ([Trace::to_text], the format used by [Window Lifecycle Events](crate::doc::Window_Lifecycle_Events)),
+//! or as JSON lines ([Trace::to_json_lines]) for diffing traces across Windows versions.
+//!
+//! ### Example
+//! ```
+//! # use hwnd::*;
+//! # use std::ptr::*;
+//! use hwnd::trace::*;
+//!
+//! let tracer = Tracer::start().unwrap();
+//! let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+//! let trace = tracer.stop();
+//!
+//! let text = trace.to_text();
+//! assert!(text.starts_with("WH_CBT: HCBT_CREATEWND\n"), "{text}");
+//! assert!(text.contains("\nWM_NCCREATE\n"), "{text}");
+//! assert!(text.contains("\nWM_CREATE\n"), "{text}");
+//! # println!("{}", trace.to_text_detailed());
+//! # println!("{}", trace.to_json_lines());
+//! ```
+
+use crate::*;
+use crate::hooks::*;
+use ISMEX::InSendMessageExFlags;
+
+use winapi::um::winuser::*;
+
+use std::cell::*;
+use std::fmt::Write;
+use std::rc::Rc;
+
+
+
+/// Records a [Trace] of the current thread's window events until [stopped](Self::stop) (or dropped.)
+pub struct Tracer {
+    state:  Rc<RefCell<State>>,
+    _hooks: (Subscription, Subscription, Hook, Hook),
+}
+
+impl Tracer {
+    /// Start tracing the current thread.
+    ///
+    /// ### Errors
+    /// *   If `WH_CBT` or `WH_GETMESSAGE` hooks couldn't be installed (unexpected)
+    pub fn start() -> Result<Self, Error> {
+        let state = Rc::new(RefCell::new(State::default()));
+        let _hooks = (
+            on_message      ({ let state = state.clone(); move |m| {
+                let entry = Entry::new(m.hwnd, EntryKind::Sent { msg: m.msg, wparam: m.wparam, lparam: m.lparam, ismex: in_send_message_ex(None), result: None });
+                state.borrow_mut().open(entry);
+            }}),
+            on_message_ret  ({ let state = state.clone(); move |m, lr| state.borrow_mut().close_sent(m, lr) }),
+            Hook::cbt       ({ let state = state.clone(); move |e| {
+                let (hwnd, code) = match e {
+                    WhCbt::Activate     { hwnd, .. }    => (hwnd, "HCBT_ACTIVATE"),
+                    WhCbt::ClickSkipped { hwnd, .. }    => (hwnd, "HCBT_CLICKSKIPPED"),
+                    WhCbt::CreateWnd    { hwnd, .. }    => (hwnd, "HCBT_CREATEWND"),
+                    WhCbt::DestroyWnd   { hwnd }        => (hwnd, "HCBT_DESTROYWND"),
+                    WhCbt::KeySkipped   { .. }          => (HWnd::NULL, "HCBT_KEYSKIPPED"),
+                    WhCbt::MinMax       { hwnd, .. }    => (hwnd, "HCBT_MINMAX"),
+                    WhCbt::MoveSize     { hwnd, .. }    => (hwnd, "HCBT_MOVESIZE"),
+                    WhCbt::Qs                           => (HWnd::NULL, "HCBT_QS"),
+                    WhCbt::SetFocus     { hwnd, .. }    => (hwnd, "HCBT_SETFOCUS"),
+                    WhCbt::SysCommand   { .. }          => (HWnd::NULL, "HCBT_SYSCOMMAND"),
+                    _                                   => (HWnd::NULL, "HCBT_???"),
+                };
+                let entry = Entry::new(hwnd, EntryKind::Cbt { code });
+                state.borrow_mut().leaf(entry);
+                HookAction::CallNext
+            }})?,
+            Hook::get_message({ let state = state.clone(); move |e| {
+                if !e.removed { return } // peeked, not dispatched
+                let m = &*e.msg;
+                let entry = Entry::new(m.hwnd, EntryKind::Posted { msg: m.message, wparam: m.wparam, lparam: m.lparam });
+                state.borrow_mut().open_posted(entry);
+            }})?,
+        );
+        Ok(Self { state, _hooks })
+    }
+
+    /// Stop tracing, and return everything recorded.
+    pub fn stop(self) -> Trace {
+        let Self { state, _hooks } = self;
+        drop(_hooks);
+        let mut state = state.borrow_mut();
+        while !state.open.is_empty() { state.close() }
+        Trace { roots: std::mem::take(&mut state.roots) }
+    }
+}
+
+
+
+/// A recorded tree of window events.
+#[derive(Clone, Debug, Default)]
+pub struct Trace {
+    /// Top level entries, in the order they occured.
+    pub roots: Vec<Entry>,
+}
+
+/// A single recorded event, and everything that occured while it was being processed.
+#[derive(Clone, Debug)]
+#[non_exhaustive] pub struct Entry {
+    pub kind:       EntryKind,
+    pub hwnd:       HWnd,
+    /// The window class name of `hwnd`, at the time of the event
+    pub class:      String,
+    /// The window text of `hwnd`, at the time of the event (read without sending [WM::GETTEXT])
+    pub text:       String,
+    pub children:   Vec<Entry>,
+}
+
+/// What kind of event an [Entry] records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum EntryKind {
+    /// A `WH_CBT` event such as `"HCBT_CREATEWND"`
+    Cbt     { code: &'static str },
+    /// A sent message, `result` is [None] if the trace was stopped before it returned.
+    Sent    { msg: WM32, wparam: WPARAM, lparam: LPARAM, ismex: InSendMessageExFlags, result: Option<LRESULT> },
+    /// A posted message, removed from the queue by [get_message_w] or [peek_message_w].
+    Posted  { msg: WM32, wparam: WPARAM, lparam: LPARAM },
+}
+
+impl Entry {
+    fn new(hwnd: HWnd, kind: EntryKind) -> Self {
+        Self { kind, hwnd, class: class_name(hwnd), text: window_text(hwnd), children: Vec::new() }
+    }
+
+    /// The event name as used by [Trace::to_text], e.g. `"WM_CREATE"` or `"WH_CBT: HCBT_CREATEWND"`
+    pub fn name(&self) -> String {
+        match self.kind {
+            EntryKind::Cbt { code }         => format!("WH_CBT: {code}"),
+            EntryKind::Sent { msg, .. }     => format!("{msg:?}").replace("WM::", "WM_"),
+            EntryKind::Posted { msg, .. }   => format!("{msg:?}").replace("WM::", "WM_"),
+        }
+    }
+}
+
+impl Trace {
+    /// Format as an indented tree of event names, one per line:
+    ///
+    /// ```text
+    /// WH_CBT: HCBT_CREATEWND
+    /// WM_GETMINMAXINFO
+    /// WM_NCCREATE
+    /// WM_NCCALCSIZE
+    /// WM_CREATE
+    /// ```
+    pub fn to_text(&self) -> String {
+        let mut o = String::new();
+        self.visit(&mut |depth, e| { let _ = writeln!(o, "{:indent$}{}", "", e.name(), indent = 4 * depth); });
+        o
+    }
+
+    /// Like [to_text](Self::to_text), but with the hwnd, class, text, parameters, [ISMEX] flags, and result of each entry.
+    pub fn to_text_detailed(&self) -> String {
+        let mut o = String::new();
+        self.visit(&mut |depth, e| {
+            let _ = write!(o, "{:indent$}{:<32} {:?} {:?} {:?}", "", e.name(), e.hwnd, e.class, e.text, indent = 4 * depth);
+            let _ = match e.kind {
+                EntryKind::Cbt { .. }                                   => writeln!(o),
+                EntryKind::Sent { wparam, lparam, ismex, result, .. }   => writeln!(o, " wparam={wparam:#X} lparam={lparam:#X} {ismex:?} => {}", result.map_or(String::from("(unreturned)"), |r| r.to_string())),
+                EntryKind::Posted { wparam, lparam, .. }                => writeln!(o, " wparam={wparam:#X} lparam={lparam:#X} (posted)"),
+            };
+        });
+        o
+    }
+
+    /// Format as JSON lines, one object per entry, in the same order as [to_text](Self::to_text):
+    ///
+    /// ```text
+    /// {"depth":0,"kind":"cbt","name":"WH_CBT: HCBT_CREATEWND","hwnd":"0x1234","class":"Message","text":""}
+    /// {"depth":0,"kind":"sent","name":"WM_GETMINMAXINFO","hwnd":"0x1234","class":"Message","text":"","msg":36,"wparam":0,"lparam":123456,"ismex":0,"result":0}
+    /// ```
+    pub fn to_json_lines(&self) -> String {
+        let mut o = String::new();
+        self.visit(&mut |depth, e| {
+            let kind = match e.kind { EntryKind::Cbt { .. } => "cbt", EntryKind::Sent { .. } => "sent", EntryKind::Posted { .. } => "posted" };
+            let _ = write!(o, "{{\"depth\":{depth},\"kind\":\"{kind}\",\"name\":{},\"hwnd\":\"{:#X}\",\"class\":{},\"text\":{}", json_str(&e.name()), HWND::from(e.hwnd) as usize, json_str(&e.class), json_str(&e.text));
+            let _ = match e.kind {
+                EntryKind::Cbt { .. }                                       => Ok(()),
+                EntryKind::Sent { msg, wparam, lparam, ismex, result }      => write!(o, ",\"msg\":{},\"wparam\":{wparam},\"lparam\":{lparam},\"ismex\":{},\"result\":{}", u32::from(msg), u32::from(ismex), result.map_or(String::from("null"), |r| r.to_string())),
+                EntryKind::Posted { msg, wparam, lparam }                   => write!(o, ",\"msg\":{},\"wparam\":{wparam},\"lparam\":{lparam}", u32::from(msg)),
+            };
+            o.push_str("}\n");
+        });
+        o
+    }
+
+    fn visit(&self, f: &mut impl FnMut(usize, &Entry)) {
+        fn visit_entries(depth: usize, entries: &[Entry], f: &mut impl FnMut(usize, &Entry)) {
+            for e in entries {
+                f(depth, e);
+                visit_entries(depth + 1, &e.children, f);
+            }
+        }
+        visit_entries(0, &self.roots, f)
+    }
+}
+
+
+
+#[derive(Default)] struct State {
+    roots:  Vec<Entry>,
+    open:   Vec<Entry>,
+}
+
+impl State {
+    fn leaf(&mut self, entry: Entry) {
+        match self.open.last_mut() {
+            Some(parent)    => parent.children.push(entry),
+            None            => self.roots.push(entry),
+        }
+    }
+
+    fn open(&mut self, entry: Entry) { self.open.push(entry) }
+
+    fn close(&mut self) {
+        if let Some(entry) = self.open.pop() { self.leaf(entry) }
+    }
+
+    /// A posted message was retrieved: it replaces any posted message still open at the same level (presumably already dispatched.)
+    fn open_posted(&mut self, entry: Entry) {
+        while matches!(self.open.last(), Some(Entry { kind: EntryKind::Posted { .. }, .. })) { self.close() }
+        self.open(entry);
+    }
+
+    fn close_sent(&mut self, m: &SentMessage, lr: LRESULT) {
+        let Some(idx) = self.open.iter().rposition(|e| matches!(e.kind, EntryKind::Sent { msg, result: None, .. } if msg == m.msg && e.hwnd == m.hwnd)) else { return }; // sent before tracing started
+        while self.open.len() > idx + 1 { self.close() } // e.g. posted messages dispatched by a modal loop
+        if let Some(Entry { kind: EntryKind::Sent { result, .. }, .. }) = self.open.last_mut() { *result = Some(lr) }
+        self.close();
+    }
+}
+
+/// GetClassNameW - doesn't send any messages
+fn class_name(hwnd: HWnd) -> String {
+    if hwnd.is_null() { return String::new() }
+    let mut buf = [0u16; 256];
+    let n = unsafe { GetClassNameW(hwnd.into(), buf.as_mut_ptr(), buf.len() as _) };
+    String::from_utf16_lossy(&buf[..n.max(0) as usize])
+}
+
+/// InternalGetWindowText - unlike GetWindowTextW, doesn't send [WM::GETTEXT] (which would recurse into the tracer, and can hang on hung windows)
+fn window_text(hwnd: HWnd) -> String {
+    if hwnd.is_null() { return String::new() }
+    let mut buf = [0u16; 256];
+    let n = unsafe { InternalGetWindowText(hwnd.into(), buf.as_mut_ptr(), buf.len() as _) };
+    String::from_utf16_lossy(&buf[..n.max(0) as usize])
+}
+
+fn json_str(s: &str) -> String {
+    let mut o = String::with_capacity(s.len() + 2);
+    o.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"'                 => o.push_str("\\\""),
+            '\\'                => o.push_str("\\\\"),
+            '\n'                => o.push_str("\\n"),
+            '\r'                => o.push_str("\\r"),
+            '\t'                => o.push_str("\\t"),
+            c if c < ' '        => { let _ = write!(o, "\\u{:04x}", c as u32); },
+            c                   => o.push(c),
+        }
+    }
+    o.push('"');
+    o
+}
+
+
+
+#[test] fn json_escaping() {
+    assert_eq!(json_str(""),                r#""""#);
+    assert_eq!(json_str("Message"),         r#""Message""#);
+    assert_eq!(json_str("a \"b\" \\ c"),    r#""a \"b\" \\ c""#);
+    assert_eq!(json_str("\n\t\u{1}"),       r#""\n\t\u0001""#);
+}
+
+#[test] fn nesting() {
+    let e = |msg: WM32| Entry { kind: EntryKind::Sent { msg, wparam: 0, lparam: 0, ismex: ISMEX::NOSEND, result: None }, hwnd: HWnd::NULL, class: String::new(), text: String::new(), children: Vec::new() };
+    let m = |msg: WM32| SentMessage { hwnd: HWnd::NULL, msg, wparam: 0, lparam: 0 };
+
+    let mut s = State::default();
+    s.leaf(Entry { kind: EntryKind::Cbt { code: "HCBT_DESTROYWND" }, ..e(WM::NULL) });
+    s.open(e(WM::CLOSE));
+    s.open(e(WM::DESTROY));
+    s.close_sent(&m(WM::DESTROY), 0);
+    s.open(e(WM::NCDESTROY));
+    s.close_sent(&m(WM::CLOSE), 1); // implicitly closes (unreturned) WM_NCDESTROY
+    s.close_sent(&m(WM::PAINT), 2); // never opened: ignored
+    let trace = Trace { roots: s.roots };
+
+    assert_eq!(trace.to_text(), "WH_CBT: HCBT_DESTROYWND\nWM_CLOSE\n    WM_DESTROY\n    WM_NCDESTROY\n");
+    let json = trace.to_json_lines();
+    assert_eq!(json.lines().count(), 4);
+    assert!(json.lines().nth(1).unwrap().ends_with(r#""result":1}"#), "{json}");
+    assert!(json.lines().nth(3).unwrap().ends_with(r#""result":null}"#), "{json}");
+}
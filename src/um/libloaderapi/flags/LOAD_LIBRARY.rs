@@ -0,0 +1,97 @@
+// WARNING: this file is auto-generated by xtask gen and may be overwritten: edit crates/xtask/src/bin/data/load_library.rs instead
+//! \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryexw)\]
+//! LOAD_LIBRARY_\* flags for [load_library_ex_w]
+
+#![allow(non_snake_case)]
+
+use crate::*;
+use bytemuck::*;
+use winapi::um::libloaderapi::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryexw)\]
+/// LOAD_LIBRARY_\* flags for [load_library_ex_w]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Zeroable)] #[repr(transparent)] pub struct LoadLibraryFlags(u32);
+impl_ops_for_flag!(LoadLibraryFlags);
+
+impl From<LoadLibraryFlags> for u32 { fn from(cmd: LoadLibraryFlags) -> Self { cmd.0 } }
+impl From<u32> for LoadLibraryFlags { fn from(cmd: u32             ) -> Self { Self(cmd) } }
+
+impl_debug_for_flags! {
+    LoadLibraryFlags => {
+        LOAD_LIBRARY::AS_DATAFILE,
+        LOAD_LIBRARY::AS_IMAGE_RESOURCE,
+        LOAD_LIBRARY::AS_DATAFILE_EXCLUSIVE,
+        LOAD_LIBRARY::REQUIRE_SIGNED_TARGET,
+        LOAD_LIBRARY::SEARCH_DEFAULT_DIRS,
+        LOAD_LIBRARY::SEARCH_DLL_LOAD_DIR,
+        LOAD_LIBRARY::SEARCH_APPLICATION_DIR,
+        LOAD_LIBRARY::SEARCH_USER_DIRS,
+        LOAD_LIBRARY::SEARCH_SYSTEM32,
+        LOAD_LIBRARY::SAFE_CURRENT_DIRS,
+        LOAD_LIBRARY::SEARCH_SYSTEM32_NO_FORWARDER,
+        LOAD_LIBRARY::OS_INTEGRITY_CONTINUITY,
+    }
+}
+
+impl_from_str_for_flags! {
+    LoadLibraryFlags => {
+        LOAD_LIBRARY::AS_DATAFILE,
+        LOAD_LIBRARY::AS_IMAGE_RESOURCE,
+        LOAD_LIBRARY::AS_DATAFILE_EXCLUSIVE,
+        LOAD_LIBRARY::REQUIRE_SIGNED_TARGET,
+        LOAD_LIBRARY::SEARCH_DEFAULT_DIRS,
+        LOAD_LIBRARY::SEARCH_DLL_LOAD_DIR,
+        LOAD_LIBRARY::SEARCH_APPLICATION_DIR,
+        LOAD_LIBRARY::SEARCH_USER_DIRS,
+        LOAD_LIBRARY::SEARCH_SYSTEM32,
+        LOAD_LIBRARY::SAFE_CURRENT_DIRS,
+        LOAD_LIBRARY::SEARCH_SYSTEM32_NO_FORWARDER,
+        LOAD_LIBRARY::OS_INTEGRITY_CONTINUITY,
+    }
+}
+
+impl_validate_for_flags! {
+    LoadLibraryFlags => {
+    }
+}
+
+
+
+/// Map the module as a data file: resources are accessible, but code can't be executed (nor [get_proc_address](crate::get_proc_address)ed.)
+pub const AS_DATAFILE                  : LoadLibraryFlags = LoadLibraryFlags(LOAD_LIBRARY_AS_DATAFILE);
+
+/// Map the module as an image file: resources are accessible (with relative virtual addresses fixed up), but code can't be executed.
+pub const AS_IMAGE_RESOURCE            : LoadLibraryFlags = LoadLibraryFlags(LOAD_LIBRARY_AS_IMAGE_RESOURCE);
+
+/// Like [AS_DATAFILE], but opens the file with exclusive write access.
+pub const AS_DATAFILE_EXCLUSIVE        : LoadLibraryFlags = LoadLibraryFlags(LOAD_LIBRARY_AS_DATAFILE_EXCLUSIVE);
+
+pub const REQUIRE_SIGNED_TARGET        : LoadLibraryFlags = LoadLibraryFlags(LOAD_LIBRARY_REQUIRE_SIGNED_TARGET);
+
+/// Equivalent to `SEARCH_APPLICATION_DIR | SEARCH_SYSTEM32 | SEARCH_USER_DIRS`
+pub const SEARCH_DEFAULT_DIRS          : LoadLibraryFlags = LoadLibraryFlags(LOAD_LIBRARY_SEARCH_DEFAULT_DIRS);
+
+pub const SEARCH_DLL_LOAD_DIR          : LoadLibraryFlags = LoadLibraryFlags(LOAD_LIBRARY_SEARCH_DLL_LOAD_DIR);
+pub const SEARCH_APPLICATION_DIR       : LoadLibraryFlags = LoadLibraryFlags(LOAD_LIBRARY_SEARCH_APPLICATION_DIR);
+pub const SEARCH_USER_DIRS             : LoadLibraryFlags = LoadLibraryFlags(LOAD_LIBRARY_SEARCH_USER_DIRS);
+pub const SEARCH_SYSTEM32              : LoadLibraryFlags = LoadLibraryFlags(LOAD_LIBRARY_SEARCH_SYSTEM32);
+pub const SAFE_CURRENT_DIRS            : LoadLibraryFlags = LoadLibraryFlags(LOAD_LIBRARY_SAFE_CURRENT_DIRS);
+pub const SEARCH_SYSTEM32_NO_FORWARDER : LoadLibraryFlags = LoadLibraryFlags(LOAD_LIBRARY_SEARCH_SYSTEM32_NO_FORWARDER);
+pub const OS_INTEGRITY_CONTINUITY      : LoadLibraryFlags = LoadLibraryFlags(LOAD_LIBRARY_OS_INTEGRITY_CONTINUITY);
+
+const _ : () = { // crates/xtask/src/bin/data/load_library.rs must agree with winapi
+    assert!(LOAD_LIBRARY_AS_DATAFILE                  == 0x00000002);
+    assert!(LOAD_LIBRARY_AS_IMAGE_RESOURCE            == 0x00000020);
+    assert!(LOAD_LIBRARY_AS_DATAFILE_EXCLUSIVE        == 0x00000040);
+    assert!(LOAD_LIBRARY_REQUIRE_SIGNED_TARGET        == 0x00000080);
+    assert!(LOAD_LIBRARY_SEARCH_DEFAULT_DIRS          == 0x00001000);
+    assert!(LOAD_LIBRARY_SEARCH_DLL_LOAD_DIR          == 0x00000100);
+    assert!(LOAD_LIBRARY_SEARCH_APPLICATION_DIR       == 0x00000200);
+    assert!(LOAD_LIBRARY_SEARCH_USER_DIRS             == 0x00000400);
+    assert!(LOAD_LIBRARY_SEARCH_SYSTEM32              == 0x00000800);
+    assert!(LOAD_LIBRARY_SAFE_CURRENT_DIRS            == 0x00002000);
+    assert!(LOAD_LIBRARY_SEARCH_SYSTEM32_NO_FORWARDER == 0x00004000);
+    assert!(LOAD_LIBRARY_OS_INTEGRITY_CONTINUITY      == 0x00008000);
+};
@@ -0,0 +1,70 @@
+use crate::*;
+use abistr::*;
+use winapi::um::libloaderapi::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-getprocaddress)\]
+/// GetProcAddress
+///
+/// Retrieves the address of an exported function, as a typed function pointer `F` (e.g. <code>unsafe extern "system" fn() -> u32</code>.)
+///
+/// ### Safety
+/// *   `F` must match the signature and calling convention of the exported function.
+/// *   The returned function pointer must not be called after `hmodule` is unloaded (e.g. after its [Library] is dropped.)
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]  If `proc_name` contains interior nulls
+/// *   [ERROR::PROC_NOT_FOUND]     If `proc_name` isn't exported by `hmodule`
+///
+/// ### Example
+/// ```rust
+/// # use abistr::*;
+/// # use hwnd::*;
+/// # use winresult::*;
+/// let user32 = unsafe { load_library_ex_w(cstr16!("user32.dll"), LOAD_LIBRARY::SEARCH_SYSTEM32) }.unwrap();
+/// let get_dpi : unsafe extern "system" fn() -> u32 = unsafe { get_proc_address(&user32, "GetDpiForSystem") }.unwrap();
+/// let dpi = unsafe { get_dpi() };
+///
+/// type F = unsafe extern "system" fn();
+/// assert_eq!(ERROR::PROC_NOT_FOUND, unsafe { get_proc_address::<F>(&user32, "NotARealFunction") }.unwrap_err());
+/// ```
+pub unsafe fn get_proc_address<'m, F: FnPtr>(hmodule: impl Into<HModule<'m>>, proc_name: impl TryIntoAsCStr) -> Result<F, Error> {
+    fn_context!(get_proc_address => GetProcAddress);
+    debug_poison_last_error();
+    let proc_name = proc_name.try_into().map_err(|_| fn_param_error!(proc_name, ERROR::INVALID_PARAMETER))?;
+    let proc = unsafe { GetProcAddress(hmodule.into().into(), proc_name.as_cstr()) };
+    fn_succeeded!(!proc.is_null())?;
+    Ok(unsafe { std::mem::transmute_copy::<_, F>(&proc) })
+}
+
+/// A non-null function pointer type, as returned by [get_proc_address]: implemented for `extern "system"` and `extern "C"` functions of up to 12 parameters.
+///
+/// ### Safety
+/// *   Implementors must be (non-nullable) function pointers, with the same size and ABI as `FARPROC`.
+pub unsafe trait FnPtr : Copy + 'static {}
+
+macro_rules! fn_ptrs {
+    ( $( ( $($arg:ident),* ) )* ) => { $(
+        unsafe impl<R: 'static, $($arg: 'static),*> FnPtr for        extern "system" fn($($arg),*) -> R {}
+        unsafe impl<R: 'static, $($arg: 'static),*> FnPtr for unsafe extern "system" fn($($arg),*) -> R {}
+        unsafe impl<R: 'static, $($arg: 'static),*> FnPtr for        extern "C"      fn($($arg),*) -> R {}
+        unsafe impl<R: 'static, $($arg: 'static),*> FnPtr for unsafe extern "C"      fn($($arg),*) -> R {}
+    )* };
+}
+
+fn_ptrs! {
+    ()
+    (A)
+    (A, B)
+    (A, B, C)
+    (A, B, C, D)
+    (A, B, C, D, E)
+    (A, B, C, D, E, F)
+    (A, B, C, D, E, F, G)
+    (A, B, C, D, E, F, G, H)
+    (A, B, C, D, E, F, G, H, I)
+    (A, B, C, D, E, F, G, H, I, J)
+    (A, B, C, D, E, F, G, H, I, J, K)
+    (A, B, C, D, E, F, G, H, I, J, K, L)
+}
@@ -0,0 +1,67 @@
+use crate::*;
+use crate::LOAD_LIBRARY::LoadLibraryFlags;
+use abistr::*;
+use winapi::um::libloaderapi::*;
+use std::ptr::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryexw)\]
+/// LoadLibraryExW(file_name, nullptr, flags)
+///
+/// Loads a DLL or EXE into the process, returning an owned [Library] which is freed when dropped.
+///
+/// ### Safety
+/// Unless `flags` contains [LOAD_LIBRARY::AS_DATAFILE] or [LOAD_LIBRARY::AS_IMAGE_RESOURCE], this runs the module's `DllMain` and TLS callbacks, which may do anything.
+/// Additionally, unloading a module when the [Library] is dropped will invalidate any code or data still borrowed from it (e.g. via [get_proc_address].)
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]  If `file_name` contains interior nulls, or `flags` are invalid
+/// *   [ERROR::MOD_NOT_FOUND]      If `file_name` cannot be found
+///
+/// ### Example
+/// ```rust
+/// # use abistr::*;
+/// # use hwnd::*;
+/// # use winresult::*;
+/// let user32 = unsafe { load_library_ex_w(cstr16!("user32.dll"), LOAD_LIBRARY::SEARCH_SYSTEM32) }.unwrap();
+/// assert_eq!(ERROR::MOD_NOT_FOUND, unsafe { load_library_ex_w(cstr16!("not_a_real.dll"), LOAD_LIBRARY::SEARCH_SYSTEM32) }.unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [load_library_as_resource_w]    &mdash; safely load a module for its resources only
+/// *   [get_module_handle_ex_w_pin]    &mdash; get an already loaded module
+pub unsafe fn load_library_ex_w(file_name: impl TryIntoAsCStr<u16>, flags: impl Into<LoadLibraryFlags>) -> Result<Library, Error> {
+    fn_context!(load_library_ex_w => LoadLibraryExW);
+    debug_poison_last_error();
+    let file_name = file_name.try_into().map_err(|_| fn_param_error!(file_name, ERROR::INVALID_PARAMETER))?;
+    let hmodule = unsafe { LoadLibraryExW(file_name.as_cstr(), null_mut(), flags.into().into()) };
+    fn_succeeded!(!hmodule.is_null())?;
+    Ok(unsafe { Library::from_raw(hmodule) })
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryexw)\]
+/// LoadLibraryExW(file_name, nullptr, LOAD_LIBRARY_AS_DATAFILE | LOAD_LIBRARY_AS_IMAGE_RESOURCE)
+///
+/// Maps a DLL or EXE into the process for its resources (icons, strings, version info, ...) without running any of its code.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]  If `file_name` contains interior nulls
+/// *   [ERROR::MOD_NOT_FOUND]      If `file_name` cannot be found
+///
+/// ### Example
+/// ```rust
+/// # use abistr::*;
+/// # use hwnd::*;
+/// let shell32 = load_library_as_resource_w(cstr16!("shell32.dll")).unwrap();
+/// assert!(!enum_resource_names_w(&shell32, RT::GROUP_ICON).unwrap().is_empty());
+/// ```
+pub fn load_library_as_resource_w(file_name: impl TryIntoAsCStr<u16>) -> Result<Library, Error> {
+    fn_context!(load_library_as_resource_w => LoadLibraryExW);
+    debug_poison_last_error();
+    let file_name = file_name.try_into().map_err(|_| fn_param_error!(file_name, ERROR::INVALID_PARAMETER))?;
+    let flags = LOAD_LIBRARY::AS_DATAFILE | LOAD_LIBRARY::AS_IMAGE_RESOURCE;
+    let hmodule = unsafe { LoadLibraryExW(file_name.as_cstr(), null_mut(), flags.into()) };
+    fn_succeeded!(!hmodule.is_null())?;
+    Ok(unsafe { Library::from_raw(hmodule) })
+}
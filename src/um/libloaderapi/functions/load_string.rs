@@ -0,0 +1,38 @@
+use crate::*;
+use winapi::um::libloaderapi::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadstringw)\]
+/// LoadStringW(hinstance, id, &mut ptr, 0)
+///
+/// Borrows string `id` directly from `hinstance`'s string table, without copying it.
+/// The result is **not** `\0`-terminated.
+/// If `hinstance` is `()`, the entry executable is searched.
+///
+/// ### Errors
+/// *   [ERROR::RESOURCE_DATA_NOT_FOUND]    if `hinstance` has no resources
+/// *   [ERROR::RESOURCE_TYPE_NOT_FOUND]    if `hinstance` has no string table
+/// *   [ERROR::RESOURCE_NAME_NOT_FOUND]    if `hinstance` has no string `id` (string tables can't distinguish missing and empty strings)
+///
+/// ### Example
+/// ```rust
+/// # use abistr::*;
+/// # use hwnd::*;
+/// # use winresult::*;
+/// let user32 = load_library_as_resource_w(cstr16!("user32.dll")).unwrap();
+/// let ok = load_string_w(&user32, 800).unwrap(); // "OK"
+/// assert!(!ok.is_empty());
+///
+/// assert_eq!(ERROR::RESOURCE_NAME_NOT_FOUND, load_string_w(&user32, 0xFFFF).unwrap_err());
+/// ```
+pub fn load_string_w<'m>(hinstance: impl Into<HInstance<'m>>, id: u16) -> Result<&'m [u16], Error> {
+    fn_context!(load_string_w => LoadStringW);
+    debug_poison_last_error();
+    let mut ptr = std::ptr::null::<u16>();
+    clear_last_error();
+    let len = unsafe { LoadStringW(hinstance.into().into(), id.into(), (&mut ptr as *mut *const u16).cast(), 0) };
+    if len <= 0 || ptr.is_null() { return Err(fn_error_gle_nz!().err().unwrap_or(fn_error!(ERROR::RESOURCE_NAME_NOT_FOUND))) }
+    // SAFETY: string tables are read-only, and mapped for as long as the module is loaded ('m)
+    Ok(unsafe { std::slice::from_raw_parts(ptr, len as usize) })
+}
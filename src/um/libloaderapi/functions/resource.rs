@@ -0,0 +1,109 @@
+use crate::*;
+use winapi::shared::minwindef::{BOOL, HMODULE, TRUE};
+use winapi::shared::basetsd::LONG_PTR;
+use winapi::um::libloaderapi::*;
+use winapi::um::winnt::{LPCWSTR, LPWSTR};
+use std::ffi::OsString;
+use std::os::windows::ffi::OsStringExt;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-findresourceexw)\]
+/// FindResourceExW
+///
+/// Finds a resource of type `ty` (e.g. [RT::GROUP_ICON]) named `name`, in the specified `language` (`0` for the thread's default language, falling back on neutral / English resources.)
+/// If `hmodule` is `()`, the entry executable is searched.
+///
+/// ### Errors
+/// *   [ERROR::RESOURCE_DATA_NOT_FOUND]    if `hmodule` has no resources
+/// *   [ERROR::RESOURCE_TYPE_NOT_FOUND]    if `hmodule` has no resources of type `ty`
+/// *   [ERROR::RESOURCE_NAME_NOT_FOUND]    if `hmodule` has no `ty` resource named `name`
+/// *   [ERROR::RESOURCE_LANG_NOT_FOUND]    if `hmodule` has no `ty` resource named `name` in `language`
+///
+/// ### Example
+/// ```rust
+/// # use abistr::*;
+/// # use hwnd::*;
+/// # use winresult::*;
+/// let shell32 = load_library_as_resource_w(cstr16!("shell32.dll")).unwrap();
+/// let group   = find_resource_ex_w(&shell32, RT::GROUP_ICON, 4, 0).unwrap();
+/// let data    = load_resource(group).unwrap();
+/// assert_eq!(&data[..4], &[0, 0, 1, 0]); // GRPICONDIR: reserved, type = icon
+///
+/// assert_eq!(ERROR::RESOURCE_TYPE_NOT_FOUND, find_resource_ex_w(&shell32, 0x7FFF, 4, 0).unwrap_err());
+/// assert_eq!(ERROR::RESOURCE_NAME_NOT_FOUND, find_resource_ex_w(&shell32, RT::GROUP_ICON, 0x7FFF, 0).unwrap_err());
+/// ```
+pub fn find_resource_ex_w<'m, 't>(hmodule: impl Into<HModule<'m>>, ty: impl Into<NameAtomOrZero<'t, u16>>, name: impl Into<NameAtomOrZero<'t, u16>>, language: u16) -> Result<HRsrc<'m>, Error> {
+    fn_context!(find_resource_ex_w => FindResourceExW);
+    debug_poison_last_error();
+    let hmodule = hmodule.into();
+    let hrsrc = unsafe { FindResourceExW(hmodule.into(), ty.into().as_atom_or_cstr_ptr(), name.into().as_atom_or_cstr_ptr(), language) }; // N.B. winapi mislabels the type and name parameters
+    fn_succeeded!(!hrsrc.is_null())?;
+    Ok(unsafe { HRsrc::from_unchecked(hmodule, hrsrc) })
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadresource)\]
+/// LoadResource + LockResource + SizeofResource
+///
+/// Borrows the bytes of a resource, which remain valid for as long as the module stays loaded.
+///
+/// ### Errors
+/// *   [ERROR::RESOURCE_DATA_NOT_FOUND]    if `hrsrc` is invalid
+///
+/// ### See Also
+/// *   [find_resource_ex_w]    &mdash; find `hrsrc`
+pub fn load_resource<'m>(hrsrc: HRsrc<'m>) -> Result<&'m [u8], Error> {
+    fn_context!(load_resource => LoadResource);
+    debug_poison_last_error();
+    let hmodule = hrsrc.hmodule.into();
+    let hglobal = unsafe { LoadResource(hmodule, hrsrc.into()) };
+    fn_succeeded!(!hglobal.is_null())?;
+    let data = unsafe { LockResource(hglobal) };
+    fn_succeeded!(!data.is_null())?;
+    clear_last_error();
+    let size = unsafe { SizeofResource(hmodule, hrsrc.into()) };
+    if size == 0 { fn_error_gle_nz!()?; return Ok(&[]) }
+    // SAFETY: resources are read-only, and mapped for as long as the module is loaded ('m)
+    Ok(unsafe { std::slice::from_raw_parts(data.cast::<u8>(), size as usize) })
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-enumresourcenamesw)\]
+/// EnumResourceNamesW
+///
+/// Lists the names of every resource of type `ty` (e.g. [RT::ICON], [RT::GROUP_ICON], [RT::VERSION]) in `hmodule`.
+/// If `hmodule` is `()`, the entry executable is searched.
+///
+/// Returns an empty [Vec] if `hmodule` has no resources of type `ty` (or no resources at all.)
+///
+/// ### Example
+/// ```rust
+/// # use abistr::*;
+/// # use hwnd::*;
+/// let shell32 = load_library_as_resource_w(cstr16!("shell32.dll")).unwrap();
+/// let groups  = enum_resource_names_w(&shell32, RT::GROUP_ICON).unwrap();
+/// assert!(groups.contains(&ResourceName::Id(4)));
+/// assert_eq!(enum_resource_names_w(&shell32, 0x7FFF).unwrap(), []);
+/// ```
+pub fn enum_resource_names_w<'m, 't>(hmodule: impl Into<HModule<'m>>, ty: impl Into<NameAtomOrZero<'t, u16>>) -> Result<Vec<ResourceName>, Error> {
+    fn_context!(enum_resource_names_w => EnumResourceNamesW);
+    debug_poison_last_error();
+    let mut names = Vec::new();
+    let ok = unsafe { EnumResourceNamesW(hmodule.into().into(), ty.into().as_atom_or_cstr_ptr(), Some(enum_resource_names_proc), &mut names as *mut Vec<ResourceName> as LONG_PTR) };
+    if ok == 0 {
+        let err = fn_error_gle!();
+        if err == ERROR::RESOURCE_TYPE_NOT_FOUND || err == ERROR::RESOURCE_DATA_NOT_FOUND { return Ok(names) }
+        return Err(err);
+    }
+    Ok(names)
+}
+
+unsafe extern "system" fn enum_resource_names_proc(_hmodule: HMODULE, _ty: LPCWSTR, name: LPWSTR, lparam: LONG_PTR) -> BOOL {
+    let names = unsafe { &mut *(lparam as *mut Vec<ResourceName>) };
+    names.push(if let Ok(id) = u16::try_from(name as usize) {
+        ResourceName::Id(id)
+    } else {
+        let len = (0 ..).take_while(|&i| unsafe { *name.add(i) } != 0).count();
+        ResourceName::Name(OsString::from_wide(unsafe { std::slice::from_raw_parts(name, len) }))
+    });
+    TRUE
+}
@@ -0,0 +1,18 @@
+use std::ffi::OsString;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nc-libloaderapi-enumresnameprocw)\]
+/// The name of a resource, as enumerated by [enum_resource_names_w](crate::enum_resource_names_w)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)] pub enum ResourceName {
+    /// An integer resource ID (e.g. `IDI_APP` in a `.rc` script)
+    Id(u16),
+
+    /// A string resource name (e.g. `"APP_ICON"` in a `.rc` script)
+    Name(OsString),
+}
+
+impl ResourceName {
+    /// The integer resource ID, if any.
+    pub fn id(&self) -> Option<u16> { match self { Self::Id(id) => Some(*id), Self::Name(_) => None } }
+}
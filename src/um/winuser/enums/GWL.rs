@@ -32,6 +32,18 @@ impl_debug_for_enum! {
     }
 }
 
+impl_from_str_for_enum! {
+    GetWindowLongIndex => {
+        GWL::STYLE,
+        GWL::EXSTYLE,
+        GWL::ID,
+        GWL::WNDPROC,
+        GWL::HINSTANCE,
+        GWL::HWNDPARENT,
+        GWL::USERDATA,
+    }
+}
+
 
 
 pub const STYLE         : GetWindowLongIndex = GetWindowLongIndex(GWL_STYLE);
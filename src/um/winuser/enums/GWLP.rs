@@ -29,6 +29,16 @@ impl_debug_for_enum! {
     }
 }
 
+impl_from_str_for_enum! {
+    GetWindowLongPtrIndex => {
+        GWLP::WNDPROC,
+        GWLP::HINSTANCE,
+        GWLP::HWNDPARENT,
+        GWLP::USERDATA,
+        GWLP::ID,
+    }
+}
+
 
 
 pub const WNDPROC      : GetWindowLongPtrIndex = GetWindowLongPtrIndex(GWLP_WNDPROC);
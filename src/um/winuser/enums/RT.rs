@@ -0,0 +1,29 @@
+//! \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/menurc/resource-types)\]
+//! RT_\* resource types for [find_resource_ex_w], [enum_resource_names_w], etc.
+#![allow(non_snake_case)]
+
+use crate::*;
+
+
+
+pub const CURSOR        : AtomNonZero = AtomNonZero::from_constant(1);
+pub const BITMAP        : AtomNonZero = AtomNonZero::from_constant(2);
+pub const ICON          : AtomNonZero = AtomNonZero::from_constant(3);
+pub const MENU          : AtomNonZero = AtomNonZero::from_constant(4);
+pub const DIALOG        : AtomNonZero = AtomNonZero::from_constant(5);
+pub const STRING        : AtomNonZero = AtomNonZero::from_constant(6);
+pub const FONTDIR       : AtomNonZero = AtomNonZero::from_constant(7);
+pub const FONT          : AtomNonZero = AtomNonZero::from_constant(8);
+pub const ACCELERATOR   : AtomNonZero = AtomNonZero::from_constant(9);
+pub const RCDATA        : AtomNonZero = AtomNonZero::from_constant(10);
+pub const MESSAGETABLE  : AtomNonZero = AtomNonZero::from_constant(11);
+pub const GROUP_CURSOR  : AtomNonZero = AtomNonZero::from_constant(12);
+pub const GROUP_ICON    : AtomNonZero = AtomNonZero::from_constant(14);
+pub const VERSION       : AtomNonZero = AtomNonZero::from_constant(16);
+pub const DLGINCLUDE    : AtomNonZero = AtomNonZero::from_constant(17);
+pub const PLUGPLAY      : AtomNonZero = AtomNonZero::from_constant(19);
+pub const VXD           : AtomNonZero = AtomNonZero::from_constant(20);
+pub const ANICURSOR     : AtomNonZero = AtomNonZero::from_constant(21);
+pub const ANIICON       : AtomNonZero = AtomNonZero::from_constant(22);
+pub const HTML          : AtomNonZero = AtomNonZero::from_constant(23);
+pub const MANIFEST      : AtomNonZero = AtomNonZero::from_constant(24);
@@ -13,6 +13,7 @@ use bytemuck::*;
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Zeroable)] #[repr(transparent)] pub struct ShowWindowCmd(i32);
 
 impl From<ShowWindowCmd> for i32 { fn from(cmd: ShowWindowCmd) -> Self { cmd.0 } }
+impl From<i32> for ShowWindowCmd { fn from(cmd: i32) -> Self { Self(cmd) } }
 
 impl_debug_for_enum! {
     ShowWindowCmd => {
@@ -32,6 +33,25 @@ impl_debug_for_enum! {
     }
 }
 
+impl_from_str_for_enum! {
+    ShowWindowCmd => {
+        SW::HIDE,
+        SW::SHOWNORMAL,
+        SW::NORMAL,
+        SW::SHOWMINIMIZED,
+        SW::SHOWMAXIMIZED,
+        SW::MAXIMIZE,
+        SW::SHOWNOACTIVATE,
+        SW::SHOW,
+        SW::MINIMIZE,
+        SW::SHOWMINNOACTIVE,
+        SW::SHOWNA,
+        SW::RESTORE,
+        SW::SHOWDEFAULT,
+        SW::FORCEMINIMIZE,
+    }
+}
+
 
 
 /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-showwindow)\]
@@ -3,9 +3,13 @@
 #![allow(non_snake_case)]
 
 #[cfg(doc)] use crate::*;
+use crate::{register_window_message_w, Error, LastErrorGuard, ERROR};
 use bytemuck::*;
 use winapi::um::winuser::*;
 use core::fmt::{self, Debug, Formatter};
+use core::str::FromStr;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
 
 
 
@@ -23,6 +27,103 @@ impl From<u32> for WM32 { fn from(cmd: u32 ) -> Self { Self(cmd) } }
 impl PartialEq<u32> for WM32 { fn eq(&self, other: &u32 ) -> bool { self.0 == *other } }
 impl PartialEq<WM32> for u32 { fn eq(&self, other: &WM32) -> bool { *self == other.0 } }
 
+
+
+/// WM_\* (16-bit) window message and notification types
+///
+/// Message values packed into 16 bits, such as the low word of [WM::PARENTNOTIFY]'s `wparam`.
+/// All system, [`WM::USER`], [`WM::APP`], and [registered](WM::REGISTERED) messages fit, but [WM32] values `0x10000` and up don't.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Pod, Zeroable)] #[repr(transparent)] pub struct WM16(u16);
+
+impl WM16 {
+    pub const fn to_u16(self) -> u16 { self.0 }
+    pub const fn to_wm32(self) -> WM32 { WM32(self.0 as u32) }
+}
+
+impl From<WM16> for u16  { fn from(cmd: WM16) -> Self { cmd.0 } }
+impl From<WM16> for u32  { fn from(cmd: WM16) -> Self { cmd.0.into() } }
+impl From<WM16> for WM32 { fn from(cmd: WM16) -> Self { cmd.to_wm32() } }
+impl From<u16 > for WM16 { fn from(cmd: u16 ) -> Self { Self(cmd) } }
+impl TryFrom<WM32> for WM16 { type Error = core::num::TryFromIntError; fn try_from(cmd: WM32) -> Result<Self, Self::Error> { u16::try_from(cmd.0).map(Self) } }
+
+impl PartialEq<u16 > for WM16 { fn eq(&self, other: &u16 ) -> bool { self.0 == *other } }
+impl PartialEq<WM16> for u16  { fn eq(&self, other: &WM16) -> bool { *self == other.0 } }
+
+impl Debug for WM16 { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&self.to_wm32(), fmt) } }
+
+impl FromStr for WM16 {
+    type Err = crate::ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let wm = WM32::from_str(s).map_err(|err| crate::ParseError { ty: "WM16", ..err })?;
+        WM16::try_from(wm).map_err(|_| crate::ParseError { ty: "WM16", term: s.trim().into() })
+    }
+}
+
+impl_serde_via_str!(WM16);
+
+
+
+impl WM32 {
+    /// The name of a registered (string) message, e.g. `"TaskbarCreated"`.
+    ///
+    /// Messages in `0xC000 ..= 0xFFFF` are looked up via
+    /// [GetClipboardFormatNameW](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclipboardformatnamew),
+    /// as registered messages and clipboard formats share an atom table, and the result is remembered.
+    /// The name is spelled as it was first registered system-wide (atom names are case insensitive.)
+    ///
+    /// Returns `None` if `self` isn't a registered message.
+    pub fn registered_name(self) -> Option<String> {
+        if !(0xC000 ..= 0xFFFF).contains(&self.0) { return None }
+        if let Some(name) = registered_names().lock().ok().and_then(|names| names.get(&self.0).cloned()) { return Some(name.into()) }
+        let name = lookup_registered_name(self.0)?;
+        remember_registered_name(self, name.clone());
+        Some(name)
+    }
+
+    /// Find an already registered (string) message by name, without registering it (unlike [WM::REGISTERED].)
+    ///
+    /// Since there's no lookup-only equivalent of [RegisterWindowMessageW](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerwindowmessagew),
+    /// this may scan all of `0xC000 ..= 0xFFFF` via [registered_name](Self::registered_name)'s lookup, so prefer [WM::REGISTERED] when registering is acceptable.
+    ///
+    /// ### Example
+    /// ```
+    /// # use hwnd::*;
+    /// let wm = WM::REGISTERED("com.example.hwnd.WM32.find_registered").unwrap();
+    /// assert_eq!(Some(wm), WM32::find_registered("com.example.hwnd.WM32.find_registered"));
+    /// assert_eq!(Some(wm), WM32::find_registered("COM.EXAMPLE.HWND.WM32.FIND_REGISTERED"));
+    /// assert_eq!(None,     WM32::find_registered("com.example.hwnd.WM32.find_registered.never_registered"));
+    /// ```
+    pub fn find_registered(name: &str) -> Option<Self> {
+        if name.is_empty() { return None }
+        let remembered = registered_names().lock().ok().and_then(|names| names.iter().find(|(_, n)| n.eq_ignore_ascii_case(name)).map(|(wm, _)| *wm));
+        if let Some(wm) = remembered { return Some(Self(wm)) }
+        let wm = Self((0xC000 ..= 0xFFFF).find(|&wm| lookup_registered_name(wm).is_some_and(|n| n.eq_ignore_ascii_case(name)))?);
+        let _ = wm.registered_name(); // remember
+        Some(wm)
+    }
+}
+
+/// GetClipboardFormatNameW(wm, ...)
+fn lookup_registered_name(wm: u32) -> Option<String> {
+    let mut buf = [0u16; 256]; // atom names are limited to 255 characters
+    let _gle = LastErrorGuard::new(); // don't clobber the last error just to Debug a message
+    let n = unsafe { GetClipboardFormatNameW(wm, buf.as_mut_ptr(), buf.len() as _) };
+    let name = String::from_utf16(buf.get(..usize::try_from(n).ok()?)?).ok()?;
+    (!name.is_empty()).then_some(name)
+}
+
+/// Names of registered (string) messages, by value
+fn registered_names() -> &'static Mutex<BTreeMap<u32, Box<str>>> {
+    lazy_static::lazy_static! { static ref NAMES : Mutex<BTreeMap<u32, Box<str>>> = Default::default(); }
+    &NAMES
+}
+
+/// Remember `wm`'s name (as looked up by [`WM32::registered_name`]) for [Debug].
+fn remember_registered_name(wm: WM32, name: String) {
+    if !(0xC000 ..= 0xFFFF).contains(&wm.0) || name.is_empty() { return }
+    if let Ok(mut names) = registered_names().lock() { names.entry(wm.0).or_insert_with(|| name.into()); }
+}
+
 impl Debug for WM32 {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
         // https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-app#remarks
@@ -31,19 +132,66 @@ impl Debug for WM32 {
             0       ..= WM_USER_1   => if let Some(s) = self.to_str() { write!(fmt, "{}", s) } else { write!(fmt, "WM_??? (system message {:#X})", self.0) },
             WM_USER ..= 0x7FFF      => write!(fmt, "WM_USER+{}", self.0 - WM_USER),
             WM_APP  ..= 0xBFFF      => write!(fmt, "WM_APP+{}",  self.0 - WM_APP),
-            0xC000  ..= 0xFFFF      => write!(fmt, "WM_??? (string message {:#X})", self.0),
+            0xC000  ..= 0xFFFF      => if let Some(name) = self.registered_name() { write!(fmt, "WM::REGISTERED({:?})", name) } else { write!(fmt, "WM_??? (string message {:#X})", self.0) },
             0x10000 ..              => write!(fmt, "WM_??? (system message {:#X})", self.0),
         }
     }
 }
 
+/// Accepts anything [Debug] produces (`"WM::CREATE"`, `"WM_USER+5"`, `"WM::REGISTERED(\"TaskbarCreated\")"`, `"WM_??? (string message 0xC123)"`, ...), C spellings (`"WM_CREATE"`), and integers (`"0x0001"`).
+///
+/// `WM::REGISTERED(...)` is parsed by [looking up](WM32::find_registered) an already registered message, without registering it.
+impl FromStr for WM32 {
+    type Err = crate::ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use crate::utils::parse::integer;
+        let term = s.trim();
+
+        // "WM_USER+N", "WM_APP+N" (n.b. "WM_USERCHANGED", "WM_APPCOMMAND" fall through to names)
+        let offset = |base: u32, end: u32, rest: &str| -> Option<WM32> {
+            let rest = rest.trim_start();
+            let n = if rest.is_empty() { 0 } else { integer(rest.strip_prefix('+')?.trim())? };
+            let wm = u32::try_from(i64::from(base) + n).ok()?;
+            (base..end).contains(&wm).then_some(WM32(wm))
+        };
+        for (base, end, prefixes) in [(WM_USER, WM_APP, ["WM_USER", "WM::USER"]), (WM_APP, 0xC000, ["WM_APP", "WM::APP"])] {
+            for prefix in prefixes {
+                if let Some(wm) = term.strip_prefix(prefix).and_then(|rest| offset(base, end, rest)) { return Ok(wm) }
+            }
+        }
+
+        // "WM_??? (system message 0x1234)", "WM_??? (string message 0xC123)"
+        if let Some(rest) = term.strip_prefix("WM_???") {
+            let n = rest.trim().strip_prefix('(').and_then(|r| r.strip_suffix(')')).map(str::trim)
+                .and_then(|r| r.strip_prefix("system message").or_else(|| r.strip_prefix("string message")))
+                .and_then(|r| integer(r.trim()))
+                .and_then(|n| u32::try_from(n).ok());
+            return n.map(WM32).ok_or_else(|| crate::ParseError { ty: "WM32", term: term.into() });
+        }
+
+        // "WM::REGISTERED(\"TaskbarCreated\")"
+        if let Some(quoted) = term.strip_prefix("WM::REGISTERED(").and_then(|r| r.strip_suffix(')')) {
+            return crate::utils::parse::debug_str(quoted.trim()).and_then(|name| WM32::find_registered(&name))
+                .ok_or_else(|| crate::ParseError { ty: "WM32", term: term.into() });
+        }
+
+        // "WM::CREATE", "WM_CREATE"
+        if let Some(wm) = term.strip_prefix("WM::").or_else(|| term.strip_prefix("WM_")).and_then(WM32::from_name) { return Ok(wm) }
+
+        // "1", "0x0001"
+        integer(term).and_then(|n| u32::try_from(n).ok()).map(WM32).ok_or_else(|| crate::ParseError { ty: "WM32", term: term.into() })
+    }
+}
+
+impl_serde_via_str!(WM32);
+
 macro_rules! messages {($(
     $(#[$($attr:meta),*$(,)?])*
     $($url:literal)?
     $ident:ident => $winapi:expr
 ),* $(,)? ) => {
     impl WM32 {
-        fn to_str(self) -> Option<&'static str> {
+        pub(crate) fn to_str(self) -> Option<&'static str> {
             //#[allow(unreachable_patterns)]
             match self {
                 $(
@@ -53,6 +201,17 @@ macro_rules! messages {($(
                 _ => None,
             }
         }
+
+        /// `"CREATE"` &rarr; [`WM::CREATE`]
+        fn from_name(name: &str) -> Option<Self> {
+            match name {
+                $(
+                    $(#[$($attr),*])*
+                    stringify!($ident) => Some($ident),
+                )*
+                _ => None,
+            }
+        }
     }
 
     $(
@@ -127,6 +286,35 @@ pub const fn USER(x: u32) -> WM32 {
     WM32(wm)
 }
 
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerwindowmessagew)\]
+/// RegisterWindowMessageW(name)
+///
+/// Messages shared between applications, in the range `0xC000 ..= 0xFFFF`.
+/// [Debug]s as `WM::REGISTERED("name")`.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]  if `name` contains interior `\0`s
+/// *   [ERROR::NOT_ENOUGH_MEMORY]  if the system is unable to allocate a unique message constant between 0xC000 and 0xFFFF
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// let wm = WM::REGISTERED("com.example.hwnd.WM_EXAMPLE").unwrap();
+/// assert_eq!(wm, register_window_message_w(abistr::cstr16!("com.example.hwnd.WM_EXAMPLE")).unwrap());
+/// assert_eq!(format!("{wm:?}"), r#"WM::REGISTERED("com.example.hwnd.WM_EXAMPLE")"#);
+/// assert_eq!(Some(wm), format!("{wm:?}").parse().ok());
+/// ```
+///
+/// ### See Also
+/// *   [`WM::APP`]     &mdash; Messages specific to an application.
+/// *   [`WM::USER`]    &mdash; Messages specific to a window class.
+pub fn REGISTERED(name: &str) -> Result<WM32, Error> {
+    fn_context!(WM::REGISTERED => RegisterWindowMessageW);
+    let units = name.encode_utf16().chain(Some(0)).collect::<Vec<u16>>();
+    let units = abistr::CStrNonNull::<u16>::from_units_with_nul(&units).map_err(|_| fn_param_error!(name, ERROR::INVALID_PARAMETER))?;
+    register_window_message_w(units)
+}
+
 // TODO: WM_{KEY, IME_KEY, MOUSE, TABLET, HANDHELD, AFX, PENWIN}{FIRST, LAST}
 
 // https://social.msdn.microsoft.com/Forums/windowsapps/en-US/f677f319-9f02-4438-92fb-6e776924425d/windowproc-and-messages-0x90-0x91-0x92-0x93?forum=windowsuidevelopment
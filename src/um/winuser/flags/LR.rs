@@ -0,0 +1,93 @@
+// WARNING: this file is auto-generated by xtask gen and may be overwritten: edit crates/xtask/src/bin/data/lr.rs instead
+//! \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadimagew)\]
+//! LR_\* flags for [load_image_icon_w], [create_icon_from_resource_ex], etc.
+
+#![allow(non_snake_case)]
+
+use crate::*;
+use bytemuck::*;
+use winapi::um::winuser::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadimagew)\]
+/// LR_\* flags for [load_image_icon_w], [create_icon_from_resource_ex], etc.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Zeroable)] #[repr(transparent)] pub struct LoadImageFlags(u32);
+impl_ops_for_flag!(LoadImageFlags);
+
+impl From<LoadImageFlags> for u32 { fn from(cmd: LoadImageFlags) -> Self { cmd.0 } }
+impl From<u32> for LoadImageFlags { fn from(cmd: u32           ) -> Self { Self(cmd) } }
+
+impl_debug_for_flags! {
+    LoadImageFlags => {
+        //LR::DEFAULTCOLOR,     // 0 / default
+        LR::MONOCHROME,
+        LR::COLOR,
+        LR::COPYRETURNORG,
+        LR::COPYDELETEORG,
+        LR::LOADFROMFILE,
+        LR::LOADTRANSPARENT,
+        LR::DEFAULTSIZE,
+        LR::VGACOLOR,
+        LR::LOADMAP3DCOLORS,
+        LR::CREATEDIBSECTION,
+        LR::COPYFROMRESOURCE,
+        LR::SHARED,
+    }
+}
+
+impl_from_str_for_flags! {
+    LoadImageFlags => {
+        LR::DEFAULTCOLOR,
+        LR::MONOCHROME,
+        LR::COLOR,
+        LR::COPYRETURNORG,
+        LR::COPYDELETEORG,
+        LR::LOADFROMFILE,
+        LR::LOADTRANSPARENT,
+        LR::DEFAULTSIZE,
+        LR::VGACOLOR,
+        LR::LOADMAP3DCOLORS,
+        LR::CREATEDIBSECTION,
+        LR::COPYFROMRESOURCE,
+        LR::SHARED,
+    }
+}
+
+impl_validate_for_flags! {
+    LoadImageFlags => {
+        LR::LOADFROMFILE | LR::SHARED => "Do not use LR_SHARED for images that are loaded from a file",
+    }
+}
+
+
+
+pub const DEFAULTCOLOR     : LoadImageFlags = LoadImageFlags(LR_DEFAULTCOLOR);
+pub const MONOCHROME       : LoadImageFlags = LoadImageFlags(LR_MONOCHROME);
+pub const COLOR            : LoadImageFlags = LoadImageFlags(LR_COLOR);
+pub const COPYRETURNORG    : LoadImageFlags = LoadImageFlags(LR_COPYRETURNORG);
+pub const COPYDELETEORG    : LoadImageFlags = LoadImageFlags(LR_COPYDELETEORG);
+pub const LOADFROMFILE     : LoadImageFlags = LoadImageFlags(LR_LOADFROMFILE);
+pub const LOADTRANSPARENT  : LoadImageFlags = LoadImageFlags(LR_LOADTRANSPARENT);
+pub const DEFAULTSIZE      : LoadImageFlags = LoadImageFlags(LR_DEFAULTSIZE);
+pub const VGACOLOR         : LoadImageFlags = LoadImageFlags(LR_VGACOLOR);
+pub const LOADMAP3DCOLORS  : LoadImageFlags = LoadImageFlags(LR_LOADMAP3DCOLORS);
+pub const CREATEDIBSECTION : LoadImageFlags = LoadImageFlags(LR_CREATEDIBSECTION);
+pub const COPYFROMRESOURCE : LoadImageFlags = LoadImageFlags(LR_COPYFROMRESOURCE);
+pub const SHARED           : LoadImageFlags = LoadImageFlags(LR_SHARED);
+
+const _ : () = { // crates/xtask/src/bin/data/lr.rs must agree with winapi
+    assert!(LR_DEFAULTCOLOR     == 0x00000000);
+    assert!(LR_MONOCHROME       == 0x00000001);
+    assert!(LR_COLOR            == 0x00000002);
+    assert!(LR_COPYRETURNORG    == 0x00000004);
+    assert!(LR_COPYDELETEORG    == 0x00000008);
+    assert!(LR_LOADFROMFILE     == 0x00000010);
+    assert!(LR_LOADTRANSPARENT  == 0x00000020);
+    assert!(LR_DEFAULTSIZE      == 0x00000040);
+    assert!(LR_VGACOLOR         == 0x00000080);
+    assert!(LR_LOADMAP3DCOLORS  == 0x00001000);
+    assert!(LR_CREATEDIBSECTION == 0x00002000);
+    assert!(LR_COPYFROMRESOURCE == 0x00004000);
+    assert!(LR_SHARED           == 0x00008000);
+};
@@ -0,0 +1,51 @@
+// WARNING: this file is auto-generated by xtask gen and may be overwritten: edit crates/xtask/src/bin/data/lwa.rs instead
+//! \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setlayeredwindowattributes)\]
+//! LWA_\* flags for [set_layered_window_attributes]
+
+#![allow(non_snake_case)]
+
+use crate::*;
+use bytemuck::*;
+use winapi::um::winuser::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setlayeredwindowattributes)\]
+/// LWA_\* flags for [set_layered_window_attributes]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Zeroable)] #[repr(transparent)] pub struct LayeredWindowAttributesFlags(u32);
+impl_ops_for_flag!(LayeredWindowAttributesFlags);
+
+impl From<LayeredWindowAttributesFlags> for u32 { fn from(cmd: LayeredWindowAttributesFlags) -> Self { cmd.0 } }
+impl From<u32> for LayeredWindowAttributesFlags { fn from(cmd: u32                         ) -> Self { Self(cmd) } }
+
+impl_debug_for_flags! {
+    LayeredWindowAttributesFlags => {
+        LWA::COLORKEY,
+        LWA::ALPHA,
+    }
+}
+
+impl_from_str_for_flags! {
+    LayeredWindowAttributesFlags => {
+        LWA::COLORKEY,
+        LWA::ALPHA,
+    }
+}
+
+impl_validate_for_flags! {
+    LayeredWindowAttributesFlags => {
+    }
+}
+
+
+
+/// Pixels matching `key` are fully transparent (and click-through.)
+pub const COLORKEY : LayeredWindowAttributesFlags = LayeredWindowAttributesFlags(LWA_COLORKEY);
+
+/// The whole window is blended with opacity `alpha`.
+pub const ALPHA    : LayeredWindowAttributesFlags = LayeredWindowAttributesFlags(LWA_ALPHA);
+
+const _ : () = { // crates/xtask/src/bin/data/lwa.rs must agree with winapi
+    assert!(LWA_COLORKEY == 0x00000001);
+    assert!(LWA_ALPHA    == 0x00000002);
+};
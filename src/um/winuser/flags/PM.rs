@@ -1,3 +1,4 @@
+// WARNING: this file is auto-generated by xtask gen and may be overwritten: edit crates/xtask/src/bin/data/pm.rs instead
 //! \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-peekmessagew)\]
 //! PM_\* flags for [peek_message](peek_message_w)
 
@@ -15,7 +16,7 @@ use winapi::um::winuser::*;
 impl_ops_for_flag!(PeekMessageFlags);
 
 impl From<PeekMessageFlags> for u32 { fn from(cmd: PeekMessageFlags) -> Self { cmd.0 } }
-impl From<u32> for PeekMessageFlags { fn from(cmd: u32 ) -> Self { Self(cmd) } }
+impl From<u32> for PeekMessageFlags { fn from(cmd: u32             ) -> Self { Self(cmd) } }
 
 impl_debug_for_flags! {
     PeekMessageFlags => {
@@ -29,12 +30,39 @@ impl_debug_for_flags! {
     }
 }
 
+impl_from_str_for_flags! {
+    PeekMessageFlags => {
+        PM::NOREMOVE,
+        PM::REMOVE,
+        PM::NOYIELD,
+        PM::QS_INPUT,
+        PM::QS_PAINT,
+        PM::QS_POSTMESSAGE,
+        PM::QS_SENDMESSAGE,
+    }
+}
+
+impl_validate_for_flags! {
+    PeekMessageFlags => {
+    }
+}
+
+
 
+pub const NOREMOVE       : PeekMessageFlags = PeekMessageFlags(PM_NOREMOVE);
+pub const REMOVE         : PeekMessageFlags = PeekMessageFlags(PM_REMOVE);
+pub const NOYIELD        : PeekMessageFlags = PeekMessageFlags(PM_NOYIELD);
+pub const QS_INPUT       : PeekMessageFlags = PeekMessageFlags(PM_QS_INPUT);
+pub const QS_PAINT       : PeekMessageFlags = PeekMessageFlags(PM_QS_PAINT);
+pub const QS_POSTMESSAGE : PeekMessageFlags = PeekMessageFlags(PM_QS_POSTMESSAGE);
+pub const QS_SENDMESSAGE : PeekMessageFlags = PeekMessageFlags(PM_QS_SENDMESSAGE);
 
-pub const NOREMOVE          : PeekMessageFlags = PeekMessageFlags(PM_NOREMOVE);
-pub const REMOVE            : PeekMessageFlags = PeekMessageFlags(PM_REMOVE);
-pub const NOYIELD           : PeekMessageFlags = PeekMessageFlags(PM_NOYIELD);
-pub const QS_INPUT          : PeekMessageFlags = PeekMessageFlags(PM_QS_INPUT);
-pub const QS_PAINT          : PeekMessageFlags = PeekMessageFlags(PM_QS_PAINT);
-pub const QS_POSTMESSAGE    : PeekMessageFlags = PeekMessageFlags(PM_QS_POSTMESSAGE);
-pub const QS_SENDMESSAGE    : PeekMessageFlags = PeekMessageFlags(PM_QS_SENDMESSAGE);
+const _ : () = { // crates/xtask/src/bin/data/pm.rs must agree with winapi
+    assert!(PM_NOREMOVE       == 0x00000000);
+    assert!(PM_REMOVE         == 0x00000001);
+    assert!(PM_NOYIELD        == 0x00000002);
+    assert!(PM_QS_INPUT       == 0x1C070000);
+    assert!(PM_QS_PAINT       == 0x00200000);
+    assert!(PM_QS_POSTMESSAGE == 0x00980000);
+    assert!(PM_QS_SENDMESSAGE == 0x00400000);
+};
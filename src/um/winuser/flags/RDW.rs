@@ -0,0 +1,116 @@
+// WARNING: this file is auto-generated by xtask gen and may be overwritten: edit crates/xtask/src/bin/data/rdw.rs instead
+//! \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-redrawwindow)\]
+//! RDW_\* flags for [redraw_window]
+
+#![allow(non_snake_case)]
+
+use crate::*;
+use bytemuck::*;
+use winapi::um::winuser::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-redrawwindow)\]
+/// RDW_\* flags for [redraw_window]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Zeroable)] #[repr(transparent)] pub struct RedrawWindowFlags(u32);
+impl_ops_for_flag!(RedrawWindowFlags);
+
+impl From<RedrawWindowFlags> for u32 { fn from(cmd: RedrawWindowFlags) -> Self { cmd.0 } }
+impl From<u32> for RedrawWindowFlags { fn from(cmd: u32              ) -> Self { Self(cmd) } }
+
+impl_debug_for_flags! {
+    RedrawWindowFlags => {
+        RDW::INVALIDATE,
+        RDW::INTERNALPAINT,
+        RDW::ERASE,
+        RDW::VALIDATE,
+        RDW::NOINTERNALPAINT,
+        RDW::NOERASE,
+        RDW::NOCHILDREN,
+        RDW::ALLCHILDREN,
+        RDW::UPDATENOW,
+        RDW::ERASENOW,
+        RDW::FRAME,
+        RDW::NOFRAME,
+    }
+}
+
+impl_from_str_for_flags! {
+    RedrawWindowFlags => {
+        RDW::INVALIDATE,
+        RDW::INTERNALPAINT,
+        RDW::ERASE,
+        RDW::VALIDATE,
+        RDW::NOINTERNALPAINT,
+        RDW::NOERASE,
+        RDW::NOCHILDREN,
+        RDW::ALLCHILDREN,
+        RDW::UPDATENOW,
+        RDW::ERASENOW,
+        RDW::FRAME,
+        RDW::NOFRAME,
+    }
+}
+
+impl_validate_for_flags! {
+    RedrawWindowFlags => {
+        RDW::INVALIDATE | RDW::VALIDATE => "RDW_INVALIDATE and RDW_VALIDATE contradict each other",
+        RDW::INTERNALPAINT | RDW::NOINTERNALPAINT => "RDW_INTERNALPAINT and RDW_NOINTERNALPAINT contradict each other",
+        RDW::ERASE | RDW::NOERASE => "RDW_ERASE and RDW_NOERASE contradict each other",
+        RDW::NOCHILDREN | RDW::ALLCHILDREN => "RDW_NOCHILDREN and RDW_ALLCHILDREN contradict each other",
+        RDW::FRAME | RDW::NOFRAME => "RDW_FRAME and RDW_NOFRAME contradict each other",
+    }
+}
+
+
+
+/// Invalidate the update region (or the whole window, if none is specified.)
+pub const INVALIDATE      : RedrawWindowFlags = RedrawWindowFlags(RDW_INVALIDATE);
+
+/// Post [WM::PAINT] even if the window isn't invalid.
+pub const INTERNALPAINT   : RedrawWindowFlags = RedrawWindowFlags(RDW_INTERNALPAINT);
+
+/// Send [WM::ERASEBKGND] when the window is repainted. Requires [INVALIDATE].
+pub const ERASE           : RedrawWindowFlags = RedrawWindowFlags(RDW_ERASE);
+
+/// Validate the update region (or the whole window, if none is specified.)
+pub const VALIDATE        : RedrawWindowFlags = RedrawWindowFlags(RDW_VALIDATE);
+
+/// Suppress pending internal [WM::PAINT]s.
+pub const NOINTERNALPAINT : RedrawWindowFlags = RedrawWindowFlags(RDW_NOINTERNALPAINT);
+
+/// Suppress pending [WM::ERASEBKGND]s.
+pub const NOERASE         : RedrawWindowFlags = RedrawWindowFlags(RDW_NOERASE);
+
+/// Exclude child windows.
+pub const NOCHILDREN      : RedrawWindowFlags = RedrawWindowFlags(RDW_NOCHILDREN);
+
+/// Include child windows, even those with [WS::CLIPCHILDREN] parents.
+pub const ALLCHILDREN     : RedrawWindowFlags = RedrawWindowFlags(RDW_ALLCHILDREN);
+
+/// Send [WM::NCPAINT], [WM::ERASEBKGND], and [WM::PAINT] before returning, as needed.
+pub const UPDATENOW       : RedrawWindowFlags = RedrawWindowFlags(RDW_UPDATENOW);
+
+/// Send [WM::NCPAINT] and [WM::ERASEBKGND] before returning, as needed.
+pub const ERASENOW        : RedrawWindowFlags = RedrawWindowFlags(RDW_ERASENOW);
+
+/// Include the nonclient area (sending [WM::NCPAINT].)
+pub const FRAME           : RedrawWindowFlags = RedrawWindowFlags(RDW_FRAME);
+
+/// Exclude the nonclient area.
+pub const NOFRAME         : RedrawWindowFlags = RedrawWindowFlags(RDW_NOFRAME);
+
+const _ : () = { // crates/xtask/src/bin/data/rdw.rs must agree with winapi
+    assert!(RDW_INVALIDATE      == 0x00000001);
+    assert!(RDW_INTERNALPAINT   == 0x00000002);
+    assert!(RDW_ERASE           == 0x00000004);
+    assert!(RDW_VALIDATE        == 0x00000008);
+    assert!(RDW_NOINTERNALPAINT == 0x00000010);
+    assert!(RDW_NOERASE         == 0x00000020);
+    assert!(RDW_NOCHILDREN      == 0x00000040);
+    assert!(RDW_ALLCHILDREN     == 0x00000080);
+    assert!(RDW_UPDATENOW       == 0x00000100);
+    assert!(RDW_ERASENOW        == 0x00000200);
+    assert!(RDW_FRAME           == 0x00000400);
+    assert!(RDW_NOFRAME         == 0x00000800);
+};
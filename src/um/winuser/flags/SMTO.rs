@@ -1,3 +1,4 @@
+// WARNING: this file is auto-generated by xtask gen and may be overwritten: edit crates/xtask/src/bin/data/smto.rs instead
 //! \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-sendmessagetimeoutw)\]
 //! SMTO_\* window style flags for [send_message_timeout](send_message_timeout_w)
 
@@ -15,7 +16,7 @@ use winapi::um::winuser::*;
 impl_ops_for_flag!(SendMessageTimeOutFlags);
 
 impl From<SendMessageTimeOutFlags> for u32 { fn from(cmd: SendMessageTimeOutFlags) -> Self { cmd.0 } }
-impl From<u32> for SendMessageTimeOutFlags { fn from(cmd: u32 ) -> Self { Self(cmd) } }
+impl From<u32> for SendMessageTimeOutFlags { fn from(cmd: u32                    ) -> Self { Self(cmd) } }
 
 impl_debug_for_flags! {
     SendMessageTimeOutFlags => {
@@ -27,10 +28,33 @@ impl_debug_for_flags! {
     }
 }
 
+impl_from_str_for_flags! {
+    SendMessageTimeOutFlags => {
+        SMTO::ABORTIFHUNG,
+        SMTO::BLOCK,
+        SMTO::NORMAL,
+        SMTO::NOTIMEOUTIFNOTHUNG,
+        SMTO::ERRORONEXIT,
+    }
+}
+
+impl_validate_for_flags! {
+    SendMessageTimeOutFlags => {
+    }
+}
+
+
 
+pub const ABORTIFHUNG        : SendMessageTimeOutFlags = SendMessageTimeOutFlags(SMTO_ABORTIFHUNG);
+pub const BLOCK              : SendMessageTimeOutFlags = SendMessageTimeOutFlags(SMTO_BLOCK);
+pub const NORMAL             : SendMessageTimeOutFlags = SendMessageTimeOutFlags(SMTO_NORMAL);
+pub const NOTIMEOUTIFNOTHUNG : SendMessageTimeOutFlags = SendMessageTimeOutFlags(SMTO_NOTIMEOUTIFNOTHUNG);
+pub const ERRORONEXIT        : SendMessageTimeOutFlags = SendMessageTimeOutFlags(SMTO_ERRORONEXIT);
 
-pub const ABORTIFHUNG           : SendMessageTimeOutFlags = SendMessageTimeOutFlags(SMTO_ABORTIFHUNG);
-pub const BLOCK                 : SendMessageTimeOutFlags = SendMessageTimeOutFlags(SMTO_BLOCK);
-pub const NORMAL                : SendMessageTimeOutFlags = SendMessageTimeOutFlags(SMTO_NORMAL);
-pub const NOTIMEOUTIFNOTHUNG    : SendMessageTimeOutFlags = SendMessageTimeOutFlags(SMTO_NOTIMEOUTIFNOTHUNG);
-pub const ERRORONEXIT           : SendMessageTimeOutFlags = SendMessageTimeOutFlags(SMTO_ERRORONEXIT);
+const _ : () = { // crates/xtask/src/bin/data/smto.rs must agree with winapi
+    assert!(SMTO_ABORTIFHUNG        == 0x00000002);
+    assert!(SMTO_BLOCK              == 0x00000001);
+    assert!(SMTO_NORMAL             == 0x00000000);
+    assert!(SMTO_NOTIMEOUTIFNOTHUNG == 0x00000008);
+    assert!(SMTO_ERRORONEXIT        == 0x00000020);
+};
@@ -1,3 +1,4 @@
+// WARNING: this file is auto-generated by xtask gen and may be overwritten: edit crates/xtask/src/bin/data/swp.rs instead
 //! \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowpos)\]
 //! SWP_\* flags for [set_window_pos]
 
@@ -15,7 +16,7 @@ use winapi::um::winuser::*;
 impl_ops_for_flag!(SetWindowPosFlags);
 
 impl From<SetWindowPosFlags> for u32 { fn from(cmd: SetWindowPosFlags) -> Self { cmd.0 } }
-impl From<u32> for SetWindowPosFlags { fn from(cmd: u32 ) -> Self { Self(cmd) } }
+impl From<u32> for SetWindowPosFlags { fn from(cmd: u32              ) -> Self { Self(cmd) } }
 
 impl_debug_for_flags! {
     SetWindowPosFlags => {
@@ -30,27 +31,71 @@ impl_debug_for_flags! {
         SWP::NOCOPYBITS,
         SWP::NOOWNERZORDER,
         SWP::NOSENDCHANGING,
-        //SWP::DRAWFRAME,       // duplicate
-        //SWP::NOREPOSITION,    // duplicate
+        //SWP::DRAWFRAME,      // alias for SWP::FRAMECHANGED
+        //SWP::NOREPOSITION,   // alias for SWP::NOOWNERZORDER
         SWP::DEFERERASE,
         SWP::ASYNCWINDOWPOS,
     }
 }
 
+impl_from_str_for_flags! {
+    SetWindowPosFlags => {
+        SWP::NOSIZE,
+        SWP::NOMOVE,
+        SWP::NOZORDER,
+        SWP::NOREDRAW,
+        SWP::NOACTIVATE,
+        SWP::FRAMECHANGED,
+        SWP::SHOWWINDOW,
+        SWP::HIDEWINDOW,
+        SWP::NOCOPYBITS,
+        SWP::NOOWNERZORDER,
+        SWP::NOSENDCHANGING,
+        SWP::DRAWFRAME,
+        SWP::NOREPOSITION,
+        SWP::DEFERERASE,
+        SWP::ASYNCWINDOWPOS,
+    }
+}
+
+impl_validate_for_flags! {
+    SetWindowPosFlags => {
+        SWP::SHOWWINDOW | SWP::HIDEWINDOW => "SWP_SHOWWINDOW and SWP_HIDEWINDOW contradict each other",
+    }
+}
+
+
 
+pub const NOSIZE         : SetWindowPosFlags = SetWindowPosFlags(SWP_NOSIZE);
+pub const NOMOVE         : SetWindowPosFlags = SetWindowPosFlags(SWP_NOMOVE);
+pub const NOZORDER       : SetWindowPosFlags = SetWindowPosFlags(SWP_NOZORDER);
+pub const NOREDRAW       : SetWindowPosFlags = SetWindowPosFlags(SWP_NOREDRAW);
+pub const NOACTIVATE     : SetWindowPosFlags = SetWindowPosFlags(SWP_NOACTIVATE);
+pub const FRAMECHANGED   : SetWindowPosFlags = SetWindowPosFlags(SWP_FRAMECHANGED);
+pub const SHOWWINDOW     : SetWindowPosFlags = SetWindowPosFlags(SWP_SHOWWINDOW);
+pub const HIDEWINDOW     : SetWindowPosFlags = SetWindowPosFlags(SWP_HIDEWINDOW);
+pub const NOCOPYBITS     : SetWindowPosFlags = SetWindowPosFlags(SWP_NOCOPYBITS);
+pub const NOOWNERZORDER  : SetWindowPosFlags = SetWindowPosFlags(SWP_NOOWNERZORDER);
+pub const NOSENDCHANGING : SetWindowPosFlags = SetWindowPosFlags(SWP_NOSENDCHANGING);
+pub const DRAWFRAME      : SetWindowPosFlags = SetWindowPosFlags(SWP_DRAWFRAME);
+pub const NOREPOSITION   : SetWindowPosFlags = SetWindowPosFlags(SWP_NOREPOSITION);
+pub const DEFERERASE     : SetWindowPosFlags = SetWindowPosFlags(SWP_DEFERERASE);
+pub const ASYNCWINDOWPOS : SetWindowPosFlags = SetWindowPosFlags(SWP_ASYNCWINDOWPOS);
 
-pub const NOSIZE            : SetWindowPosFlags = SetWindowPosFlags(SWP_NOSIZE);
-pub const NOMOVE            : SetWindowPosFlags = SetWindowPosFlags(SWP_NOMOVE);
-pub const NOZORDER          : SetWindowPosFlags = SetWindowPosFlags(SWP_NOZORDER);
-pub const NOREDRAW          : SetWindowPosFlags = SetWindowPosFlags(SWP_NOREDRAW);
-pub const NOACTIVATE        : SetWindowPosFlags = SetWindowPosFlags(SWP_NOACTIVATE);
-pub const FRAMECHANGED      : SetWindowPosFlags = SetWindowPosFlags(SWP_FRAMECHANGED);
-pub const SHOWWINDOW        : SetWindowPosFlags = SetWindowPosFlags(SWP_SHOWWINDOW);
-pub const HIDEWINDOW        : SetWindowPosFlags = SetWindowPosFlags(SWP_HIDEWINDOW);
-pub const NOCOPYBITS        : SetWindowPosFlags = SetWindowPosFlags(SWP_NOCOPYBITS);
-pub const NOOWNERZORDER     : SetWindowPosFlags = SetWindowPosFlags(SWP_NOOWNERZORDER);
-pub const NOSENDCHANGING    : SetWindowPosFlags = SetWindowPosFlags(SWP_NOSENDCHANGING);
-pub const DRAWFRAME         : SetWindowPosFlags = SetWindowPosFlags(SWP_DRAWFRAME);
-pub const NOREPOSITION      : SetWindowPosFlags = SetWindowPosFlags(SWP_NOREPOSITION);
-pub const DEFERERASE        : SetWindowPosFlags = SetWindowPosFlags(SWP_DEFERERASE);
-pub const ASYNCWINDOWPOS    : SetWindowPosFlags = SetWindowPosFlags(SWP_ASYNCWINDOWPOS);
+const _ : () = { // crates/xtask/src/bin/data/swp.rs must agree with winapi
+    assert!(SWP_NOSIZE         == 0x00000001);
+    assert!(SWP_NOMOVE         == 0x00000002);
+    assert!(SWP_NOZORDER       == 0x00000004);
+    assert!(SWP_NOREDRAW       == 0x00000008);
+    assert!(SWP_NOACTIVATE     == 0x00000010);
+    assert!(SWP_FRAMECHANGED   == 0x00000020);
+    assert!(SWP_SHOWWINDOW     == 0x00000040);
+    assert!(SWP_HIDEWINDOW     == 0x00000080);
+    assert!(SWP_NOCOPYBITS     == 0x00000100);
+    assert!(SWP_NOOWNERZORDER  == 0x00000200);
+    assert!(SWP_NOSENDCHANGING == 0x00000400);
+    assert!(SWP_DRAWFRAME      == 0x00000020);
+    assert!(SWP_NOREPOSITION   == 0x00000200);
+    assert!(SWP_DEFERERASE     == 0x00002000);
+    assert!(SWP_ASYNCWINDOWPOS == 0x00004000);
+};
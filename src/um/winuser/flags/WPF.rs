@@ -1,3 +1,4 @@
+// WARNING: this file is auto-generated by xtask gen and may be overwritten: edit crates/xtask/src/bin/data/wpf.rs instead
 //! \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-windowplacement)\]
 //! WPF_\* flags for [WindowPlacement]
 
@@ -25,8 +26,27 @@ impl_debug_for_flags! {
     }
 }
 
+impl_from_str_for_flags! {
+    WindowPlacementFlags => {
+        WPF::SETMINPOSITION,
+        WPF::RESTORETOMAXIMIZED,
+        WPF::ASYNCWINDOWPLACEMENT,
+    }
+}
+
+impl_validate_for_flags! {
+    WindowPlacementFlags => {
+    }
+}
+
+
 
+pub const SETMINPOSITION       : WindowPlacementFlags = WindowPlacementFlags(WPF_SETMINPOSITION);
+pub const RESTORETOMAXIMIZED   : WindowPlacementFlags = WindowPlacementFlags(WPF_RESTORETOMAXIMIZED);
+pub const ASYNCWINDOWPLACEMENT : WindowPlacementFlags = WindowPlacementFlags(WPF_ASYNCWINDOWPLACEMENT);
 
-pub const SETMINPOSITION        : WindowPlacementFlags = WindowPlacementFlags(WPF_SETMINPOSITION);
-pub const RESTORETOMAXIMIZED    : WindowPlacementFlags = WindowPlacementFlags(WPF_RESTORETOMAXIMIZED);
-pub const ASYNCWINDOWPLACEMENT  : WindowPlacementFlags = WindowPlacementFlags(WPF_ASYNCWINDOWPLACEMENT);
+const _ : () = { // crates/xtask/src/bin/data/wpf.rs must agree with winapi
+    assert!(WPF_SETMINPOSITION       == 0x00000001);
+    assert!(WPF_RESTORETOMAXIMIZED   == 0x00000002);
+    assert!(WPF_ASYNCWINDOWPLACEMENT == 0x00000004);
+};
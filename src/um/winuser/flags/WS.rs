@@ -1,3 +1,4 @@
+// WARNING: this file is auto-generated by xtask gen and may be overwritten: edit crates/xtask/src/bin/data/ws.rs instead
 //! \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/window-styles)\]
 //! WS_\* window style flags for [create_window_a] etc.
 
@@ -19,11 +20,41 @@ impl From<u32> for WindowStyle { fn from(cmd: u32        ) -> Self { Self(cmd) }
 
 impl_debug_for_flags! {
     WindowStyle => {
-        // Aggregate styles
-        WS::OVERLAPPEDWINDOW,   // OVERLAPPED | CAPTION | SYSMENU | THICKFRAME | MINIMIZEBOX | MAXIMIZEBOX
+        WS::OVERLAPPEDWINDOW,   // CAPTION | SYSMENU | THICKFRAME | MINIMIZEBOX | MAXIMIZEBOX
         WS::POPUPWINDOW,        // POPUP | BORDER | SYSMENU
         WS::CAPTION,            // BORDER | DLGFRAME
+        WS::OVERLAPPED,
+        WS::POPUP,
+        WS::CHILD,
+        WS::MINIMIZE,
+        WS::VISIBLE,
+        WS::DISABLED,
+        WS::CLIPSIBLINGS,
+        WS::CLIPCHILDREN,
+        WS::MAXIMIZE,
+        WS::BORDER,
+        WS::DLGFRAME,
+        WS::VSCROLL,
+        WS::HSCROLL,
+        WS::SYSMENU,
+        WS::THICKFRAME,
+        //WS::GROUP,            // overlaps with WS::MINIMIZEBOX
+        //WS::TABSTOP,          // overlaps with WS::MAXIMIZEBOX
+        WS::MINIMIZEBOX,
+        WS::MAXIMIZEBOX,
+        //WS::TILED,            // alias for WS::OVERLAPPED
+        //WS::ICONIC,           // alias for WS::MINIMIZE
+        //WS::SIZEBOX,          // alias for WS::THICKFRAME
+        //WS::TILEDWINDOW,      // alias for WS::OVERLAPPEDWINDOW
+        //WS::CHILDWINDOW,      // alias for WS::CHILD
+    }
+}
 
+impl_from_str_for_flags! {
+    WindowStyle => {
+        WS::OVERLAPPEDWINDOW,
+        WS::POPUPWINDOW,
+        WS::CAPTION,
         WS::OVERLAPPED,
         WS::POPUP,
         WS::CHILD,
@@ -39,44 +70,80 @@ impl_debug_for_flags! {
         WS::HSCROLL,
         WS::SYSMENU,
         WS::THICKFRAME,
-        //WS::GROUP,        // overlaps with WS::MINIMIZEBOX
-        //WS::TABSTOP,      // overlaps with WS::MAXIMIZEBOX
+        WS::GROUP,
+        WS::TABSTOP,
         WS::MINIMIZEBOX,
         WS::MAXIMIZEBOX,
-        //WS::TILED,        // alias for WS::OVERLAPPED
-        //WS::ICONIC,       // alias for WS::MINIMIZE
-        //WS::SIZEBOX,      // alias for WS::THICKFRAME
-        //WS::TILEDWINDOW,  // alias for WS::OVERLAPPEDWINDOW
-        //WS::CHILDWINDOW,  // alias for WS::CHILD
+        WS::TILED,
+        WS::ICONIC,
+        WS::SIZEBOX,
+        WS::TILEDWINDOW,
+        WS::CHILDWINDOW,
+    }
+}
+
+impl_validate_for_flags! {
+    WindowStyle => {
+        WS::CHILD | WS::POPUP => "WS_CHILD cannot be used with the WS_POPUP style",
     }
 }
 
 
 
-pub const OVERLAPPED        : WindowStyle = WindowStyle(WS_OVERLAPPED);
-pub const POPUP             : WindowStyle = WindowStyle(WS_POPUP);
-pub const CHILD             : WindowStyle = WindowStyle(WS_CHILD);
-pub const MINIMIZE          : WindowStyle = WindowStyle(WS_MINIMIZE);
-pub const VISIBLE           : WindowStyle = WindowStyle(WS_VISIBLE);
-pub const DISABLED          : WindowStyle = WindowStyle(WS_DISABLED);
-pub const CLIPSIBLINGS      : WindowStyle = WindowStyle(WS_CLIPSIBLINGS);
-pub const CLIPCHILDREN      : WindowStyle = WindowStyle(WS_CLIPCHILDREN);
-pub const MAXIMIZE          : WindowStyle = WindowStyle(WS_MAXIMIZE);
-pub const CAPTION           : WindowStyle = WindowStyle(WS_CAPTION);
-pub const BORDER            : WindowStyle = WindowStyle(WS_BORDER);
-pub const DLGFRAME          : WindowStyle = WindowStyle(WS_DLGFRAME);
-pub const VSCROLL           : WindowStyle = WindowStyle(WS_VSCROLL);
-pub const HSCROLL           : WindowStyle = WindowStyle(WS_HSCROLL);
-pub const SYSMENU           : WindowStyle = WindowStyle(WS_SYSMENU);
-pub const THICKFRAME        : WindowStyle = WindowStyle(WS_THICKFRAME);
-pub const GROUP             : WindowStyle = WindowStyle(WS_GROUP);
-pub const TABSTOP           : WindowStyle = WindowStyle(WS_TABSTOP);
-pub const MINIMIZEBOX       : WindowStyle = WindowStyle(WS_MINIMIZEBOX);
-pub const MAXIMIZEBOX       : WindowStyle = WindowStyle(WS_MAXIMIZEBOX);
-pub const TILED             : WindowStyle = WindowStyle(WS_TILED);
-pub const ICONIC            : WindowStyle = WindowStyle(WS_ICONIC);
-pub const SIZEBOX           : WindowStyle = WindowStyle(WS_SIZEBOX);
-pub const TILEDWINDOW       : WindowStyle = WindowStyle(WS_TILEDWINDOW);
-pub const OVERLAPPEDWINDOW  : WindowStyle = WindowStyle(WS_OVERLAPPEDWINDOW);
-pub const POPUPWINDOW       : WindowStyle = WindowStyle(WS_POPUPWINDOW);
-pub const CHILDWINDOW       : WindowStyle = WindowStyle(WS_CHILDWINDOW);
+pub const OVERLAPPEDWINDOW : WindowStyle = WindowStyle(WS_OVERLAPPEDWINDOW);
+pub const POPUPWINDOW      : WindowStyle = WindowStyle(WS_POPUPWINDOW);
+pub const CAPTION          : WindowStyle = WindowStyle(WS_CAPTION);
+pub const OVERLAPPED       : WindowStyle = WindowStyle(WS_OVERLAPPED);
+pub const POPUP            : WindowStyle = WindowStyle(WS_POPUP);
+pub const CHILD            : WindowStyle = WindowStyle(WS_CHILD);
+pub const MINIMIZE         : WindowStyle = WindowStyle(WS_MINIMIZE);
+pub const VISIBLE          : WindowStyle = WindowStyle(WS_VISIBLE);
+pub const DISABLED         : WindowStyle = WindowStyle(WS_DISABLED);
+pub const CLIPSIBLINGS     : WindowStyle = WindowStyle(WS_CLIPSIBLINGS);
+pub const CLIPCHILDREN     : WindowStyle = WindowStyle(WS_CLIPCHILDREN);
+pub const MAXIMIZE         : WindowStyle = WindowStyle(WS_MAXIMIZE);
+pub const BORDER           : WindowStyle = WindowStyle(WS_BORDER);
+pub const DLGFRAME         : WindowStyle = WindowStyle(WS_DLGFRAME);
+pub const VSCROLL          : WindowStyle = WindowStyle(WS_VSCROLL);
+pub const HSCROLL          : WindowStyle = WindowStyle(WS_HSCROLL);
+pub const SYSMENU          : WindowStyle = WindowStyle(WS_SYSMENU);
+pub const THICKFRAME       : WindowStyle = WindowStyle(WS_THICKFRAME);
+pub const GROUP            : WindowStyle = WindowStyle(WS_GROUP);
+pub const TABSTOP          : WindowStyle = WindowStyle(WS_TABSTOP);
+pub const MINIMIZEBOX      : WindowStyle = WindowStyle(WS_MINIMIZEBOX);
+pub const MAXIMIZEBOX      : WindowStyle = WindowStyle(WS_MAXIMIZEBOX);
+pub const TILED            : WindowStyle = WindowStyle(WS_TILED);
+pub const ICONIC           : WindowStyle = WindowStyle(WS_ICONIC);
+pub const SIZEBOX          : WindowStyle = WindowStyle(WS_SIZEBOX);
+pub const TILEDWINDOW      : WindowStyle = WindowStyle(WS_TILEDWINDOW);
+pub const CHILDWINDOW      : WindowStyle = WindowStyle(WS_CHILDWINDOW);
+
+const _ : () = { // crates/xtask/src/bin/data/ws.rs must agree with winapi
+    assert!(WS_OVERLAPPEDWINDOW == 0x00CF0000);
+    assert!(WS_POPUPWINDOW      == 0x80880000);
+    assert!(WS_CAPTION          == 0x00C00000);
+    assert!(WS_OVERLAPPED       == 0x00000000);
+    assert!(WS_POPUP            == 0x80000000);
+    assert!(WS_CHILD            == 0x40000000);
+    assert!(WS_MINIMIZE         == 0x20000000);
+    assert!(WS_VISIBLE          == 0x10000000);
+    assert!(WS_DISABLED         == 0x08000000);
+    assert!(WS_CLIPSIBLINGS     == 0x04000000);
+    assert!(WS_CLIPCHILDREN     == 0x02000000);
+    assert!(WS_MAXIMIZE         == 0x01000000);
+    assert!(WS_BORDER           == 0x00800000);
+    assert!(WS_DLGFRAME         == 0x00400000);
+    assert!(WS_VSCROLL          == 0x00200000);
+    assert!(WS_HSCROLL          == 0x00100000);
+    assert!(WS_SYSMENU          == 0x00080000);
+    assert!(WS_THICKFRAME       == 0x00040000);
+    assert!(WS_GROUP            == 0x00020000);
+    assert!(WS_TABSTOP          == 0x00010000);
+    assert!(WS_MINIMIZEBOX      == 0x00020000);
+    assert!(WS_MAXIMIZEBOX      == 0x00010000);
+    assert!(WS_TILED            == 0x00000000);
+    assert!(WS_ICONIC           == 0x20000000);
+    assert!(WS_SIZEBOX          == 0x00040000);
+    assert!(WS_TILEDWINDOW      == 0x00CF0000);
+    assert!(WS_CHILDWINDOW      == 0x40000000);
+};
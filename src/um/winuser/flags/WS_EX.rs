@@ -1,3 +1,4 @@
+// WARNING: this file is auto-generated by xtask gen and may be overwritten: edit crates/xtask/src/bin/data/ws_ex.rs instead
 //! \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/extended-window-styles)\]
 //! WS_EX_\* extended window style flags for [create_window_ex_a] etc.
 
@@ -30,11 +31,43 @@ impl_debug_for_flags! {
         WS_EX::CLIENTEDGE,
         WS_EX::CONTEXTHELP,
         WS_EX::RIGHT,
-        //WS_EX::LEFT,              // 0 / default
+        //WS_EX::LEFT,                // 0 / default
         WS_EX::RTLREADING,
-        //WS_EX::LTRREADING,        // 0 / default
+        //WS_EX::LTRREADING,          // 0 / default
         WS_EX::LEFTSCROLLBAR,
-        //WS_EX::RIGHTSCROLLBAR,    // 0 / default
+        //WS_EX::RIGHTSCROLLBAR,      // 0 / default
+        WS_EX::CONTROLPARENT,
+        WS_EX::STATICEDGE,
+        WS_EX::APPWINDOW,
+        WS_EX::OVERLAPPEDWINDOW,      // WINDOWEDGE | CLIENTEDGE
+        WS_EX::PALETTEWINDOW,         // TOPMOST | TOOLWINDOW | WINDOWEDGE
+        WS_EX::LAYERED,
+        WS_EX::NOINHERITLAYOUT,
+        WS_EX::NOREDIRECTIONBITMAP,
+        WS_EX::LAYOUTRTL,
+        WS_EX::COMPOSITED,
+        WS_EX::NOACTIVATE,
+    }
+}
+
+impl_from_str_for_flags! {
+    WindowStyleExtended => {
+        WS_EX::DLGMODALFRAME,
+        WS_EX::NOPARENTNOTIFY,
+        WS_EX::TOPMOST,
+        WS_EX::ACCEPTFILES,
+        WS_EX::TRANSPARENT,
+        WS_EX::MDICHILD,
+        WS_EX::TOOLWINDOW,
+        WS_EX::WINDOWEDGE,
+        WS_EX::CLIENTEDGE,
+        WS_EX::CONTEXTHELP,
+        WS_EX::RIGHT,
+        WS_EX::LEFT,
+        WS_EX::RTLREADING,
+        WS_EX::LTRREADING,
+        WS_EX::LEFTSCROLLBAR,
+        WS_EX::RIGHTSCROLLBAR,
         WS_EX::CONTROLPARENT,
         WS_EX::STATICEDGE,
         WS_EX::APPWINDOW,
@@ -49,13 +82,21 @@ impl_debug_for_flags! {
     }
 }
 
+impl_validate_for_flags! {
+    WindowStyleExtended => {
+    }
+}
+
 
 
 pub const DLGMODALFRAME       : WindowStyleExtended = WindowStyleExtended(WS_EX_DLGMODALFRAME);
 pub const NOPARENTNOTIFY      : WindowStyleExtended = WindowStyleExtended(WS_EX_NOPARENTNOTIFY);
 pub const TOPMOST             : WindowStyleExtended = WindowStyleExtended(WS_EX_TOPMOST);
 pub const ACCEPTFILES         : WindowStyleExtended = WindowStyleExtended(WS_EX_ACCEPTFILES);
+
+/// Paint after siblings beneath this window. Combined with [LAYERED], mouse input also passes through (see [set_click_through].)
 pub const TRANSPARENT         : WindowStyleExtended = WindowStyleExtended(WS_EX_TRANSPARENT);
+
 pub const MDICHILD            : WindowStyleExtended = WindowStyleExtended(WS_EX_MDICHILD);
 pub const TOOLWINDOW          : WindowStyleExtended = WindowStyleExtended(WS_EX_TOOLWINDOW);
 pub const WINDOWEDGE          : WindowStyleExtended = WindowStyleExtended(WS_EX_WINDOWEDGE);
@@ -72,10 +113,42 @@ pub const STATICEDGE          : WindowStyleExtended = WindowStyleExtended(WS_EX_
 pub const APPWINDOW           : WindowStyleExtended = WindowStyleExtended(WS_EX_APPWINDOW);
 pub const OVERLAPPEDWINDOW    : WindowStyleExtended = WindowStyleExtended(WS_EX_OVERLAPPEDWINDOW);
 pub const PALETTEWINDOW       : WindowStyleExtended = WindowStyleExtended(WS_EX_PALETTEWINDOW);
+
+/// Invisible until [set_layered_window_attributes] or [update_layered_window] is called.
 pub const LAYERED             : WindowStyleExtended = WindowStyleExtended(WS_EX_LAYERED);
+
 pub const NOINHERITLAYOUT     : WindowStyleExtended = WindowStyleExtended(WS_EX_NOINHERITLAYOUT);
 pub const NOREDIRECTIONBITMAP : WindowStyleExtended = WindowStyleExtended(WS_EX_NOREDIRECTIONBITMAP);
 pub const LAYOUTRTL           : WindowStyleExtended = WindowStyleExtended(WS_EX_LAYOUTRTL);
 pub const COMPOSITED          : WindowStyleExtended = WindowStyleExtended(WS_EX_COMPOSITED);
 pub const NOACTIVATE          : WindowStyleExtended = WindowStyleExtended(WS_EX_NOACTIVATE);
 
+const _ : () = { // crates/xtask/src/bin/data/ws_ex.rs must agree with winapi
+    assert!(WS_EX_DLGMODALFRAME       == 0x00000001);
+    assert!(WS_EX_NOPARENTNOTIFY      == 0x00000004);
+    assert!(WS_EX_TOPMOST             == 0x00000008);
+    assert!(WS_EX_ACCEPTFILES         == 0x00000010);
+    assert!(WS_EX_TRANSPARENT         == 0x00000020);
+    assert!(WS_EX_MDICHILD            == 0x00000040);
+    assert!(WS_EX_TOOLWINDOW          == 0x00000080);
+    assert!(WS_EX_WINDOWEDGE          == 0x00000100);
+    assert!(WS_EX_CLIENTEDGE          == 0x00000200);
+    assert!(WS_EX_CONTEXTHELP         == 0x00000400);
+    assert!(WS_EX_RIGHT               == 0x00001000);
+    assert!(WS_EX_LEFT                == 0x00000000);
+    assert!(WS_EX_RTLREADING          == 0x00002000);
+    assert!(WS_EX_LTRREADING          == 0x00000000);
+    assert!(WS_EX_LEFTSCROLLBAR       == 0x00004000);
+    assert!(WS_EX_RIGHTSCROLLBAR      == 0x00000000);
+    assert!(WS_EX_CONTROLPARENT       == 0x00010000);
+    assert!(WS_EX_STATICEDGE          == 0x00020000);
+    assert!(WS_EX_APPWINDOW           == 0x00040000);
+    assert!(WS_EX_OVERLAPPEDWINDOW    == 0x00000300);
+    assert!(WS_EX_PALETTEWINDOW       == 0x00000188);
+    assert!(WS_EX_LAYERED             == 0x00080000);
+    assert!(WS_EX_NOINHERITLAYOUT     == 0x00100000);
+    assert!(WS_EX_NOREDIRECTIONBITMAP == 0x00200000);
+    assert!(WS_EX_LAYOUTRTL           == 0x00400000);
+    assert!(WS_EX_COMPOSITED          == 0x02000000);
+    assert!(WS_EX_NOACTIVATE          == 0x08000000);
+};
@@ -29,6 +29,7 @@ use winapi::um::winuser::*;
 /// ```
 pub fn adjust_window_rect(rect: &mut impl AsMut<Rect>, style: impl Into<WindowStyle>, menu: impl Into<bool>) -> Result<(), Error> {
     fn_context!(adjust_window_rect => AdjustWindowRect);
+    debug_poison_last_error();
     fn_succeeded!(unsafe { AdjustWindowRect(rect.as_mut().as_mut(), style.into().into(), menu.into() as BOOL) })
 }
 
@@ -85,6 +86,7 @@ pub fn adjust_window_rect_copy(rect: impl Into<Rect>, style: impl Into<WindowSty
 /// ```
 pub fn adjust_window_rect_ex(rect: &mut impl AsMut<Rect>, style: impl Into<WindowStyle>, menu: impl Into<bool>, ex_style: impl Into<WindowStyleExtended>) -> Result<(), Error> {
     fn_context!(adjust_window_rect_ex => AdjustWindowRectEx);
+    debug_poison_last_error();
     fn_succeeded!(unsafe { AdjustWindowRectEx(rect.as_mut().as_mut(), style.into().into(), menu.into() as BOOL, ex_style.into().into()) })
 }
 
@@ -129,6 +131,8 @@ pub fn adjust_window_rect_ex_copy(rect: impl Into<Rect>, style: impl Into<Window
 ///
 /// Calculates the outer window size required for a given client area.
 ///
+/// Before Windows 10 1607, falls back on [AdjustWindowRectEx](adjust_window_rect_ex), which uses the system DPI's metrics (ignoring `dpi`.)
+///
 /// ### Arguments
 /// *   `rect`      **In:** The desired client area.  **Out:** the required outer area.
 /// *   `style`     The [window style](https://learn.microsoft.com/en-us/windows/win32/winmsg/window-styles) of the window in question.
@@ -149,7 +153,8 @@ pub fn adjust_window_rect_ex_copy(rect: impl Into<Rect>, style: impl Into<Window
 /// ```
 pub fn adjust_window_rect_ex_for_dpi(rect: &mut impl AsMut<Rect>, style: impl Into<WindowStyle>, menu: impl Into<bool>, ex_style: impl Into<WindowStyleExtended>, dpi: u32) -> Result<(), Error> {
     fn_context!(adjust_window_rect_ex_for_dpi => AdjustWindowRectExForDpi);
-    fn_succeeded!(unsafe { AdjustWindowRectExForDpi(rect.as_mut().as_mut(), style.into().into(), menu.into() as BOOL, ex_style.into().into(), dpi) })
+    debug_poison_last_error();
+    User32::get().adjust_window_rect_ex_for_dpi(rect.as_mut().as_mut(), style.into().into(), menu.into(), ex_style.into().into(), dpi).map_err(|e| e.with_context(FN_CONTEXT))
 }
 
 /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-adjustwindowrectexfordpi)\]
@@ -157,6 +162,8 @@ pub fn adjust_window_rect_ex_for_dpi(rect: &mut impl AsMut<Rect>, style: impl In
 ///
 /// Calculates the outer window size required for a given client area.
 ///
+/// Before Windows 10 1607, falls back on [AdjustWindowRectEx](adjust_window_rect_ex), which uses the system DPI's metrics (ignoring `dpi`.)
+///
 /// ### Arguments
 /// *   `rect`      The desired client area.
 /// *   `style`     The [window style](https://learn.microsoft.com/en-us/windows/win32/winmsg/window-styles) of the window in question.
@@ -0,0 +1,46 @@
+use crate::*;
+use winapi::um::winuser::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-beginpaint)\]
+/// BeginPaint
+///
+/// Prepares `hwnd` for painting, returning a [PaintGuard] which calls EndPaint when dropped.
+/// Call this in response to [WM::PAINT] (and only then): the update region is validated once the guard is dropped.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  if `hwnd` is invalid, or belongs to another thread
+/// *   [ERROR::DC_NOT_FOUND]           if no display device context is available
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// unsafe extern "system" fn wndproc(hwnd: HWnd, msg: WM32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+///     if msg == WM::PAINT {
+///         if let Ok(paint) = begin_paint(hwnd) {
+///             let (hdc, rect) = (paint.hdc(), paint.paint_rect());
+///             // ...draw to `hdc` within `rect`...
+///         } // EndPaint
+///         return 0;
+///     }
+///     unsafe { def_window_proc_w(hwnd, msg, wparam, lparam) }
+/// }
+/// ```
+///
+/// ### See Also
+/// *   [invalidate_rect]   &mdash; request a [WM::PAINT]
+/// *   [update_window]     &mdash; send a pending [WM::PAINT] immediately
+pub fn begin_paint(hwnd: impl TryInto<HWnd>) -> Result<PaintGuard, Error> {
+    fn_context!(begin_paint => BeginPaint);
+    debug_poison_last_error();
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?;
+    let mut ps : PAINTSTRUCT = unsafe { std::mem::zeroed() };
+    clear_last_error();
+    let hdc = unsafe { BeginPaint(hwnd.into(), &mut ps) };
+    if hdc.is_null() {
+        let err = fn_error_gle!();
+        return Err(if err == ERROR::SUCCESS { fn_error!(ERROR::DC_NOT_FOUND) } else { err });
+    }
+    Ok(unsafe { PaintGuard::from_raw(hwnd, ps) })
+}
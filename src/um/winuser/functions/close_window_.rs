@@ -34,6 +34,7 @@ use winapi::um::winuser::*;
 /// ```
 pub fn close_window(hwnd: impl TryInto<HWnd>) -> Result<(), Error> {
     fn_context!(close_window => CloseWindow);
+    debug_poison_last_error();
     let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     fn_succeeded!(unsafe { CloseWindow(hwnd) })
 }
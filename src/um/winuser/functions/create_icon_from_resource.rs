@@ -0,0 +1,130 @@
+use crate::*;
+use crate::ico::*;
+use winapi::um::wingdi::{GetDeviceCaps, BITSPIXEL, PLANES};
+use winapi::um::winuser::*;
+use std::ptr::null_mut;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createiconfromresourceex)\]
+/// CreateIconFromResourceEx(resource, resource.len(), kind == Icon, 0x00030000, cx, cy, lr)
+///
+/// Creates an icon or cursor from a single image's bytes (e.g. [ico::IconDir::image], or an `RT_ICON` / `RT_CURSOR` resource.)
+/// Cursor resources must start with their hotspot (two little-endian `u16`s), which [ico::IconDir::image] doesn't include: prefer [create_icon_from_ico] for `.cur` files.
+/// If `cx` and `cy` are `0`, the image's own size is used, unless [LR::DEFAULTSIZE] is specified, in which case the system icon / cursor size is used.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]  if `resource` is empty or larger than 4 GiB, if `cx` / `cy` exceed [i32::MAX], or if `lr` contains [LR::SHARED]
+/// *   [ERROR::INVALID_DATA]       if `resource` isn't a valid image (on some versions of Windows)
+///
+/// ### See Also
+/// *   [create_icon_from_ico]  &mdash; pick and create the best image from a whole `.ico` / `.cur` file
+pub fn create_icon_from_resource_ex(resource: &[u8], kind: IconKind, cx: u32, cy: u32, lr: impl Into<LR::LoadImageFlags>) -> Result<OwnedIcon, Error> {
+    fn_context!(create_icon_from_resource_ex => CreateIconFromResourceEx);
+    debug_poison_last_error();
+    let lr = lr.into();
+    if lr.has_all(LR::SHARED) { return Err(fn_param_error!(lr, ERROR::INVALID_PARAMETER)) }
+    if resource.is_empty() { return Err(fn_param_error!(resource, ERROR::INVALID_PARAMETER)) }
+    let size = u32::try_from(resource.len()).map_err(|_| fn_param_error!(resource, ERROR::INVALID_PARAMETER))?;
+    let cx = i32::try_from(cx).map_err(|_| fn_param_error!(cx, ERROR::INVALID_PARAMETER))?;
+    let cy = i32::try_from(cy).map_err(|_| fn_param_error!(cy, ERROR::INVALID_PARAMETER))?;
+    clear_last_error();
+    // SAFETY: despite the `PBYTE`, `presbits` is only read
+    let hicon = unsafe { CreateIconFromResourceEx(resource.as_ptr().cast_mut(), size, (kind == IconKind::Icon) as _, 0x00030000, cx, cy, lr.into()) };
+    if hicon.is_null() {
+        let err = fn_error_gle!();
+        return Err(if err == ERROR::SUCCESS { fn_error!(ERROR::INVALID_DATA) } else { err });
+    }
+    Ok(unsafe { OwnedIcon::from_raw(hicon) })
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createiconfromresourceex)\]
+/// CreateIconFromResourceEx
+///
+/// Creates an icon or cursor from the image in a whole `.ico` or `.cur` file that's closest to `cx` by `cy` pixels and the display's color depth (see [ico::IconDir::best].)
+/// If `cx` and `cy` are `0`, the first image's size is used, unless [LR::DEFAULTSIZE] is specified, in which case the system icon / cursor size is used.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_DATA]       if `data` isn't a valid `.ico` or `.cur` file
+/// *   [ERROR::INVALID_PARAMETER]  if `cx` / `cy` exceed [i32::MAX], or if `lr` contains [LR::SHARED]
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # let ico = [
+/// #     &[0u8, 0, 1, 0, 1, 0][..],
+/// #     &[1, 1, 0, 0, 1, 0, 32, 0], &(48u32).to_le_bytes(), &(22u32).to_le_bytes(),
+/// #     &[40, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 32, 0], &[0; 24], &[0xFF, 0xFF, 0x00, 0xFF], &[0; 4],
+/// # ].concat();
+/// // let ico = std::fs::read("app.ico").unwrap();
+/// let icon = create_icon_from_ico(&ico, 16, 16, LR::DEFAULTCOLOR).unwrap();
+/// assert_eq!(ERROR::INVALID_DATA, create_icon_from_ico(b"not an icon", 16, 16, LR::DEFAULTCOLOR).unwrap_err());
+/// ```
+pub fn create_icon_from_ico(data: &[u8], cx: u32, cy: u32, lr: impl Into<LR::LoadImageFlags>) -> Result<OwnedIcon, Error> {
+    fn_context!(create_icon_from_ico => CreateIconFromResourceEx);
+    let lr = lr.into();
+    let dir = IconDir::parse(data).map_err(|_| fn_param_error!(data, ERROR::INVALID_DATA))?;
+    let (bx, by) = match (cx, cy, dir.kind()) {
+        (0, 0, IconKind::Icon)   if lr.has_all(LR::DEFAULTSIZE) => unsafe { (GetSystemMetrics(SM_CXICON),   GetSystemMetrics(SM_CYICON)) },
+        (0, 0, IconKind::Cursor) if lr.has_all(LR::DEFAULTSIZE) => unsafe { (GetSystemMetrics(SM_CXCURSOR), GetSystemMetrics(SM_CYCURSOR)) },
+        (cx, cy, _) => (cx as i32, cy as i32),
+    };
+    let entry = dir.best(bx.max(0) as u32, by.max(0) as u32, lookup_depth(lr)).ok_or(fn_param_error!(data, ERROR::INVALID_DATA))?;
+    match entry.hotspot {
+        None => create_icon_from_resource_ex(dir.image(entry), dir.kind(), cx, cy, lr),
+        Some(hotspot) => {
+            let mut resource = Vec::with_capacity(4 + dir.image(entry).len());
+            resource.extend_from_slice(&(hotspot.x as u16).to_le_bytes());
+            resource.extend_from_slice(&(hotspot.y as u16).to_le_bytes());
+            resource.extend_from_slice(dir.image(entry));
+            create_icon_from_resource_ex(&resource, dir.kind(), cx, cy, lr)
+        },
+    }
+}
+
+/// The bits per pixel `LookupIconIdFromDirectoryEx` matches images against: `1` for [LR::MONOCHROME], otherwise the display's.
+fn lookup_depth(lr: LR::LoadImageFlags) -> u16 {
+    if lr.has_all(LR::MONOCHROME) { return 1 }
+    let hdc = unsafe { GetDC(null_mut()) };
+    if hdc.is_null() { return 32 }
+    let bits = unsafe { GetDeviceCaps(hdc, BITSPIXEL) * GetDeviceCaps(hdc, PLANES) };
+    unsafe { ReleaseDC(null_mut(), hdc) };
+    u16::try_from(bits).ok().filter(|b| *b != 0).unwrap_or(32)
+}
+
+
+
+#[test] fn best_matches_lookup_icon_id_from_directory_ex() {
+    let shell32 = load_library_as_resource_w(abistr::cstr16!("shell32.dll")).unwrap();
+    let groups = (1 ..= 300u16).filter_map(|id| find_resource_ex_w(&shell32, RT::GROUP_ICON, id, 0).ok()).take(16).map(|g| load_resource(g).unwrap()).collect::<Vec<_>>();
+    assert!(groups.len() >= 8, "expected shell32.dll to have plenty of icons");
+
+    for group in groups {
+        // GRPICONDIR → .ico: same header, but ICONDIRENTRYs end with the image's offset instead of its RT_ICON id
+        let count = usize::from(u16::from_le_bytes([group[4], group[5]]));
+        let entries = (0 .. count).map(|i| &group[6 + 14 * i .. 6 + 14 * (i + 1)]).collect::<Vec<_>>();
+        let ids = entries.iter().map(|e| u16::from_le_bytes([e[12], e[13]])).collect::<Vec<_>>();
+        let images = ids.iter().map(|id| load_resource(find_resource_ex_w(&shell32, RT::ICON, *id, 0).unwrap()).unwrap()).collect::<Vec<_>>();
+        let mut ico = group[..6].to_vec();
+        let mut offset = 6 + 16 * count;
+        for (e, image) in entries.iter().zip(images.iter()) {
+            ico.extend_from_slice(&e[..8]);
+            ico.extend_from_slice(&(image.len() as u32).to_le_bytes());
+            ico.extend_from_slice(&(offset as u32).to_le_bytes());
+            offset += image.len();
+        }
+        for image in images.iter() { ico.extend_from_slice(image) }
+        let dir = ico::IconDir::parse(&ico).unwrap();
+
+        for lr in [LR::DEFAULTCOLOR, LR::MONOCHROME] {
+            for (cx, cy) in [(0, 0), (16, 16), (20, 20), (24, 24), (30, 30), (32, 32), (36, 36), (40, 40), (48, 48), (64, 64), (96, 96), (128, 128), (256, 256), (300, 300), (16, 32), (48, 0)] {
+                // SAFETY: despite the `PBYTE`, `presbits` is only read
+                let expected = unsafe { LookupIconIdFromDirectoryEx(group.as_ptr().cast_mut(), 1, cx as _, cy as _, lr.into()) };
+                let best = dir.best(cx, cy, lookup_depth(lr)).unwrap();
+                let actual = dir.entries().iter().position(|e| e == best).map(|i| i32::from(ids[i]));
+                assert_eq!(Some(expected), actual, "{cx}x{cy} {lr:?} from {:?}", dir.entries());
+            }
+        }
+    }
+}
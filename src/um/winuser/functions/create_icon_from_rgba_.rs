@@ -0,0 +1,80 @@
+use crate::*;
+use winapi::um::wingdi::*;
+use winapi::um::winuser::*;
+use std::ptr::null_mut;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createiconindirect)\]
+/// CreateIconIndirect
+///
+/// Creates an icon (or, if `hotspot` is [Some], a cursor) from `width * height` top-down, straight (non-premultiplied) alpha RGBA pixels.
+///
+/// The colors are copied into a 32-bit DIB section, with an AND mask (see [pixels::and_mask]) marking fully transparent pixels.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]  if `width` or `height` is `0`, if `rgba.len() != width * height * 4`, or if `hotspot` is out of bounds
+/// *   [ERROR::NOT_ENOUGH_MEMORY]  if the bitmaps couldn't be allocated
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// # use winresult::*;
+/// // a 32x32 red square with a transparent border
+/// let rgba = (0 .. 32 * 32).flat_map(|i| {
+///     let (x, y) = (i % 32, i / 32);
+///     if (4 .. 28).contains(&x) && (4 .. 28).contains(&y) { [0xFF, 0, 0, 0xFF] } else { [0; 4] }
+/// }).collect::<Vec<u8>>();
+///
+/// let icon    = create_icon_from_rgba(32, 32, &rgba, None).unwrap();
+/// let cursor  = create_icon_from_rgba(32, 32, &rgba, Some(Point { x: 16, y: 16 })).unwrap();
+///
+/// assert_eq!(ERROR::INVALID_PARAMETER, create_icon_from_rgba( 0,  0, &[],          None).unwrap_err());
+/// assert_eq!(ERROR::INVALID_PARAMETER, create_icon_from_rgba(32, 32, &rgba[4..],   None).unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [OwnedIcon]     &mdash; the returned icon, destroyed when dropped
+/// *   [load_icon_w]   &mdash; load a system or resource icon instead
+pub fn create_icon_from_rgba(width: u32, height: u32, rgba: &[u8], hotspot: Option<Point>) -> Result<OwnedIcon, Error> {
+    fn_context!(create_icon_from_rgba => CreateIconIndirect);
+    debug_poison_last_error();
+    let (w, h) = (width as usize, height as usize);
+    if w == 0 || w > i32::MAX as usize { return Err(fn_param_error!(width,  ERROR::INVALID_PARAMETER)) }
+    if h == 0 || h > i32::MAX as usize { return Err(fn_param_error!(height, ERROR::INVALID_PARAMETER)) }
+    if w.checked_mul(h).and_then(|n| n.checked_mul(4)) != Some(rgba.len()) { return Err(fn_param_error!(rgba, ERROR::INVALID_PARAMETER)) }
+    if let Some(pt) = hotspot { if !(0 .. width as i64).contains(&pt.x.into()) || !(0 .. height as i64).contains(&pt.y.into()) { return Err(fn_param_error!(hotspot, ERROR::INVALID_PARAMETER)) } }
+
+    let mut bmi : BITMAPINFO = unsafe { std::mem::zeroed() };
+    bmi.bmiHeader = BITMAPINFOHEADER {
+        biSize:         size_of_32::<BITMAPINFOHEADER>(),
+        biWidth:        width as _,
+        biHeight:       -(height as i32), // top-down
+        biPlanes:       1,
+        biBitCount:     32,
+        biCompression:  BI_RGB,
+        .. unsafe { std::mem::zeroed() }
+    };
+    let mut bits = null_mut();
+    let color = OwnedBitmap(unsafe { CreateDIBSection(null_mut(), &bmi, DIB_RGB_COLORS, &mut bits, null_mut(), 0) });
+    if color.0.is_null() || bits.is_null() { return Err(fn_error!(ERROR::NOT_ENOUGH_MEMORY)) }
+    // SAFETY: a top-down 32bpp DIB section has exactly `width * height` unpadded BGRA pixels
+    let bgra = unsafe { std::slice::from_raw_parts_mut(bits.cast::<u8>(), rgba.len()) };
+    bgra.copy_from_slice(rgba);
+    pixels::swap_red_blue(bgra);
+
+    let mask = pixels::and_mask(w, h, rgba);
+    let mask = OwnedBitmap(unsafe { CreateBitmap(width as _, height as _, 1, 1, mask.as_ptr().cast()) });
+    if mask.0.is_null() { return Err(fn_error!(ERROR::NOT_ENOUGH_MEMORY)) }
+
+    let mut info = ICONINFO {
+        fIcon:      hotspot.is_none() as _,
+        xHotspot:   hotspot.map_or(0, |pt| pt.x as _),
+        yHotspot:   hotspot.map_or(0, |pt| pt.y as _),
+        hbmMask:    mask.0,
+        hbmColor:   color.0,
+    };
+    let hicon = unsafe { CreateIconIndirect(&mut info) };
+    fn_succeeded!(!hicon.is_null())?;
+    Ok(unsafe { OwnedIcon::from_raw(hicon) }) // CreateIconIndirect copies the bitmaps, which `OwnedBitmap` deletes
+}
@@ -47,6 +47,7 @@ pub unsafe fn create_window_a<'a>(
     param:          *mut c_void,
 ) -> Result<HWnd, Error> {
     fn_context!(create_window_a => CreateWindowA);
+    debug_poison_last_error();
     let parent      = parent        .try_into().map_err(|_| fn_param_error!(parent,         ERROR::INVALID_WINDOW_HANDLE))?.into();
     let window_name = window_name   .try_into().map_err(|_| fn_param_error!(window_name,    ERROR::INVALID_WINDOW_HANDLE))?;
     let hwnd = unsafe { CreateWindowExA(
@@ -100,6 +101,7 @@ pub unsafe fn create_window_w<'a>(
     param:          *mut c_void,
 ) -> Result<HWnd, Error> {
     fn_context!(create_window_w => CreateWindowW);
+    debug_poison_last_error();
     let parent      = parent        .try_into().map_err(|_| fn_param_error!(parent,         ERROR::INVALID_WINDOW_HANDLE))?.into();
     let window_name = window_name   .try_into().map_err(|_| fn_param_error!(window_name,    ERROR::INVALID_WINDOW_HANDLE))?;
     let hwnd = unsafe { CreateWindowExW(
@@ -154,6 +156,7 @@ pub unsafe fn create_window_ex_a<'a>(
     param:          *mut c_void,
 ) -> Result<HWnd, Error> {
     fn_context!(create_window_ex_a => CreateWindowExA);
+    debug_poison_last_error();
     let parent      = parent        .try_into().map_err(|_| fn_param_error!(parent,         ERROR::INVALID_WINDOW_HANDLE))?.into();
     let window_name = window_name   .try_into().map_err(|_| fn_param_error!(window_name,    ERROR::INVALID_WINDOW_HANDLE))?;
     let hwnd = unsafe { CreateWindowExA(
@@ -208,6 +211,7 @@ pub unsafe fn create_window_ex_w<'a>(
     param:          *mut c_void,
 ) -> Result<HWnd, Error> {
     fn_context!(create_window_ex_w => CreateWindowExW);
+    debug_poison_last_error();
     let parent      = parent        .try_into().map_err(|_| fn_param_error!(parent,         ERROR::INVALID_WINDOW_HANDLE))?.into();
     let window_name = window_name   .try_into().map_err(|_| fn_param_error!(window_name,    ERROR::INVALID_WINDOW_HANDLE))?;
     let hwnd = unsafe { CreateWindowExW(
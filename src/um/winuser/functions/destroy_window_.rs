@@ -68,6 +68,7 @@ use winapi::um::winuser::*;
 /// ```
 pub unsafe fn destroy_window(hwnd: impl TryInto<HWnd>) -> Result<(), Error> {
     fn_context!(destroy_window => DestroyWindow);
+    debug_poison_last_error();
     let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     fn_succeeded!(unsafe { DestroyWindow(hwnd) })
 }
@@ -45,6 +45,7 @@ use winapi::um::winuser::*;
 /// ```
 pub unsafe fn dispatch_message_a(msg: &impl AsRef<Msg>) -> Result<LRESULT, Error> {
     fn_context!(dispatch_message_a => DispatchMessageA);
+    debug_poison_last_error();
     let msg = msg.as_ref().as_ref();
     clear_last_error();
     let lr = unsafe { DispatchMessageA(msg) };
@@ -94,6 +95,7 @@ pub unsafe fn dispatch_message_a(msg: &impl AsRef<Msg>) -> Result<LRESULT, Error
 /// ```
 pub unsafe fn dispatch_message_w(msg: &impl AsRef<Msg>) -> Result<LRESULT, Error> {
     fn_context!(dispatch_message_w => DispatchMessageW);
+    debug_poison_last_error();
     let msg = msg.as_ref().as_ref();
     clear_last_error();
     let lr = unsafe { DispatchMessageW(msg) };
@@ -0,0 +1,157 @@
+use crate::*;
+use abistr::{TryIntoAsOptCStr, AsOptCStr};
+use winapi::um::winuser::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-findwindowa)\]
+/// FindWindowA
+///
+/// Retrieves a handle to the top-level window whose class name and window name match the specified strings.
+/// This function does not search child windows, nor does it perform a case-sensitive search.
+///
+/// ### Returns
+/// *   `Ok(hwnd)`          The first matching window
+/// *   `Ok(HWnd::NULL)`    If no window matched
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]      If `window_name` contains interior `\0`s
+/// *   [ERROR::CANNOT_FIND_WND_CLASS]  If `class_name` isn't a registered window class
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// assert!(find_window_a(abistr::cstr!("Hwnd.Class.That.Should.Not.Exist"), ()).unwrap_or(HWnd::NULL).is_null());
+/// assert!(find_window_a((), abistr::cstr!("Hwnd Window That Should Not Exist")).unwrap().is_null());
+/// ```
+///
+/// ### See Also
+/// *   [find_window_w] (wide variant)
+/// *   [find_window_ex_a]
+pub fn find_window_a<'a>(class_name: impl Into<NameAtomOrZero<'a, u8>>, window_name: impl TryIntoAsOptCStr) -> Result<HWnd, Error> {
+    fn_context!(find_window_a => FindWindowA);
+    debug_poison_last_error();
+    let window_name = window_name.try_into().map_err(|_| fn_param_error!(window_name, ERROR::INVALID_PARAMETER))?;
+    clear_last_error();
+    let hwnd = unsafe { FindWindowA(class_name.into().as_atom_or_cstr_ptr(), window_name.as_opt_cstr()) };
+    if hwnd.is_null() { fn_error_gle_nz!()? }
+    Ok(hwnd.into())
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-findwindoww)\]
+/// FindWindowW
+///
+/// Retrieves a handle to the top-level window whose class name and window name match the specified strings.
+/// This function does not search child windows, nor does it perform a case-sensitive search.
+///
+/// ### Returns
+/// *   `Ok(hwnd)`          The first matching window
+/// *   `Ok(HWnd::NULL)`    If no window matched
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]      If `window_name` contains interior `\0`s
+/// *   [ERROR::CANNOT_FIND_WND_CLASS]  If `class_name` isn't a registered window class
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// assert!(find_window_w(abistr::cstr16!("Hwnd.Class.That.Should.Not.Exist"), ()).unwrap_or(HWnd::NULL).is_null());
+/// assert!(find_window_w((), abistr::cstr16!("Hwnd Window That Should Not Exist")).unwrap().is_null());
+/// ```
+///
+/// ### See Also
+/// *   [find_window_a] (narrow variant)
+/// *   [find_window_ex_w]
+pub fn find_window_w<'a>(class_name: impl Into<NameAtomOrZero<'a, u16>>, window_name: impl TryIntoAsOptCStr<u16>) -> Result<HWnd, Error> {
+    fn_context!(find_window_w => FindWindowW);
+    debug_poison_last_error();
+    let window_name = window_name.try_into().map_err(|_| fn_param_error!(window_name, ERROR::INVALID_PARAMETER))?;
+    clear_last_error();
+    let hwnd = unsafe { FindWindowW(class_name.into().as_atom_or_cstr_ptr(), window_name.as_opt_cstr()) };
+    if hwnd.is_null() { fn_error_gle_nz!()? }
+    Ok(hwnd.into())
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-findwindowexa)\]
+/// FindWindowExA
+///
+/// Retrieves a handle to a window whose class name and window name match the specified strings.
+/// The function searches child windows of `parent`, beginning with the one following `child_after`.
+/// This function does not perform a case-sensitive search.
+///
+/// *   `parent`        [HWnd::NULL] searches top-level windows, [HWnd::MESSAGE] searches message-only windows.
+/// *   `child_after`   [HWnd::NULL] starts the search from the first child of `parent`.
+///
+/// ### Returns
+/// *   `Ok(hwnd)`          The first matching window
+/// *   `Ok(HWnd::NULL)`    If no window matched
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  If `parent` or `child_after` is invalid
+/// *   [ERROR::INVALID_PARAMETER]      If `window_name` contains interior `\0`s
+/// *   [ERROR::CANNOT_FIND_WND_CLASS]  If `class_name` isn't a registered window class
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use std::ptr::*;
+/// let hwnd = unsafe { create_window_a(abistr::cstr!("Message"), abistr::cstr!("find_window_ex_a example"), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()).unwrap() };
+/// assert_eq!(hwnd, find_window_ex_a(HWnd::MESSAGE, HWnd::NULL, abistr::cstr!("Message"), abistr::cstr!("find_window_ex_a example")).unwrap());
+/// ```
+///
+/// ### See Also
+/// *   [find_window_ex_w] (wide variant)
+/// *   [find_window_a]
+pub fn find_window_ex_a<'a>(parent: impl TryInto<HWnd>, child_after: impl TryInto<HWnd>, class_name: impl Into<NameAtomOrZero<'a, u8>>, window_name: impl TryIntoAsOptCStr) -> Result<HWnd, Error> {
+    fn_context!(find_window_ex_a => FindWindowExA);
+    debug_poison_last_error();
+    let parent      = parent        .try_into().map_err(|_| fn_param_error!(parent,         ERROR::INVALID_WINDOW_HANDLE))?.into();
+    let child_after = child_after   .try_into().map_err(|_| fn_param_error!(child_after,    ERROR::INVALID_WINDOW_HANDLE))?.into();
+    let window_name = window_name   .try_into().map_err(|_| fn_param_error!(window_name,    ERROR::INVALID_PARAMETER))?;
+    clear_last_error();
+    let hwnd = unsafe { FindWindowExA(parent, child_after, class_name.into().as_atom_or_cstr_ptr(), window_name.as_opt_cstr()) };
+    if hwnd.is_null() { fn_error_gle_nz!()? }
+    Ok(hwnd.into())
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-findwindowexw)\]
+/// FindWindowExW
+///
+/// Retrieves a handle to a window whose class name and window name match the specified strings.
+/// The function searches child windows of `parent`, beginning with the one following `child_after`.
+/// This function does not perform a case-sensitive search.
+///
+/// *   `parent`        [HWnd::NULL] searches top-level windows, [HWnd::MESSAGE] searches message-only windows.
+/// *   `child_after`   [HWnd::NULL] starts the search from the first child of `parent`.
+///
+/// ### Returns
+/// *   `Ok(hwnd)`          The first matching window
+/// *   `Ok(HWnd::NULL)`    If no window matched
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  If `parent` or `child_after` is invalid
+/// *   [ERROR::INVALID_PARAMETER]      If `window_name` contains interior `\0`s
+/// *   [ERROR::CANNOT_FIND_WND_CLASS]  If `class_name` isn't a registered window class
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use std::ptr::*;
+/// let hwnd = unsafe { create_window_w(abistr::cstr16!("Message"), abistr::cstr16!("find_window_ex_w example"), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()).unwrap() };
+/// assert_eq!(hwnd, find_window_ex_w(HWnd::MESSAGE, HWnd::NULL, abistr::cstr16!("Message"), abistr::cstr16!("find_window_ex_w example")).unwrap());
+/// ```
+///
+/// ### See Also
+/// *   [find_window_ex_a] (narrow variant)
+/// *   [find_window_w]
+pub fn find_window_ex_w<'a>(parent: impl TryInto<HWnd>, child_after: impl TryInto<HWnd>, class_name: impl Into<NameAtomOrZero<'a, u16>>, window_name: impl TryIntoAsOptCStr<u16>) -> Result<HWnd, Error> {
+    fn_context!(find_window_ex_w => FindWindowExW);
+    debug_poison_last_error();
+    let parent      = parent        .try_into().map_err(|_| fn_param_error!(parent,         ERROR::INVALID_WINDOW_HANDLE))?.into();
+    let child_after = child_after   .try_into().map_err(|_| fn_param_error!(child_after,    ERROR::INVALID_WINDOW_HANDLE))?.into();
+    let window_name = window_name   .try_into().map_err(|_| fn_param_error!(window_name,    ERROR::INVALID_PARAMETER))?;
+    clear_last_error();
+    let hwnd = unsafe { FindWindowExW(parent, child_after, class_name.into().as_atom_or_cstr_ptr(), window_name.as_opt_cstr()) };
+    if hwnd.is_null() { fn_error_gle_nz!()? }
+    Ok(hwnd.into())
+}
@@ -0,0 +1,30 @@
+use crate::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdpiforwindow)\]
+/// GetDpiForWindow
+///
+/// Returns the DPI of `hwnd`, which depends on its [DPI awareness](set_thread_dpi_awareness_context) (e.g. `96` for DPI unaware windows, or the DPI of the monitor for per-monitor aware windows.)
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  if `hwnd` is null or invalid
+/// *   [ERROR::CALL_NOT_IMPLEMENTED]   before Windows 10 1607
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::null_mut;
+/// let hwnd = unsafe { create_window_w(abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+/// match get_dpi_for_window(hwnd) {
+///     Ok(dpi) => assert!(dpi >= 96),
+///     Err(err) => assert_eq!(err, ERROR::CALL_NOT_IMPLEMENTED),
+/// }
+/// # unsafe { destroy_window(hwnd) }.unwrap();
+/// ```
+pub fn get_dpi_for_window(hwnd: impl TryInto<HWnd>) -> Result<u32, Error> {
+    fn_context!(get_dpi_for_window => GetDpiForWindow);
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?;
+    User32::get().get_dpi_for_window(hwnd.into()).map_err(|e| e.with_context(FN_CONTEXT))
+}
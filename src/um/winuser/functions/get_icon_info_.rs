@@ -0,0 +1,54 @@
+use crate::*;
+use winapi::um::wingdi::*;
+use winapi::um::winuser::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-geticoninfo)\]
+/// GetIconInfo + GetObjectW
+///
+/// Get an icon's (or cursor's) type, hotspot, size, and color depth.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_CURSOR_HANDLE]  if `hicon` isn't a valid icon or cursor
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// # use winresult::*;
+/// let icon = create_icon_from_rgba(24, 16, &[0xFF; 24 * 16 * 4], None).unwrap();
+/// let info = get_icon_info(&icon).unwrap();
+/// assert!(info.is_icon);
+/// assert_eq!((info.width, info.height, info.bit_count), (24, 16, 32));
+///
+/// let cursor = create_icon_from_rgba(32, 32, &[0xFF; 32 * 32 * 4], Some(Point { x: 3, y: 4 })).unwrap();
+/// let info = get_icon_info(cursor.as_hcursor()).unwrap();
+/// assert!(!info.is_icon);
+/// assert_eq!(info.hotspot, Point { x: 3, y: 4 });
+///
+/// let arrow = load_cursor_w((), IDC::ARROW).unwrap();
+/// assert!(!get_icon_info(arrow).unwrap().is_icon);
+///
+/// let bad = unsafe { HIcon::from_unchecked(!42usize as _) };
+/// assert_eq!(ERROR::INVALID_CURSOR_HANDLE, get_icon_info(bad).unwrap_err());
+/// ```
+pub fn get_icon_info<'a>(hicon: impl Into<HIcon<'a>>) -> Result<IconInfo, Error> {
+    fn_context!(get_icon_info => GetIconInfo);
+    debug_poison_last_error();
+    let mut info : ICONINFO = unsafe { std::mem::zeroed() };
+    fn_succeeded!(unsafe { GetIconInfo(hicon.into().into(), &mut info) })?;
+    let (mask, color) = (OwnedBitmap(info.hbmMask), OwnedBitmap(info.hbmColor));
+
+    let (bitmap, monochrome) = if color.0.is_null() { (&mask, true) } else { (&color, false) };
+    let mut bm : BITMAP = unsafe { std::mem::zeroed() };
+    let n = unsafe { GetObjectW(bitmap.0.cast(), size_of_32::<BITMAP>() as _, (&mut bm as *mut BITMAP).cast()) };
+    if n == 0 { return Err(fn_error!(ERROR::INVALID_CURSOR_HANDLE)) }
+
+    Ok(IconInfo {
+        is_icon:    info.fIcon != 0,
+        hotspot:    Point { x: info.xHotspot as _, y: info.yHotspot as _ },
+        width:      bm.bmWidth as _,
+        height:     if monochrome { bm.bmHeight / 2 } else { bm.bmHeight } as _, // monochrome masks are AND mask atop XOR mask
+        bit_count:  if monochrome { 1 } else { bm.bmBitsPixel },
+    })
+}
@@ -52,6 +52,7 @@ use winapi::um::winuser::*;
 /// ```
 pub fn get_message_a(msg: &mut impl AsMut<Msg>, hwnd: impl Into<HWnd>, min: impl Into<WM32>, max: impl Into<WM32>) -> Result<bool, Error> {
     fn_context!(get_message_a => GetMessageA);
+    debug_poison_last_error();
     let msg = msg.as_mut().as_mut();
     let hwnd = hwnd.into();
     let min : u32 = min.into().into();
@@ -120,6 +121,7 @@ pub fn get_message_a(msg: &mut impl AsMut<Msg>, hwnd: impl Into<HWnd>, min: impl
 /// ```
 pub fn get_message_w(msg: &mut impl AsMut<Msg>, hwnd: impl Into<HWnd>, min: impl Into<WM32>, max: impl Into<WM32>) -> Result<bool, Error> {
     fn_context!(get_message_w => GetMessageW);
+    debug_poison_last_error();
     let msg = msg.as_mut().as_mut();
     let hwnd = hwnd.into();
     let min : u32 = min.into().into();
@@ -0,0 +1,44 @@
+use crate::*;
+use bytemuck::Zeroable;
+use winapi::um::winuser::GetUpdateRect;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getupdaterect)\]
+/// GetUpdateRect
+///
+/// Retrieves the bounds of `hwnd`'s update region (in client coordinates), or [None] if it's empty.
+/// If `erase` is `true`, [WM::ERASEBKGND] is sent first for any part of the update region that needs its background erased.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  if `hwnd` is invalid
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # // visible: a hidden window's update region is always empty
+/// # let hwnd = unsafe { create_window_a(abistr::cstr!("Static"), (), WS::POPUP | WS::VISIBLE, 0, 0, 64, 64, HWnd::NULL, null_mut(), None, null_mut()).unwrap() };
+/// validate_rect(hwnd, None).unwrap();
+/// assert_eq!(None, get_update_rect(hwnd, false).unwrap());
+///
+/// invalidate_rect(hwnd, Some(&Rect { left: 0, top: 0, right: 16, bottom: 16 }), false).unwrap();
+/// let dirty = get_update_rect(hwnd, false).unwrap().expect("just invalidated");
+/// assert_eq!(dirty, Rect { left: 0, top: 0, right: 16, bottom: 16 });
+///
+/// redraw_window(hwnd, Some(&dirty), RDW::UPDATENOW).unwrap(); // paints (and validates) immediately
+/// assert_eq!(None, get_update_rect(hwnd, false).unwrap());
+/// # unsafe { destroy_window(hwnd) }.unwrap();
+/// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, get_update_rect(!42usize as HWND, false).unwrap_err());
+/// ```
+pub fn get_update_rect(hwnd: impl TryInto<HWnd>, erase: bool) -> Result<Option<Rect>, Error> {
+    fn_context!(get_update_rect => GetUpdateRect);
+    debug_poison_last_error();
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
+    let mut rect = Rect::zeroed();
+    clear_last_error();
+    if unsafe { GetUpdateRect(hwnd, rect.as_mut(), erase.into()) } != 0 { return Ok(Some(rect)) }
+    fn_error_gle_nz!()?;
+    Ok(None)
+}
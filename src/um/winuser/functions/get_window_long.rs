@@ -45,6 +45,7 @@ use winapi::um::winuser::*;
 /// *   [get_window_long_ptr_w]
 pub fn get_window_long_a(hwnd: impl TryInto<HWnd>, index: impl Into<GetWindowLongIndex>) -> Result<isize, Error> {
     fn_context!(get_window_long_a => GetWindowLongA);
+    debug_poison_last_error();
     let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     let index = index.into().into();
     clear_last_error(); // GetWindowLongA might return 0 without clearing the error
@@ -94,6 +95,7 @@ pub fn get_window_long_a(hwnd: impl TryInto<HWnd>, index: impl Into<GetWindowLon
 /// *   [get_window_long_ptr_w]
 pub fn get_window_long_w(hwnd: impl TryInto<HWnd>, index: impl Into<GetWindowLongIndex>) -> Result<isize, Error> {
     fn_context!(get_window_long_w => GetWindowLongW);
+    debug_poison_last_error();
     let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     let index = index.into().into();
     clear_last_error(); // GetWindowLongW might return 0 without clearing the error
@@ -44,6 +44,7 @@ use winapi::um::winuser::*;
 /// *   [get_window_long_ptr_w]
 pub fn get_window_long_ptr_a(hwnd: impl TryInto<HWnd>, index: impl Into<GetWindowLongPtrIndex>) -> Result<isize, Error> {
     fn_context!(get_window_long_ptr_a => GetWindowLongPtrA);
+    debug_poison_last_error();
     let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     let index = index.into().into();
     clear_last_error(); // GetWindowLongPtrA might return 0 without clearing the error
@@ -92,6 +93,7 @@ pub fn get_window_long_ptr_a(hwnd: impl TryInto<HWnd>, index: impl Into<GetWindo
 /// *   [get_window_long_ptr_a]
 pub fn get_window_long_ptr_w(hwnd: impl TryInto<HWnd>, index: impl Into<GetWindowLongPtrIndex>) -> Result<isize, Error> {
     fn_context!(get_window_long_ptr_w => GetWindowLongPtrW);
+    debug_poison_last_error();
     let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     let index = index.into().into();
     clear_last_error(); // GetWindowLongPtrW might return 0 without clearing the error
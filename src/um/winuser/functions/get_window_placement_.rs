@@ -31,6 +31,7 @@ use bytemuck::Zeroable;
 /// *   [set_window_pos]
 pub fn get_window_placement(hwnd: impl TryInto<HWnd>) -> Result<WindowPlacement, Error> {
     fn_context!(get_window_placement => GetWindowPlacement);
+    debug_poison_last_error();
     let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     let mut wndpl = WindowPlacement::zeroed();
     wndpl.length = size_of_32::<WindowPlacement>();
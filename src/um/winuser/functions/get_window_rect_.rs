@@ -44,6 +44,7 @@ use winapi::um::winuser::GetWindowRect;
 /// *   [set_window_pos]
 pub fn get_window_rect(hwnd: impl TryInto<HWnd>) -> Result<Rect, Error> {
     fn_context!(get_window_rect => GetWindowRect);
+    debug_poison_last_error();
     let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     let mut rect = Rect::zeroed();
     fn_succeeded!(unsafe { GetWindowRect(hwnd, rect.as_mut()) })?;
@@ -33,6 +33,7 @@ use std::os::windows::ffi::*;
 /// *   [get_window_text_length_w]
 pub fn get_window_text_length_a(hwnd: impl TryInto<HWnd>) -> Result<usize, Error> {
     fn_context!(get_window_text_length_a => GetWindowTextLengthA);
+    debug_poison_last_error();
     let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     clear_last_error();
     let n = unsafe { GetWindowTextLengthA(hwnd) };
@@ -67,6 +68,7 @@ pub fn get_window_text_length_a(hwnd: impl TryInto<HWnd>) -> Result<usize, Error
 /// *   [get_window_text_length_a]
 pub fn get_window_text_length_w(hwnd: impl TryInto<HWnd>) -> Result<usize, Error> {
     fn_context!(get_window_text_length_w => GetWindowTextLengthW);
+    debug_poison_last_error();
     let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     clear_last_error();
     let n = unsafe { GetWindowTextLengthW(hwnd) };
@@ -83,8 +85,7 @@ pub fn get_window_text_length_w(hwnd: impl TryInto<HWnd>) -> Result<usize, Error
 /// However, testing reveals it works fine on at least the desktop window.
 ///
 /// ### Errors
-/// *   ~~[ERROR::INVALID_WINDOW_HANDLE]  If `hwnd` is invalid... *sometimes*.~~
-/// *   May succeed with 0 length despite `hwnd` being invalid!
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  If `hwnd` is invalid
 ///
 /// ### Example
 /// ```
@@ -99,7 +100,7 @@ pub fn get_window_text_length_w(hwnd: impl TryInto<HWnd>) -> Result<usize, Error
 /// assert_eq!(b"Title", bytemuck::cast_slice::<c_char, u8>(title));
 /// #
 /// # assert!(get_window_text_a(get_desktop_window(), title).unwrap().is_empty());
-/// # assert!(get_window_text_a(!42usize as HWND,     title).unwrap().is_empty());
+/// # assert_eq!(ERROR::INVALID_WINDOW_HANDLE, get_window_text_a(!42usize as HWND, title).unwrap_err());
 /// ```
 ///
 /// ### See Also
@@ -107,11 +108,12 @@ pub fn get_window_text_length_w(hwnd: impl TryInto<HWnd>) -> Result<usize, Error
 /// *   [get_window_text_os]
 pub fn get_window_text_a(hwnd: impl TryInto<HWnd>, buffer: &mut [c_char]) -> Result<&mut [c_char], Error> {
     fn_context!(get_window_text_a => GetWindowTextA);
+    debug_poison_last_error();
     let hwnd    = hwnd  .try_into().map_err(|_| fn_param_error!(hwnd,   ERROR::INVALID_WINDOW_HANDLE))?.into();
     let n : i32 = buffer.len().try_into().unwrap_or(std::i32::MAX);
     clear_last_error(); // read is 0 on error... or on empty string
     let read = unsafe { GetWindowTextA(hwnd, buffer.as_mut_ptr(), n) };
-    if n == 0 { fn_error_gle_nz!()? }
+    if read == 0 { fn_error_gle_nz!()? }
     debug_assert!(read <= n);
     Ok(&mut buffer[..read as usize])
 }
@@ -125,8 +127,7 @@ pub fn get_window_text_a(hwnd: impl TryInto<HWnd>, buffer: &mut [c_char]) -> Res
 /// However, testing reveals it works fine on at least the desktop window.
 ///
 /// ### Errors
-/// *   ~~[ERROR::INVALID_WINDOW_HANDLE]  If `hwnd` is invalid... *sometimes*.~~
-/// *   May succeed with 0 length despite `hwnd` being invalid!
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  If `hwnd` is invalid
 ///
 /// ### Example
 /// ```
@@ -140,7 +141,7 @@ pub fn get_window_text_a(hwnd: impl TryInto<HWnd>, buffer: &mut [c_char]) -> Res
 /// assert_eq!(abistr::cstr16!("Title").to_units(), title);
 /// #
 /// # assert!(get_window_text_w(get_desktop_window(), title).unwrap().is_empty());
-/// # assert!(get_window_text_w(!42usize as HWND,     title).unwrap().is_empty());
+/// # assert_eq!(ERROR::INVALID_WINDOW_HANDLE, get_window_text_w(!42usize as HWND, title).unwrap_err());
 /// ```
 ///
 /// ### See Also
@@ -148,11 +149,12 @@ pub fn get_window_text_a(hwnd: impl TryInto<HWnd>, buffer: &mut [c_char]) -> Res
 /// *   [get_window_text_os]
 pub fn get_window_text_w(hwnd: impl TryInto<HWnd>, buffer: &mut [u16]) -> Result<&mut [u16], Error> {
     fn_context!(get_window_text_w => GetWindowTextW);
+    debug_poison_last_error();
     let hwnd    = hwnd  .try_into().map_err(|_| fn_param_error!(hwnd,   ERROR::INVALID_WINDOW_HANDLE))?.into();
     let n : i32 = buffer.len().try_into().unwrap_or(std::i32::MAX);
     clear_last_error(); // read is 0 on error... or on empty string
     let read = unsafe { GetWindowTextW(hwnd, buffer.as_mut_ptr(), n) };
-    if n == 0 { fn_error_gle_nz!()? }
+    if read == 0 { fn_error_gle_nz!()? }
     debug_assert!(read <= n);
     Ok(&mut buffer[..read as usize])
 }
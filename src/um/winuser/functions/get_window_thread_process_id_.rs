@@ -35,6 +35,7 @@ use winapi::um::winuser::*;
 /// *   [get_window_process_id]
 #[must_use] pub fn get_window_thread_id(hwnd: impl Into<HWnd>) -> Result<u32, Error> {
     fn_context!(get_window_thread_id => GetWindowThreadProcessId);
+    debug_poison_last_error();
     let hwnd = hwnd.into().into();
     let tid = unsafe { GetWindowThreadProcessId(hwnd, std::ptr::null_mut()) };
     if tid != 0 { Ok(tid) } else { Err(fn_error_gle!()) }
@@ -72,6 +73,7 @@ use winapi::um::winuser::*;
 /// *   [get_window_thread_process_id]
 #[must_use] pub fn get_window_process_id(hwnd: impl Into<HWnd>) -> Result<u32, Error> {
     fn_context!(get_window_process_id => GetWindowThreadProcessId);
+    debug_poison_last_error();
     let hwnd = hwnd.into().into();
     let mut pid = 0;
     let tid = unsafe { GetWindowThreadProcessId(hwnd, &mut pid) };
@@ -110,6 +112,7 @@ use winapi::um::winuser::*;
 /// *   [get_window_thread_id]
 #[must_use] pub fn get_window_thread_process_id(hwnd: impl Into<HWnd>) -> Result<(u32, u32), Error> {
     fn_context!(get_window_thread_process_id => GetWindowThreadProcessId);
+    debug_poison_last_error();
     let hwnd = hwnd.into().into();
     let mut pid = 0;
     let tid = unsafe { GetWindowThreadProcessId(hwnd, &mut pid) };
@@ -84,6 +84,11 @@ pub fn in_send_message_ex(_reserved: impl Into<Option<std::convert::Infallible>>
     InSendMessageExFlags::from(unsafe { InSendMessageEx(null_mut()) })
 }
 
+impl InSendMessageExFlags {
+    // Per <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-insendmessageex#remarks>
+    pub fn is_sender_blocked(self) -> bool { self & (ISMEX::REPLIED | ISMEX::SEND) == ISMEX::SEND }
+}
+
 #[test] fn test_in_send_message_vs_ex() {
     use crate::*;
     use abistr::*;
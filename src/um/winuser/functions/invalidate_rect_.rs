@@ -0,0 +1,66 @@
+use crate::*;
+use winapi::um::winuser::*;
+use std::ptr::null;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-invalidaterect)\]
+/// InvalidateRect
+///
+/// Adds `rect` (or with [None], the whole client area) to `hwnd`'s update region, requesting a [WM::PAINT].
+/// If `erase` is `true`, the background will also be erased by [begin_paint] (via [WM::ERASEBKGND].)
+///
+/// A null `hwnd` invalidates and redraws *every* window on the desktop, not just this application's.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  if `hwnd` is invalid
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_a(abistr::cstr!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()).unwrap() };
+/// invalidate_rect(hwnd, None, true).unwrap();
+/// invalidate_rect(hwnd, Some(&Rect { left: 0, top: 0, right: 16, bottom: 16 }), false).unwrap();
+/// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, invalidate_rect(!42usize as HWND, None, false).unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [validate_rect]
+/// *   [redraw_window]     &mdash; more control over what's invalidated, and when it's repainted
+pub fn invalidate_rect(hwnd: impl TryInto<HWnd>, rect: Option<&Rect>, erase: bool) -> Result<(), Error> {
+    fn_context!(invalidate_rect => InvalidateRect);
+    debug_poison_last_error();
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
+    fn_succeeded!(unsafe { InvalidateRect(hwnd, rect.map_or(null(), |r| r.as_ref()), erase.into()) })
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-validaterect)\]
+/// ValidateRect
+///
+/// Removes `rect` (or with [None], the whole client area) from `hwnd`'s update region, without painting it.
+///
+/// A null `hwnd` invalidates and redraws *every* window on the desktop, not just this application's.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  if `hwnd` is invalid
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_a(abistr::cstr!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()).unwrap() };
+/// invalidate_rect(hwnd, None, false).unwrap();
+/// validate_rect(hwnd, None).unwrap(); // never mind
+/// assert_eq!(None, get_update_rect(hwnd, false).unwrap());
+/// ```
+///
+/// ### See Also
+/// *   [invalidate_rect]
+pub fn validate_rect(hwnd: impl TryInto<HWnd>, rect: Option<&Rect>) -> Result<(), Error> {
+    fn_context!(validate_rect => ValidateRect);
+    debug_poison_last_error();
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
+    fn_succeeded!(unsafe { ValidateRect(hwnd, rect.map_or(null(), |r| r.as_ref())) })
+}
@@ -74,6 +74,7 @@ pub fn is_gui_thread() -> bool {
 /// *   [is_gui_thread]
 pub fn convert_to_gui_thread() -> Result<(), Error> {
     fn_context!(convert_to_gui_thread => IsGUIThread);
+    debug_poison_last_error();
     match unsafe { IsGUIThread(1) } {
         0 => fn_succeeded!(0),
         1 => fn_succeeded!(1),
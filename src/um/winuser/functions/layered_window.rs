@@ -0,0 +1,173 @@
+use crate::*;
+use crate::LWA::LayeredWindowAttributesFlags;
+use winapi::shared::windef::{POINT, SIZE};
+use winapi::um::wingdi::*;
+use winapi::um::winuser::*;
+use std::ptr::null_mut;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setlayeredwindowattributes)\]
+/// SetLayeredWindowAttributes
+///
+/// Sets the opacity (with [LWA::ALPHA]) and/or transparent color key (with [LWA::COLORKEY]) of a [WS_EX::LAYERED] window.
+/// `key` is a `COLORREF` (`0x00BBGGRR`.)
+///
+/// Once called, [update_layered_window] fails until [WS_EX::LAYERED] is cleared and set again.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  if `hwnd` is invalid
+/// *   [ERROR::INVALID_PARAMETER]      if `hwnd` isn't [WS_EX::LAYERED]
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_ex_w(WS_EX::LAYERED, abistr::cstr16!("Message"), (), WS::POPUP, 0, 0, 64, 64, null_mut(), null_mut(), None, null_mut()).unwrap() };
+/// set_layered_window_attributes(hwnd, 0, 0xC0, LWA::ALPHA).unwrap(); // 75% opaque
+/// assert_eq!((0, 0xC0, LWA::ALPHA), get_layered_window_attributes(hwnd).unwrap());
+///
+/// set_layered_window_attributes(hwnd, 0x00FF00FF, 0, LWA::COLORKEY).unwrap(); // magenta pixels are transparent
+/// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, set_layered_window_attributes(!42usize as HWND, 0, 0xFF, LWA::ALPHA).unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [get_layered_window_attributes]
+/// *   [update_layered_window] &mdash; per-pixel alpha instead
+pub fn set_layered_window_attributes(hwnd: impl TryInto<HWnd>, key: u32, alpha: u8, flags: impl Into<LayeredWindowAttributesFlags>) -> Result<(), Error> {
+    fn_context!(set_layered_window_attributes => SetLayeredWindowAttributes);
+    debug_poison_last_error();
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
+    fn_succeeded!(unsafe { SetLayeredWindowAttributes(hwnd, key, alpha, flags.into().into()) })
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getlayeredwindowattributes)\]
+/// GetLayeredWindowAttributes
+///
+/// Retrieves the `(key, alpha, flags)` last passed to [set_layered_window_attributes] for `hwnd`.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  if `hwnd` is invalid
+/// *   [ERROR::INVALID_PARAMETER]      if `hwnd` isn't [WS_EX::LAYERED], or was last updated by [update_layered_window] instead
+pub fn get_layered_window_attributes(hwnd: impl TryInto<HWnd>) -> Result<(u32, u8, LayeredWindowAttributesFlags), Error> {
+    fn_context!(get_layered_window_attributes => GetLayeredWindowAttributes);
+    debug_poison_last_error();
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
+    let (mut key, mut alpha, mut flags) = (0, 0, 0);
+    fn_succeeded!(unsafe { GetLayeredWindowAttributes(hwnd, &mut key, &mut alpha, &mut flags) })?;
+    Ok((key, alpha, flags.into()))
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-updatelayeredwindow)\]
+/// UpdateLayeredWindow(hwnd, nullptr, pos, {width, height}, (DIB section of bgra), {0, 0}, 0, {AC_SRC_OVER, 0, alpha, AC_SRC_ALPHA}, ULW_ALPHA)
+///
+/// Replaces the contents of a [WS_EX::LAYERED] window with `width * height` top-down, **premultiplied** alpha BGRA pixels, resizing it to match.
+/// The window is also moved to `pos` (in screen coordinates) if [Some], and the whole image is blended with opacity `alpha`.
+///
+/// The system keeps its own copy of the pixels: the window is never sent [WM::PAINT], and `bgra` may be freed or reused as soon as this returns.
+/// Fully transparent pixels are click-through.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]      if `width` or `height` is `0`, if `bgra.len() != width * height * 4`, or if `bgra` isn't premultiplied (see [pixels::is_premultiplied])
+/// *   [ERROR::INVALID_PARAMETER]      if `hwnd` isn't [WS_EX::LAYERED], or [set_layered_window_attributes] has been called on it
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  if `hwnd` is invalid
+/// *   [ERROR::NOT_ENOUGH_MEMORY]      if the DIB section couldn't be allocated
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_ex_w(WS_EX::LAYERED, abistr::cstr16!("Message"), (), WS::POPUP, 0, 0, 64, 64, null_mut(), null_mut(), None, null_mut()).unwrap() };
+/// // a 32x32 half transparent orange square, from straight alpha RGBA
+/// let mut bgra = [0xFF, 0x80, 0x00, 0x80].repeat(32 * 32);
+/// pixels::swap_red_blue(&mut bgra);
+/// assert_eq!(ERROR::INVALID_PARAMETER, update_layered_window(hwnd, None, 32, 32, &bgra, 0xFF).unwrap_err());
+///
+/// pixels::premultiply_alpha(&mut bgra);
+/// update_layered_window(hwnd, Some(Point { x: 100, y: 100 }), 32, 32, &bgra, 0xFF).unwrap();
+/// ```
+///
+/// ### See Also
+/// *   [set_layered_window_attributes] &mdash; uniform alpha or color key instead
+/// *   [set_click_through]             &mdash; for overlays
+pub fn update_layered_window(hwnd: impl TryInto<HWnd>, pos: Option<Point>, width: u32, height: u32, bgra: &[u8], alpha: u8) -> Result<(), Error> {
+    fn_context!(update_layered_window => UpdateLayeredWindow);
+    debug_poison_last_error();
+    let (w, h) = (width as usize, height as usize);
+    if w == 0 || w > i32::MAX as usize { return Err(fn_param_error!(width,  ERROR::INVALID_PARAMETER)) }
+    if h == 0 || h > i32::MAX as usize { return Err(fn_param_error!(height, ERROR::INVALID_PARAMETER)) }
+    if w.checked_mul(h).and_then(|n| n.checked_mul(4)) != Some(bgra.len()) { return Err(fn_param_error!(bgra, ERROR::INVALID_PARAMETER)) }
+    if !pixels::is_premultiplied(bgra) { return Err(fn_param_error!(bgra, ERROR::INVALID_PARAMETER)) }
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
+
+    let mut bmi : BITMAPINFO = unsafe { std::mem::zeroed() };
+    bmi.bmiHeader = BITMAPINFOHEADER {
+        biSize:         size_of_32::<BITMAPINFOHEADER>(),
+        biWidth:        width as _,
+        biHeight:       -(height as i32), // top-down
+        biPlanes:       1,
+        biBitCount:     32,
+        biCompression:  BI_RGB,
+        .. unsafe { std::mem::zeroed() }
+    };
+    let mut bits = null_mut();
+    let bitmap = OwnedBitmap(unsafe { CreateDIBSection(null_mut(), &bmi, DIB_RGB_COLORS, &mut bits, null_mut(), 0) });
+    if bitmap.0.is_null() || bits.is_null() { return Err(fn_error!(ERROR::NOT_ENOUGH_MEMORY)) }
+    // SAFETY: a top-down 32bpp DIB section has exactly `width * height` unpadded BGRA pixels
+    unsafe { std::slice::from_raw_parts_mut(bits.cast::<u8>(), bgra.len()) }.copy_from_slice(bgra);
+
+    let dc = OwnedDC(unsafe { CreateCompatibleDC(null_mut()) }); // dropped before `bitmap`, deselecting it
+    if dc.0.is_null() { return Err(fn_error!(ERROR::NOT_ENOUGH_MEMORY)) }
+    let _prev = unsafe { SelectObject(dc.0, bitmap.0.cast()) };
+
+    let mut pos     = pos;
+    let mut size    = SIZE { cx: width as _, cy: height as _ };
+    let mut src     = POINT { x: 0, y: 0 };
+    let mut blend   = BLENDFUNCTION { BlendOp: AC_SRC_OVER, BlendFlags: 0, SourceConstantAlpha: alpha, AlphaFormat: AC_SRC_ALPHA };
+    let pos         = pos.as_mut().map_or(null_mut(), |pos| pos.as_mut());
+    fn_succeeded!(unsafe { UpdateLayeredWindow(hwnd, null_mut(), pos, &mut size, dc.0, &mut src, 0, &mut blend, ULW_ALPHA) })
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/winmsg/extended-window-styles)\]
+/// SetWindowLongW(hwnd, GWL_EXSTYLE, ...)
+///
+/// Sets (or clears) [WS_EX::TRANSPARENT] on `hwnd`, so that mouse input passes through to whatever is beneath it (including other processes' windows.)
+/// Useful for overlays: since hit testing only passes through [WS_EX::LAYERED] windows, enabling this also sets [WS_EX::LAYERED] if needed.
+///
+/// If `hwnd` wasn't already layered, it's made fully opaque with [set_layered_window_attributes] to keep it visible.
+/// Create windows with [WS_EX::LAYERED] up front instead if you intend to use [update_layered_window].
+///
+/// Unlike returning `HTTRANSPARENT` from [WM::NCHITTEST] (which only passes input to other windows of the same thread), this affects the whole window.
+/// Clearing this leaves [WS_EX::LAYERED] (and any layered attributes) in place.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  if `hwnd` is invalid
+/// *   [ERROR::ACCESS_DENIED]          if `hwnd` belongs to another process
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_ex_w(WS_EX::TOPMOST | WS_EX::LAYERED, abistr::cstr16!("Message"), (), WS::POPUP, 0, 0, 64, 64, null_mut(), null_mut(), None, null_mut()).unwrap() };
+/// set_click_through(hwnd, true).unwrap();
+/// # assert_eq!(ERROR::INVALID_WINDOW_HANDLE, set_click_through(!42usize as HWND, true).unwrap_err());
+/// ```
+pub fn set_click_through(hwnd: impl TryInto<HWnd>, click_through: bool) -> Result<(), Error> {
+    fn_context!(set_click_through => SetWindowLongW);
+    debug_poison_last_error();
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
+    clear_last_error(); // GetWindowLongW might return 0 without clearing the error
+    let prev = unsafe { GetWindowLongW(hwnd, GWL_EXSTYLE) };
+    if prev == 0 { fn_error_gle_nz!()?; }
+    let prev = WindowStyleExtended::from(prev as u32);
+    let next = if click_through { prev | WS_EX::LAYERED | WS_EX::TRANSPARENT } else { prev & !WS_EX::TRANSPARENT };
+    if next == prev { return Ok(()) }
+
+    clear_last_error(); // SetWindowLongW returns the previous style, which might be 0
+    if unsafe { SetWindowLongW(hwnd, GWL_EXSTYLE, u32::from(next) as _) } == 0 { fn_error_gle_nz!()?; }
+    if !prev.has_all(WS_EX::LAYERED) { fn_succeeded!(unsafe { SetLayeredWindowAttributes(hwnd, 0, 0xFF, LWA_ALPHA) })?; }
+    Ok(())
+}
@@ -33,6 +33,7 @@ use winapi::um::winuser::*;
 /// *   [load_icon_a]   &mdash; icon equivalent
 pub fn load_cursor_a<'h, 't>(hinstance: impl Into<HInstance<'h>>, cursor_name: impl Into<NameAtomOrZero<'t, u8>>) -> Result<HCursor<'h>, Error> {
     fn_context!(load_cursor_a => LoadCursorA);
+    debug_poison_last_error();
     let hcursor = unsafe { LoadCursorA(hinstance.into().into(), cursor_name.into().as_atom_or_cstr_ptr()) };
     fn_succeeded!(!hcursor.is_null())?;
     Ok(unsafe { HCursor::from_unchecked(hcursor) })
@@ -68,6 +69,7 @@ pub fn load_cursor_a<'h, 't>(hinstance: impl Into<HInstance<'h>>, cursor_name: i
 /// *   [load_icon_w]   &mdash; icon equivalent
 pub fn load_cursor_w<'h, 't>(hinstance: impl Into<HInstance<'h>>, cursor_name: impl Into<NameAtomOrZero<'t, u16>>) -> Result<HCursor<'h>, Error> {
     fn_context!(load_cursor_w => LoadCursorW);
+    debug_poison_last_error();
     let hcursor = unsafe { LoadCursorW(hinstance.into().into(), cursor_name.into().as_atom_or_cstr_ptr()) };
     fn_succeeded!(!hcursor.is_null())?;
     Ok(unsafe { HCursor::from_unchecked(hcursor) })
@@ -33,6 +33,7 @@ use winapi::um::winuser::*;
 /// *   [load_cursor_a] &mdash; cursor equivalent
 pub fn load_icon_a<'h, 't>(hinstance: impl Into<HInstance<'h>>, icon_name: impl Into<NameAtomOrZero<'t, u8>>) -> Result<HIcon<'h>, Error> {
     fn_context!(load_icon_a => LoadIconA);
+    debug_poison_last_error();
     let hicon = unsafe { LoadIconA(hinstance.into().into(), icon_name.into().as_atom_or_cstr_ptr()) };
     fn_succeeded!(!hicon.is_null())?;
     Ok(unsafe { HIcon::from_unchecked(hicon) })
@@ -68,6 +69,7 @@ pub fn load_icon_a<'h, 't>(hinstance: impl Into<HInstance<'h>>, icon_name: impl
 /// *   [load_cursor_w] &mdash; cursor equivalent
 pub fn load_icon_w<'h, 't>(hinstance: impl Into<HInstance<'h>>, icon_name: impl Into<NameAtomOrZero<'t, u16>>) -> Result<HIcon<'h>, Error> {
     fn_context!(load_icon_w => LoadIconW);
+    debug_poison_last_error();
     let hicon = unsafe { LoadIconW(hinstance.into().into(), icon_name.into().as_atom_or_cstr_ptr()) };
     fn_succeeded!(!hicon.is_null())?;
     Ok(unsafe { HIcon::from_unchecked(hicon) })
@@ -0,0 +1,131 @@
+use crate::*;
+use winapi::um::winuser::*;
+use std::os::windows::ffi::OsStrExt;
+use std::path::Path;
+
+
+
+/// Validate `cx`, `cy`, and `lr` against the caller's `FN_CONTEXT`.
+macro_rules! validate { ( $cx:ident, $cy:ident, $lr:expr ) => {{
+    let lr : LR::LoadImageFlags = $lr;
+    if lr.has_all(LR::SHARED) { return Err(fn_param_error!(lr, ERROR::INVALID_PARAMETER)) }
+    let cx = i32::try_from($cx).map_err(|_| fn_param_error!($cx, ERROR::INVALID_PARAMETER))?;
+    let cy = i32::try_from($cy).map_err(|_| fn_param_error!($cy, ERROR::INVALID_PARAMETER))?;
+    (cx, cy, lr)
+}}}
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadimagew)\]
+/// LoadImageW(hinstance, name, IMAGE_ICON, cx, cy, lr)
+///
+/// Loads an icon resource (or with [LR::LOADFROMFILE], an `.ico` file), picking the image closest to `cx` by `cy` pixels.
+/// If `cx` and `cy` are `0`, the first image's size is used, unless [LR::DEFAULTSIZE] is specified, in which case the system icon size is used.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]          if `lr` contains [LR::SHARED] (shared icons are never destroyed: use [load_icon_w] instead), or if `cx` / `cy` exceed [i32::MAX]
+/// *   [ERROR::RESOURCE_DATA_NOT_FOUND]    if `name` cannot be found for `hinstance`
+/// *   [ERROR::FILE_NOT_FOUND]             if `name` cannot be found with [LR::LOADFROMFILE]
+/// *   [ERROR::INVALID_DATA]               if the image is malformed (or fails to load for some other unreported reason)
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// # use winresult::*;
+/// let exe = get_module_handle_entry_exe().unwrap();
+/// assert_eq!(ERROR::RESOURCE_DATA_NOT_FOUND, load_image_icon_w(exe, 42, 32, 32, LR::DEFAULTCOLOR).unwrap_err());
+/// assert_eq!(ERROR::INVALID_PARAMETER,       load_image_icon_w(exe, 42, 32, 32, LR::SHARED).unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [load_icon_from_file_w] &mdash; load an `.ico` file
+/// *   [load_icon_w]           &mdash; load a shared system or resource icon
+pub fn load_image_icon_w<'t>(hinstance: impl Into<HInstance<'t>>, name: impl Into<NameAtomOrZero<'t, u16>>, cx: u32, cy: u32, lr: impl Into<LR::LoadImageFlags>) -> Result<OwnedIcon, Error> {
+    fn_context!(load_image_icon_w => LoadImageW);
+    let (cx, cy, lr) = validate!(cx, cy, lr.into());
+    unsafe { load_image_w(FN_CONTEXT, hinstance.into(), name.into().as_atom_or_cstr_ptr(), IMAGE_ICON, cx, cy, lr) }
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadimagew)\]
+/// LoadImageW(hinstance, name, IMAGE_CURSOR, cx, cy, lr)
+///
+/// Loads a cursor resource (or with [LR::LOADFROMFILE], a `.cur` or animated `.ani` file), picking the image closest to `cx` by `cy` pixels.
+/// If `cx` and `cy` are `0`, the first image's size is used, unless [LR::DEFAULTSIZE] is specified, in which case the system cursor size is used.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]          if `lr` contains [LR::SHARED] (shared cursors are never destroyed: use [load_cursor_w] instead), or if `cx` / `cy` exceed [i32::MAX]
+/// *   [ERROR::RESOURCE_DATA_NOT_FOUND]    if `name` cannot be found for `hinstance`
+/// *   [ERROR::FILE_NOT_FOUND]             if `name` cannot be found with [LR::LOADFROMFILE]
+/// *   [ERROR::INVALID_DATA]               if the image is malformed (or fails to load for some other unreported reason)
+///
+/// ### See Also
+/// *   [load_cursor_from_file_w]   &mdash; load a `.cur` or `.ani` file
+/// *   [load_cursor_w]             &mdash; load a shared system or resource cursor
+pub fn load_image_cursor_w<'t>(hinstance: impl Into<HInstance<'t>>, name: impl Into<NameAtomOrZero<'t, u16>>, cx: u32, cy: u32, lr: impl Into<LR::LoadImageFlags>) -> Result<OwnedIcon, Error> {
+    fn_context!(load_image_cursor_w => LoadImageW);
+    let (cx, cy, lr) = validate!(cx, cy, lr.into());
+    unsafe { load_image_w(FN_CONTEXT, hinstance.into(), name.into().as_atom_or_cstr_ptr(), IMAGE_CURSOR, cx, cy, lr) }
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadimagew)\]
+/// LoadImageW(nullptr, path, IMAGE_ICON, cx, cy, LR_LOADFROMFILE | lr)
+///
+/// Loads an `.ico` file (e.g. shipped next to the exe), picking the image closest to `cx` by `cy` pixels.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]  if `path` contains interior `\0`s, or `lr` contains [LR::SHARED]
+/// *   [ERROR::FILE_NOT_FOUND]     if `path` doesn't exist
+/// *   [ERROR::INVALID_DATA]       if the image is malformed (or fails to load for some other unreported reason)
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// # use winresult::*;
+/// let dir = std::env::current_exe().unwrap().with_file_name("app.ico");
+/// # let dir = std::path::Path::new("nonexistent/app.ico");
+/// match load_icon_from_file_w(dir, 32, 32, LR::DEFAULTCOLOR) {
+///     Ok(icon) => {},
+///     Err(err) => assert!(err == ERROR::FILE_NOT_FOUND || err == ERROR::PATH_NOT_FOUND),
+/// }
+/// ```
+pub fn load_icon_from_file_w(path: impl AsRef<Path>, cx: u32, cy: u32, lr: impl Into<LR::LoadImageFlags>) -> Result<OwnedIcon, Error> {
+    fn_context!(load_icon_from_file_w => LoadImageW);
+    let (cx, cy, lr) = validate!(cx, cy, lr.into());
+    let path = wide_path(path.as_ref()).ok_or(fn_param_error!(path, ERROR::INVALID_PARAMETER))?;
+    unsafe { load_image_w(FN_CONTEXT, ().into(), path.as_ptr(), IMAGE_ICON, cx, cy, lr | LR::LOADFROMFILE) }
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadimagew)\]
+/// LoadImageW(nullptr, path, IMAGE_CURSOR, cx, cy, LR_LOADFROMFILE | lr)
+///
+/// Loads a `.cur` or animated `.ani` file, picking the image closest to `cx` by `cy` pixels.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]  if `path` contains interior `\0`s, or `lr` contains [LR::SHARED]
+/// *   [ERROR::FILE_NOT_FOUND]     if `path` doesn't exist
+/// *   [ERROR::INVALID_DATA]       if the image is malformed (or fails to load for some other unreported reason)
+pub fn load_cursor_from_file_w(path: impl AsRef<Path>, cx: u32, cy: u32, lr: impl Into<LR::LoadImageFlags>) -> Result<OwnedIcon, Error> {
+    fn_context!(load_cursor_from_file_w => LoadImageW);
+    let (cx, cy, lr) = validate!(cx, cy, lr.into());
+    let path = wide_path(path.as_ref()).ok_or(fn_param_error!(path, ERROR::INVALID_PARAMETER))?;
+    unsafe { load_image_w(FN_CONTEXT, ().into(), path.as_ptr(), IMAGE_CURSOR, cx, cy, lr | LR::LOADFROMFILE) }
+}
+
+/// ### Safety
+/// *   `name` must be an atom or a valid `\0`-terminated string
+unsafe fn load_image_w(context: &'static ErrorContext, hinstance: HInstance, name: *const u16, ty: u32, cx: i32, cy: i32, lr: LR::LoadImageFlags) -> Result<OwnedIcon, Error> {
+    clear_last_error();
+    let h = unsafe { LoadImageW(hinstance.into(), name, ty, cx, cy, lr.into()) };
+    if h.is_null() {
+        Error::new_gle_nz().map_err(|e| e.with_context(context))?;
+        return Err(Error::new(ERROR::INVALID_DATA.into()).with_context(context)); // failed without setting an error (e.g. for some malformed images)
+    }
+    Ok(unsafe { OwnedIcon::from_raw(h.cast()) })
+}
+
+fn wide_path(path: &Path) -> Option<Vec<u16>> {
+    let mut wide = path.as_os_str().encode_wide().collect::<Vec<_>>();
+    if wide.contains(&0) { return None }
+    wide.push(0);
+    Some(wide)
+}
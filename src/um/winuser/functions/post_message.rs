@@ -43,6 +43,7 @@ use winapi::um::winuser::*;
 /// ```
 pub unsafe fn post_message_a(hwnd: impl Into<HWnd>, msg: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM) -> Result<(), Error> {
     fn_context!(post_message_a => PostMessageA);
+    debug_poison_last_error();
     fn_succeeded!(unsafe { PostMessageA(hwnd.into().into(), msg.into().into(), wparam, lparam) })
 }
 
@@ -85,6 +86,7 @@ pub unsafe fn post_message_a(hwnd: impl Into<HWnd>, msg: impl Into<WM32>, wparam
 /// ```
 pub unsafe fn post_message_w(hwnd: impl Into<HWnd>, msg: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM) -> Result<(), Error> {
     fn_context!(post_message_w => PostMessageW);
+    debug_poison_last_error();
     fn_succeeded!(unsafe { PostMessageW(hwnd.into().into(), msg.into().into(), wparam, lparam) })
 }
 
@@ -120,6 +122,7 @@ pub unsafe fn post_message_w(hwnd: impl Into<HWnd>, msg: impl Into<WM32>, wparam
 /// ```
 pub unsafe fn post_thread_message_a(thread: u32, msg: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM) -> Result<(), Error> {
     fn_context!(post_thread_message_a => PostThreadMessageA);
+    debug_poison_last_error();
     fn_succeeded!(unsafe { PostThreadMessageA(thread, msg.into().into(), wparam, lparam) })
 }
 
@@ -155,6 +158,7 @@ pub unsafe fn post_thread_message_a(thread: u32, msg: impl Into<WM32>, wparam: W
 /// ```
 pub unsafe fn post_thread_message_w(thread: u32, msg: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM) -> Result<(), Error> {
     fn_context!(post_thread_message_w => PostThreadMessageW);
+    debug_poison_last_error();
     fn_succeeded!(unsafe { PostThreadMessageW(thread, msg.into().into(), wparam, lparam) })
 }
 
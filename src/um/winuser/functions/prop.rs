@@ -0,0 +1,237 @@
+use crate::*;
+use winapi::shared::windef::HWND;
+use winapi::um::winuser::*;
+use std::ffi::c_void;
+use std::fmt::{self, Debug, Formatter};
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setpropw)\]
+/// SetPropW
+///
+/// Adds a new entry or changes an existing entry in the property list of the specified window.
+///
+/// Unlike [assoc::local], this works for windows belonging to other threads of the current process.
+/// Before a window is destroyed (before it returns from [WM::NCDESTROY]), all entries added to its property list must be removed with [remove_prop_w].
+///
+/// ### Safety
+/// *   Other code (other modules, other crates, the window's class) may expect `name`'s property to have a specific meaning (e.g. be a valid pointer.)
+///     Overwriting it with something else may be undefined behavior.
+///     Prefer [assoc::prop::Key] for typed properties.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  If `hwnd` is invalid
+/// *   [ERROR::ACCESS_DENIED]          If `hwnd` belongs to another process
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()).unwrap() };
+/// let name = abistr::cstr16!("hwnd.example.set_prop_w");
+/// unsafe { set_prop_w(hwnd, name, 42 as _) }.unwrap();
+/// assert_eq!(42, get_prop_w(hwnd, name).unwrap() as usize);
+/// assert_eq!(42, unsafe { remove_prop_w(hwnd, name) }.unwrap() as usize);
+///
+/// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, unsafe { set_prop_w(HWnd::NULL, name, 42 as _) }.unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [get_prop_w]
+/// *   [remove_prop_w]
+/// *   [enum_props_ex_w]
+/// *   [assoc::prop::Key]
+pub unsafe fn set_prop_w<'a>(hwnd: impl TryInto<HWnd>, name: impl Into<NameOrAtom<'a, u16>>, data: *mut c_void) -> Result<(), Error> {
+    fn_context!(set_prop_w => SetPropW);
+    debug_poison_last_error();
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
+    fn_succeeded!(unsafe { SetPropW(hwnd, name.into().as_atom_or_cstr_ptr(), data) })
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getpropw)\]
+/// GetPropW
+///
+/// Retrieves a data handle from the property list of the specified window.
+///
+/// ### Returns
+/// *   `Ok(data)`          The data associated with `name`
+/// *   `Ok(null_mut())`    If `name` isn't in the property list (indistinguishable from a property explicitly set to null)
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  If `hwnd` is invalid
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()).unwrap() };
+/// let name = abistr::cstr16!("hwnd.example.get_prop_w");
+/// assert!(get_prop_w(hwnd, name).unwrap().is_null());
+/// unsafe { set_prop_w(hwnd, name, 42 as _) }.unwrap();
+/// assert_eq!(42, get_prop_w(hwnd, name).unwrap() as usize);
+/// # unsafe { remove_prop_w(hwnd, name) }.unwrap();
+///
+/// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, get_prop_w(HWnd::NULL, name).unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [set_prop_w]
+/// *   [remove_prop_w]
+/// *   [enum_props_ex_w]
+pub fn get_prop_w<'a>(hwnd: impl TryInto<HWnd>, name: impl Into<NameOrAtom<'a, u16>>) -> Result<*mut c_void, Error> {
+    fn_context!(get_prop_w => GetPropW);
+    debug_poison_last_error();
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
+    clear_last_error();
+    let data = unsafe { GetPropW(hwnd, name.into().as_atom_or_cstr_ptr()) };
+    if data.is_null() { fn_error_gle_nz!()? }
+    Ok(data)
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-removepropw)\]
+/// RemovePropW
+///
+/// Removes an entry from the property list of the specified window.
+///
+/// ### Safety
+/// *   Other code (other modules, other crates, the window's class) may expect `name`'s property to continue to exist.
+///     Removing it may be undefined behavior.
+///
+/// ### Returns
+/// *   `Ok(data)`          The data that was associated with `name`
+/// *   `Ok(null_mut())`    If `name` wasn't in the property list (indistinguishable from a property explicitly set to null)
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  If `hwnd` is invalid
+/// *   [ERROR::ACCESS_DENIED]          If `hwnd` belongs to another process
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()).unwrap() };
+/// let name = abistr::cstr16!("hwnd.example.remove_prop_w");
+/// unsafe { set_prop_w(hwnd, name, 42 as _) }.unwrap();
+/// assert_eq!(42, unsafe { remove_prop_w(hwnd, name) }.unwrap() as usize);
+/// assert!(unsafe { remove_prop_w(hwnd, name) }.unwrap().is_null());
+///
+/// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, unsafe { remove_prop_w(HWnd::NULL, name) }.unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [set_prop_w]
+/// *   [get_prop_w]
+/// *   [enum_props_ex_w]
+pub unsafe fn remove_prop_w<'a>(hwnd: impl TryInto<HWnd>, name: impl Into<NameOrAtom<'a, u16>>) -> Result<*mut c_void, Error> {
+    fn_context!(remove_prop_w => RemovePropW);
+    debug_poison_last_error();
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
+    clear_last_error();
+    let data = unsafe { RemovePropW(hwnd, name.into().as_atom_or_cstr_ptr()) };
+    if data.is_null() { fn_error_gle_nz!()? }
+    Ok(data)
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumpropsexw)\]
+/// EnumPropsExW
+///
+/// Enumerates all entries in the property list of a window.
+///
+/// The entries are collected up front, so the returned iterator is a snapshot:
+/// it's safe to [set](set_prop_w) or [remove](remove_prop_w) properties while iterating.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  If `hwnd` is invalid
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()).unwrap() };
+/// let name = abistr::cstr16!("hwnd.example.enum_props_ex_w");
+/// unsafe { set_prop_w(hwnd, name, 42 as _) }.unwrap();
+///
+/// let prop = enum_props_ex_w(hwnd).unwrap().find(|p| p.name == name).unwrap();
+/// assert_eq!(42, prop.data as usize);
+/// # unsafe { remove_prop_w(hwnd, name) }.unwrap();
+///
+/// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, enum_props_ex_w(HWnd::NULL).unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [set_prop_w]
+/// *   [get_prop_w]
+/// *   [remove_prop_w]
+pub fn enum_props_ex_w(hwnd: impl TryInto<HWnd>) -> Result<impl Iterator<Item = Prop>, Error> {
+    fn_context!(enum_props_ex_w => EnumPropsExW);
+    debug_poison_last_error();
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
+    let mut props = Vec::<Prop>::new();
+    clear_last_error();
+    // winapi 0.3 declares the wrong (non-Ex) callback type for EnumPropsExW
+    let callback : PROPENUMPROCEXW = Some(enum_props_ex_w_callback);
+    let r = unsafe { EnumPropsExW(hwnd, std::mem::transmute::<PROPENUMPROCEXW, PROPENUMPROCW>(callback), &mut props as *mut Vec<Prop> as LPARAM) };
+    if r == -1 { fn_error_gle_nz!()? }
+    Ok(props.into_iter())
+}
+
+unsafe extern "system" fn enum_props_ex_w_callback(_hwnd: HWND, name: *mut u16, data: winapi::um::winnt::HANDLE, props: usize) -> i32 {
+    let props = unsafe { &mut *(props as *mut Vec<Prop>) };
+    let name = match AtomNonZero::new(name as u16) {
+        Some(atom) if (name as usize) <= 0xFFFF => PropName::Atom(atom), // MAKEINTATOM
+        _ => PropName::String(unsafe { abistr::CStrPtr::<u16>::from_ptr_unbounded(name) }.to_units().to_vec()),
+    };
+    props.push(Prop { name, data });
+    1 // continue
+}
+
+
+
+/// A window property, as enumerated by [enum_props_ex_w].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Prop {
+    pub name:   PropName,
+    pub data:   *mut c_void,
+}
+
+/// The name of a window property, as enumerated by [enum_props_ex_w].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PropName {
+    /// The property was set using an [ATOM](AtomNonZero).
+    Atom(AtomNonZero),
+
+    /// The property was set using a string (units don't include the terminal `\0`.)
+    String(Vec<u16>),
+}
+
+impl PropName {
+    /// Returns `true` if `self` names the same property as `name`.
+    ///
+    /// Note that string names are case insensitive, and strings passed to [set_prop_w] may end up [enumerated](enum_props_ex_w) as atoms.
+    pub fn is<'a>(&self, name: impl Into<NameOrAtom<'a, u16>>) -> bool {
+        let name = name.into();
+        match (self, name.to_atom_nz(), name.to_abistr()) {
+            (PropName::Atom(a),   Some(b), _      ) => *a == b,
+            (PropName::String(a), None,    Some(b)) => a.iter().copied().map(to_upper_ascii).eq(b.to_units().iter().copied().map(to_upper_ascii)),
+            _                                       => false,
+        }
+    }
+}
+
+fn to_upper_ascii(u: u16) -> u16 { if (u16::from(b'a') ..= u16::from(b'z')).contains(&u) { u - 32 } else { u } }
+
+impl Debug for PropName {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            PropName::Atom(atom)    => write!(fmt, "{atom:?}"),
+            PropName::String(s)     => write!(fmt, "{:?}", String::from_utf16_lossy(s)),
+        }
+    }
+}
+
+impl PartialEq<abistr::CStrNonNull<'_, u16>> for PropName { fn eq(&self, other: &abistr::CStrNonNull<'_, u16>) -> bool { self.is(*other) } }
+impl PartialEq<AtomNonZero> for PropName { fn eq(&self, other: &AtomNonZero) -> bool { self.is(*other) } }
@@ -0,0 +1,62 @@
+use crate::*;
+use winapi::um::winuser::*;
+use std::ptr::{null, null_mut};
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-redrawwindow)\]
+/// RedrawWindow(hwnd, rect, nullptr, flags)
+///
+/// Invalidates / validates `rect` (or with [None], the whole client area) of `hwnd`, and optionally repaints it immediately, as controlled by `flags`.
+/// (Update regions (`HRGN`s) aren't supported yet.)
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  if `hwnd` is invalid
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_a(abistr::cstr!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()).unwrap() };
+/// // repaint everything (including the frame and children) before returning
+/// redraw_window(hwnd, None, RDW::INVALIDATE | RDW::ERASE | RDW::FRAME | RDW::ALLCHILDREN | RDW::UPDATENOW).unwrap();
+/// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, redraw_window(!42usize as HWND, None, RDW::INVALIDATE).unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [invalidate_rect]
+/// *   [update_window]
+pub fn redraw_window(hwnd: impl TryInto<HWnd>, rect: Option<&Rect>, flags: impl Into<RDW::RedrawWindowFlags>) -> Result<(), Error> {
+    fn_context!(redraw_window => RedrawWindow);
+    debug_poison_last_error();
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
+    fn_succeeded!(unsafe { RedrawWindow(hwnd, rect.map_or(null(), |r| r.as_ref()), null_mut(), flags.into().into()) })
+}
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-updatewindow)\]
+/// UpdateWindow
+///
+/// If `hwnd`'s update region isn't empty, sends [WM::PAINT] directly to its [WndProc] (bypassing the message queue) before returning.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  if `hwnd` is invalid
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_a(abistr::cstr!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()).unwrap() };
+/// update_window(hwnd).unwrap();
+/// assert_eq!(ERROR::INVALID_WINDOW_HANDLE, update_window(!42usize as HWND).unwrap_err());
+/// ```
+///
+/// ### See Also
+/// *   [redraw_window]     &mdash; with [RDW::UPDATENOW]
+pub fn update_window(hwnd: impl TryInto<HWnd>) -> Result<(), Error> {
+    fn_context!(update_window => UpdateWindow);
+    debug_poison_last_error();
+    let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
+    fn_succeeded!(unsafe { UpdateWindow(hwnd) })
+}
@@ -41,6 +41,7 @@ use winapi::um::winuser::*;
 /// *   [About Window Classes](https://learn.microsoft.com/en-us/windows/win32/winmsg/about-window-classes)
 pub unsafe fn register_class_a(class: &WndClassA) -> Result<AtomNonZero, Error> {
     fn_context!(register_class_a => RegisterClassA);
+    debug_poison_last_error();
     let atom = unsafe { RegisterClassA(class.as_ref()) };
     AtomNonZero::new(atom).ok_or_else(|| fn_error_gle!())
 }
@@ -83,6 +84,7 @@ pub unsafe fn register_class_a(class: &WndClassA) -> Result<AtomNonZero, Error>
 /// *   [About Window Classes](https://learn.microsoft.com/en-us/windows/win32/winmsg/about-window-classes)
 pub unsafe fn register_class_w(class: &WndClassW) -> Result<AtomNonZero, Error> {
     fn_context!(register_class_w => RegisterClassW);
+    debug_poison_last_error();
     let atom = unsafe { RegisterClassW(class.as_ref()) };
     AtomNonZero::new(atom).ok_or_else(|| fn_error_gle!())
 }
@@ -123,9 +125,11 @@ pub unsafe fn register_class_w(class: &WndClassW) -> Result<AtomNonZero, Error>
 /// ```
 ///
 /// ### See Also
+/// *   [RegisteredClass::register_ex_a] &mdash; unregisters the class, and destroys [OwnedIcon]s, when dropped
 /// *   [About Window Classes](https://learn.microsoft.com/en-us/windows/win32/winmsg/about-window-classes)
 pub unsafe fn register_class_ex_a(class: &WndClassExA) -> Result<AtomNonZero, Error> {
     fn_context!(register_class_ex_a => RegisterClassExA);
+    debug_poison_last_error();
     if class.size != size_of_32::<WndClassExW>() { Err(fn_param_error!(class.size, ERROR::INVALID_PARAMETER))? }
     let atom = unsafe { RegisterClassExA(class.as_ref()) };
     AtomNonZero::new(atom).ok_or_else(|| fn_error_gle!())
@@ -166,9 +170,11 @@ pub unsafe fn register_class_ex_a(class: &WndClassExA) -> Result<AtomNonZero, Er
 /// ```
 ///
 /// ### See Also
+/// *   [RegisteredClass::register_ex_w] &mdash; unregisters the class, and destroys [OwnedIcon]s, when dropped
 /// *   [About Window Classes](https://learn.microsoft.com/en-us/windows/win32/winmsg/about-window-classes)
 pub unsafe fn register_class_ex_w(class: &WndClassExW) -> Result<AtomNonZero, Error> {
     fn_context!(register_class_ex_w => RegisterClassExW);
+    debug_poison_last_error();
     if class.size != size_of_32::<WndClassExW>() { Err(fn_param_error!(class.size, ERROR::INVALID_PARAMETER))? }
     let atom = unsafe { RegisterClassExW(class.as_ref()) };
     AtomNonZero::new(atom).ok_or_else(|| fn_error_gle!())
@@ -229,6 +235,7 @@ pub unsafe fn register_class_ex_w(class: &WndClassExW) -> Result<AtomNonZero, Er
 /// *   [About Window Classes](https://learn.microsoft.com/en-us/windows/win32/winmsg/about-window-classes)
 pub unsafe fn unregister_class_a<'t>(class_name: impl Into<NameAtomOrZero<'t, u8>>, hinstance: impl Into<HInstance<'t>>) -> Result<(), Error> {
     fn_context!(unregister_class_a => UnregisterClassA);
+    debug_poison_last_error();
     fn_succeeded!(unsafe { UnregisterClassA(class_name.into().as_atom_or_cstr_ptr(), hinstance.into().into()) })
 }
 
@@ -287,5 +294,6 @@ pub unsafe fn unregister_class_a<'t>(class_name: impl Into<NameAtomOrZero<'t, u8
 /// *   [About Window Classes](https://learn.microsoft.com/en-us/windows/win32/winmsg/about-window-classes)
 pub unsafe fn unregister_class_w<'t>(class_name: impl Into<NameAtomOrZero<'t, u16>>, hinstance: impl Into<HInstance<'t>>) -> Result<(), Error> {
     fn_context!(unregister_class_w => UnregisterClassW);
+    debug_poison_last_error();
     fn_succeeded!(unsafe { UnregisterClassW(class_name.into().as_atom_or_cstr_ptr(), hinstance.into().into()) })
 }
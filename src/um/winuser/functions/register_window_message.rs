@@ -37,6 +37,7 @@ use winapi::um::winuser::*;
 /// ```
 pub fn register_window_message_a(string: impl abistr::TryIntoAsCStr) -> Result<WM32, Error> {
     fn_context!(register_window_message_a => RegisterWindowMessageA);
+    debug_poison_last_error();
     let string = string.try_into().map_err(|_| fn_param_error!(string, ERROR::INVALID_PARAMETER))?;
     let string = string.as_cstr();
     let m = unsafe { RegisterWindowMessageA(string) };
@@ -77,6 +78,7 @@ pub fn register_window_message_a(string: impl abistr::TryIntoAsCStr) -> Result<W
 /// ```
 pub fn register_window_message_w(string: impl abistr::TryIntoAsCStr<u16>) -> Result<WM32, Error> {
     fn_context!(register_window_message_w => RegisterWindowMessageW);
+    debug_poison_last_error();
     let string = string.try_into().map_err(|_| fn_param_error!(string, ERROR::INVALID_PARAMETER))?;
     let string = string.as_cstr();
     let m = unsafe { RegisterWindowMessageW(string) };
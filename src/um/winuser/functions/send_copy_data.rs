@@ -0,0 +1,48 @@
+use crate::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-copydata)\]
+/// SendMessageTimeoutW(target, WM_COPYDATA, sender, &COPYDATASTRUCT { ... }, SMTO_ABORTIFHUNG, timeout)
+///
+/// Sends `bytes` to `target` (possibly in another process) via [WM::COPYDATA], and waits for it to be processed for up to `timeout` milliseconds.
+/// The system marshals `bytes` into the receiving process, where they can be decoded with [CopyData].
+///
+/// ### Returns
+/// *   The `LRESULT` returned by `target`'s wndproc (conventionally nonzero if the data was handled.)
+///
+/// ### Errors
+/// *   [ERROR::INVALID_WINDOW_HANDLE]  If `target` or `sender` is invalid
+/// *   [ERROR::INVALID_PARAMETER]      If `bytes` is 4 GiB or larger
+/// *   [ERROR::TIMEOUT]                `target` didn't respond within `timeout` milliseconds, or is hung.
+/// *   [ERROR::ACCESS_DENIED]          When a message is blocked by [UIPI](https://en.wikipedia.org/wiki/User_Interface_Privilege_Isolation)
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// # use std::ptr::*;
+/// # let hwnd = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()).unwrap() };
+/// // The "Message" class's wndproc ignores WM_COPYDATA, returning 0
+/// assert_eq!(0, send_copy_data(hwnd, HWnd::NULL, 42, b"hello, world", 1000).unwrap());
+///
+/// assert_eq!(
+///     ERROR::INVALID_WINDOW_HANDLE,
+///     send_copy_data(!42usize as HWND, HWnd::NULL, 42, b"hello, world", 1000).unwrap_err(),
+/// );
+/// ```
+///
+/// ### See Also
+/// *   [CopyData] (receiving side)
+/// *   [CopyDataStruct]
+/// *   [send_message_timeout_w]
+/// *   [single_instance]
+pub fn send_copy_data(target: impl TryInto<HWnd>, sender: impl TryInto<HWnd>, data: usize, bytes: &[u8], timeout: u32) -> Result<LRESULT, Error> {
+    fn_context!(send_copy_data => SendMessageTimeoutW);
+    let target  = target.try_into().map_err(|_| fn_param_error!(target, ERROR::INVALID_WINDOW_HANDLE))?;
+    let sender  = sender.try_into().map_err(|_| fn_param_error!(sender, ERROR::INVALID_WINDOW_HANDLE))?;
+    let cds     = CopyDataStruct::new(data, bytes).ok_or(fn_param_error!(bytes, ERROR::INVALID_PARAMETER))?;
+    let sender  : winapi::shared::windef::HWND = sender.into();
+    // SAFETY: WM_COPYDATA is marshaled by the system, and `cds` + `bytes` outlive the (synchronous) send
+    unsafe { send_message_timeout_w(target, WM::COPYDATA, sender as WPARAM, &cds as *const CopyDataStruct as LPARAM, SMTO::ABORTIFHUNG, timeout) }
+}
@@ -45,6 +45,7 @@ use winapi::um::winuser::*;
 /// *   [send_notify_message_a]
 pub unsafe fn send_message_a(hwnd: impl Into<HWnd>, msg: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM) -> Result<LRESULT, Error> {
     fn_context!(send_message_a => SendMessageA);
+    debug_poison_last_error();
     clear_last_error();
     let lr = unsafe { SendMessageA(hwnd.into().into(), msg.into().into(), wparam, lparam) };
     if lr == 0 { fn_error_gle_nz!()? }
@@ -92,6 +93,7 @@ pub unsafe fn send_message_a(hwnd: impl Into<HWnd>, msg: impl Into<WM32>, wparam
 /// *   [send_notify_message_w]
 pub unsafe fn send_message_w(hwnd: impl Into<HWnd>, msg: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM) -> Result<LRESULT, Error> {
     fn_context!(send_message_w => SendMessageW);
+    debug_poison_last_error();
     clear_last_error();
     let lr = unsafe { SendMessageW(hwnd.into().into(), msg.into().into(), wparam, lparam) };
     if lr == 0 { fn_error_gle_nz!()? }
@@ -184,6 +186,7 @@ pub unsafe fn send_message_callback_a(
     data:               usize,
 ) -> Result<(), Error> {
     fn_context!(send_message_callback_a => SendMessageCallbackA);
+    debug_poison_last_error();
     fn_succeeded!(unsafe { SendMessageCallbackA(hwnd.into().into(), msg.into().into(), wparam, lparam, Some(std::mem::transmute(result_callback)), data) })
 }
 
@@ -273,6 +276,7 @@ pub unsafe fn send_message_callback_w(
     data:               usize,
 ) -> Result<(), Error> {
     fn_context!(send_message_callback_w => SendMessageCallbackW);
+    debug_poison_last_error();
     fn_succeeded!(unsafe { SendMessageCallbackW(hwnd.into().into(), msg.into().into(), wparam, lparam, Some(std::mem::transmute(result_callback)), data) })
 }
 
@@ -322,6 +326,7 @@ pub unsafe fn send_message_callback_w(
 /// *   [send_notify_message_a]
 pub unsafe fn send_message_timeout_a<'r>(hwnd: impl Into<HWnd>, msg: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM, flags: impl Into<SendMessageTimeOutFlags>, timeout: u32) -> Result<LRESULT, Error> {
     fn_context!(send_message_timeout_a => SendMessageTimeoutA);
+    debug_poison_last_error();
     clear_last_error();
     let mut lresult = 0;
     fn_succeeded!(unsafe { SendMessageTimeoutA(hwnd.into().into(), msg.into().into(), wparam, lparam, flags.into().into(), timeout, &mut lresult) } != 0)?;
@@ -371,6 +376,7 @@ pub unsafe fn send_message_timeout_a<'r>(hwnd: impl Into<HWnd>, msg: impl Into<W
 /// *   [send_notify_message_w]
 pub unsafe fn send_message_timeout_w<'r>(hwnd: impl Into<HWnd>, msg: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM, flags: impl Into<SendMessageTimeOutFlags>, timeout: u32) -> Result<LRESULT, Error> {
     fn_context!(send_message_timeout_w => SendMessageTimeoutW);
+    debug_poison_last_error();
     clear_last_error();
     let mut lresult = 0;
     fn_succeeded!(unsafe { SendMessageTimeoutW(hwnd.into().into(), msg.into().into(), wparam, lparam, flags.into().into(), timeout, &mut lresult) } != 0)?;
@@ -431,6 +437,7 @@ pub unsafe fn send_message_timeout_w<'r>(hwnd: impl Into<HWnd>, msg: impl Into<W
 /// *   [send_message_timeout_a]
 pub unsafe fn send_notify_message_a(hwnd: impl Into<HWnd>, msg: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM) -> Result<(), Error> {
     fn_context!(send_notify_message_a => SendNotifyMessageA);
+    debug_poison_last_error();
     fn_succeeded!(unsafe { SendNotifyMessageA(hwnd.into().into(), msg.into().into(), wparam, lparam) })
 }
 
@@ -488,5 +495,6 @@ pub unsafe fn send_notify_message_a(hwnd: impl Into<HWnd>, msg: impl Into<WM32>,
 /// *   [send_message_timeout_w]
 pub unsafe fn send_notify_message_w(hwnd: impl Into<HWnd>, msg: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM) -> Result<(), Error> {
     fn_context!(send_notify_message_w => SendNotifyMessageW);
+    debug_poison_last_error();
     fn_succeeded!(unsafe { SendNotifyMessageW(hwnd.into().into(), msg.into().into(), wparam, lparam) })
 }
@@ -1,5 +1,4 @@
 use crate::*;
-use winapi::um::winuser::*;
 
 
 
@@ -10,6 +9,8 @@ use winapi::um::winuser::*;
 ///
 /// Each timer is uniquely identified by `(hwnd, id_event)`.
 ///
+/// Before Windows 8, falls back on [set_timer] (after validating, then ignoring, `tolerance_delay_ms`.)
+///
 /// ### Safety
 /// This is *almost* safe.  Perhaps even *arguably* safe.
 ///
@@ -85,13 +86,13 @@ use winapi::um::winuser::*;
 /// *   [`set_timerproc_exception_suppression`] - Control exception/panic behavior of [`TimerProc`]s.
 /// *   [`set_timer`]                           - Basic alternative defaulting to [`TIMERV::DEFAULT_COALESCING`].
 /// *   [`kill_timer`]                          - Cancel or remove the registered timer.
+/// *   [`timer::Timer`]                        - Safe alternative that calls a closure, and kills the timer when dropped.
 /// *   [`TimerProc`]                           - Callback used if `timer_func` is [`Some`].
 /// *   [`WM::TIMER`]                           - Event fired if `timer_func` is [`None`].
 pub unsafe fn set_coalescable_timer(hwnd: impl TryInto<HWnd>, id_event: usize, elapse_ms: u32, timer_func: TimerProc, tolerance_delay_ms: u32) -> Result<usize, Error> {
     fn_context!(set_coalescable_timer => SetCoalescableTimer);
+    debug_poison_last_error();
     // TODO: tolerance_delay → typed
     let hwnd    = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
-    let tid     = unsafe { SetCoalescableTimer(hwnd, id_event, elapse_ms, core::mem::transmute(timer_func), tolerance_delay_ms) };
-    fn_succeeded!(tid != 0)?;
-    Ok(tid)
+    unsafe { User32::get().set_coalescable_timer(hwnd, id_event, elapse_ms, core::mem::transmute(timer_func), tolerance_delay_ms) }.map_err(|e| e.with_context(FN_CONTEXT))
 }
@@ -51,6 +51,7 @@ pub use winapi::um::winuser::ASFW_ANY;
 /// ```
 pub fn allow_set_foreground_window(process_id: u32) -> Result<(), Error> {
     fn_context!(allow_set_foreground_window => AllowSetForegroundWindow);
+    debug_poison_last_error();
     fn_succeeded!(unsafe { AllowSetForegroundWindow(process_id) })
 }
 
@@ -116,6 +117,7 @@ pub fn allow_set_foreground_window(process_id: u32) -> Result<(), Error> {
 /// *   No menus are active.
 pub fn set_foreground_window(hwnd: impl TryInto<HWnd>) -> Result<(), Error> {
     fn_context!(set_foreground_window => SetForegroundWindow);
+    debug_poison_last_error();
     let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     fn_succeeded!(unsafe { SetForegroundWindow(hwnd) })
 }
@@ -0,0 +1,26 @@
+use crate::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setthreaddpiawarenesscontext)\]
+/// SetThreadDpiAwarenessContext
+///
+/// Sets the DPI awareness of the current thread (and of windows it subsequently creates), returning the previous awareness context.
+///
+/// ### Errors
+/// *   [ERROR::INVALID_PARAMETER]      if `context` is invalid, or unsupported by this version of Windows (e.g. [DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2] before Windows 10 1703)
+/// *   [ERROR::CALL_NOT_IMPLEMENTED]   before Windows 10 1607
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// # use winresult::*;
+/// match set_thread_dpi_awareness_context(DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2) {
+///     Ok(prev) => { set_thread_dpi_awareness_context(prev).unwrap(); },
+///     Err(err) => assert!(err == ERROR::CALL_NOT_IMPLEMENTED || err == ERROR::INVALID_PARAMETER),
+/// }
+/// ```
+pub fn set_thread_dpi_awareness_context(context: impl Into<DpiAwarenessContext>) -> Result<DpiAwarenessContext, Error> {
+    fn_context!(set_thread_dpi_awareness_context => SetThreadDpiAwarenessContext);
+    User32::get().set_thread_dpi_awareness_context(context.into()).map_err(|e| e.with_context(FN_CONTEXT))
+}
@@ -155,10 +155,12 @@ use winapi::um::winuser::*;
 /// *   [`set_timerproc_exception_suppression`] - Control exception/panic behavior of [`TimerProc`]s.
 /// *   [`set_coalescable_timer`]               - Alternative giving more control over how events are coalesced.
 /// *   [`kill_timer`]                          - Cancel or remove the registered timer.
+/// *   [`timer::Timer`]                        - Safe alternative that calls a closure, and kills the timer when dropped.
 /// *   [`TimerProc`]                           - Callback used if `timer_func` is [`Some`].
 /// *   [`WM::TIMER`]                           - Event fired if `timer_func` is [`None`].
 pub unsafe fn set_timer(hwnd: impl TryInto<HWnd>, id_event: usize, elapse_ms: u32, timer_func: TimerProc) -> Result<usize, Error> {
     fn_context!(set_timer => SetTimer);
+    debug_poison_last_error();
     let hwnd    = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     let tid     = unsafe { SetTimer(hwnd, id_event, elapse_ms, core::mem::transmute(timer_func)) };
     fn_succeeded!(tid != 0)?;
@@ -53,6 +53,7 @@ use winapi::um::winuser::*;
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setuserobjectinformationw#parameters>
 pub fn set_timerproc_exception_suppression(suppress: bool) -> Result<(), Error> {
     fn_context!(set_timerproc_exception_suppression => SetUserObjectInformationW);
+    debug_poison_last_error();
     let mut suppress : BOOL = if suppress { 1 } else { 0 };
     let suppress : *mut BOOL = &mut suppress;
     fn_succeeded!(unsafe { SetUserObjectInformationW(GetCurrentProcess(), UOI_TIMERPROC_EXCEPTION_SUPPRESSION as _, suppress.cast(), size_of_32::<BOOL>()) })
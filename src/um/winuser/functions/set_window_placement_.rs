@@ -46,6 +46,7 @@ use winapi::um::winuser::*;
 /// *   [set_window_pos]
 pub fn set_window_placement(hwnd: impl TryInto<HWnd>, wndpl: &impl AsRef<WindowPlacement>) -> Result<(), Error> {
     fn_context!(set_window_placement => SetWindowPlacement);
+    debug_poison_last_error();
     let hwnd    = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     let wndpl   = wndpl.as_ref().as_ref();
     clear_last_error(); // SetWindowPlacement doesn't always set the error, even when returning FALSE for failure!
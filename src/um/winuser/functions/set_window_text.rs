@@ -38,6 +38,7 @@ use winapi::um::winuser::*;
 /// *   [set_window_text_w]
 pub fn set_window_text_a(hwnd: impl TryInto<HWnd>, string: impl TryIntoAsOptCStr) -> Result<(), Error> {
     fn_context!(set_window_text_a => SetWindowTextA);
+    debug_poison_last_error();
     let hwnd    = hwnd  .try_into().map_err(|_| fn_param_error!(hwnd,   ERROR::INVALID_WINDOW_HANDLE))?.into();
     let string  = string.try_into().map_err(|_| fn_param_error!(string, ERROR::INVALID_PARAMETER))?;
     fn_succeeded!(unsafe { SetWindowTextA(hwnd, string.as_opt_cstr()) })
@@ -77,6 +78,7 @@ pub fn set_window_text_a(hwnd: impl TryInto<HWnd>, string: impl TryIntoAsOptCStr
 /// *   [set_window_text_a]
 pub fn set_window_text_w(hwnd: impl TryInto<HWnd>, string: impl TryIntoAsOptCStr<u16>) -> Result<(), Error> {
     fn_context!(set_window_text_w => SetWindowTextW);
+    debug_poison_last_error();
     let hwnd    = hwnd  .try_into().map_err(|_| fn_param_error!(hwnd,   ERROR::INVALID_WINDOW_HANDLE))?.into();
     let string  = string.try_into().map_err(|_| fn_param_error!(string, ERROR::INVALID_PARAMETER))?;
     fn_succeeded!(unsafe { SetWindowTextW(hwnd, string.as_opt_cstr()) })
@@ -58,6 +58,7 @@ use winapi::um::winuser::*;
 /// *   [show_window_async]
 pub fn show_window(hwnd: impl TryInto<HWnd>, cmd: ShowWindowCmd) -> Result<(), Error> {
     fn_context!(show_window => ShowWindow);
+    debug_poison_last_error();
     let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     fn_succeeded!(unsafe { ShowWindow(hwnd, cmd.into()) })
 }
@@ -117,6 +118,7 @@ pub fn show_window(hwnd: impl TryInto<HWnd>, cmd: ShowWindowCmd) -> Result<(), E
 /// *   [show_window]
 pub fn show_window_async(hwnd: impl TryInto<HWnd>, cmd: ShowWindowCmd) -> Result<(), Error> {
     fn_context!(show_window_async => ShowWindowAsync);
+    debug_poison_last_error();
     let hwnd = hwnd.try_into().map_err(|_| fn_param_error!(hwnd, ERROR::INVALID_WINDOW_HANDLE))?.into();
     fn_succeeded!(unsafe { ShowWindowAsync(hwnd, cmd.into()) })
 }
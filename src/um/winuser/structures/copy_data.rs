@@ -0,0 +1,114 @@
+use crate::*;
+use bytemuck::*;
+use std::ffi::c_void;
+use std::fmt::{self, Debug, Formatter};
+use std::marker::PhantomData;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-copydatastruct)\]
+/// COPYDATASTRUCT
+///
+/// Contains data to be passed to another application by the [WM::COPYDATA] message.
+///
+/// ### See Also
+/// *   [send_copy_data]
+/// *   [CopyData] (receiving side)
+#[derive(Clone, Copy)]
+#[repr(C)] pub struct CopyDataStruct<'a> {
+    pub data:       usize,
+    cb_data:        u32,
+    lp_data:        *const c_void,
+    phantom:        PhantomData<&'a [u8]>,
+}
+
+unsafe impl Zeroable for CopyDataStruct<'_> {}
+impl Default for CopyDataStruct<'_> { fn default() -> Self { Self::zeroed() } }
+
+impl<'a> CopyDataStruct<'a> {
+    /// Create a [CopyDataStruct] referencing `bytes`.
+    ///
+    /// Returns [None] if `bytes` is too large for `cbData` (4 GiB or more.)
+    pub fn new(data: usize, bytes: &'a [u8]) -> Option<Self> {
+        let cb_data = u32::try_from(bytes.len()).ok()?;
+        Some(Self { data, cb_data, lp_data: bytes.as_ptr().cast(), phantom: PhantomData })
+    }
+
+    /// The bytes referenced by `lpData` / `cbData`.
+    pub fn bytes(&self) -> &'a [u8] {
+        if self.cb_data == 0 || self.lp_data.is_null() { return &[] }
+        unsafe { std::slice::from_raw_parts(self.lp_data.cast(), self.cb_data as usize) }
+    }
+}
+
+impl Debug for CopyDataStruct<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        fmt.debug_struct("CopyDataStruct")
+            .field("data",          &self.data          )
+            .field("cb_data",       &self.cb_data       )
+            .field("lp_data",       &self.lp_data       )
+            .finish()
+    }
+}
+
+/// A decoded [WM::COPYDATA] message, as received by a wndproc.
+///
+/// The borrowed [`bytes`](Self::bytes) are owned by the system, and are only valid until the wndproc returns.
+/// Copy them (e.g. with <code>[bytes](Self::bytes).[to_vec](slice::to_vec)()</code>) if they need to outlive the message.
+///
+/// ### Example
+/// ```rust
+/// # use hwnd::*;
+/// unsafe extern "system" fn wndproc(hwnd: HWnd, msg: WM32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+///     if let Some(cd) = unsafe { CopyData::from_wm(msg, wparam, lparam) } {
+///         let text = String::from_utf8_lossy(cd.bytes());
+///         println!("{:?} sent {} / {text:?}", cd.sender, cd.data);
+///         return 1;
+///     }
+///     unsafe { def_window_proc_w(hwnd, msg, wparam, lparam) }
+/// }
+/// ```
+///
+/// ### See Also
+/// *   [send_copy_data]
+#[derive(Clone, Copy, Debug)]
+pub struct CopyData<'wndproc> {
+    /// The window that sent the message (`wparam`), if the sender bothered to specify one.
+    pub sender: HWnd,
+
+    /// The application-defined `dwData` value.
+    pub data:   usize,
+
+    cds:        CopyDataStruct<'wndproc>,
+}
+
+impl<'wndproc> CopyData<'wndproc> {
+    /// Decode `wparam` / `lparam` of a [WM::COPYDATA] message.
+    ///
+    /// Returns [None] if `msg` isn't [WM::COPYDATA], or if `lparam` is null.
+    ///
+    /// ### Safety
+    /// *   `lparam` must point to a valid `COPYDATASTRUCT`, as it will be for a [WM::COPYDATA] message delivered to a wndproc.
+    /// *   `'wndproc` must not outlive the wndproc call that received the message - the system frees the data afterwards.
+    pub unsafe fn from_wm(msg: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM) -> Option<Self> {
+        if msg.into() != WM::COPYDATA || lparam == 0 { return None }
+        let cds = unsafe { *(lparam as *const CopyDataStruct<'wndproc>) };
+        Some(Self { sender: HWnd::from(wparam as winapi::shared::windef::HWND), data: cds.data, cds })
+    }
+
+    /// Decode `wparam` / `lparam` of a [WM::COPYDATA] message, and pass the result to `f`.
+    ///
+    /// Unlike [from_wm](Self::from_wm), the borrow cannot escape `f`.
+    /// Returns [None] (without calling `f`) if `msg` isn't [WM::COPYDATA], or if `lparam` is null.
+    ///
+    /// ### Safety
+    /// *   `lparam` must point to a valid `COPYDATASTRUCT`, as it will be for a [WM::COPYDATA] message delivered to a wndproc.
+    pub unsafe fn with<R>(msg: impl Into<WM32>, wparam: WPARAM, lparam: LPARAM, f: impl FnOnce(CopyData) -> R) -> Option<R> {
+        unsafe { CopyData::from_wm(msg, wparam, lparam) }.map(f)
+    }
+
+    /// The bytes sent.  Only valid for the duration of the wndproc call.
+    pub fn bytes(&self) -> &'wndproc [u8] { self.cds.bytes() }
+}
+
+convert!(CopyDataStruct<'_> => unsafe { winapi::um::winuser::COPYDATASTRUCT });
@@ -0,0 +1,24 @@
+use crate::*;
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-iconinfo)\]
+/// ICONINFO + BITMAP, as returned by [get_icon_info]
+///
+/// Unlike ICONINFO, this doesn't own any bitmaps: [get_icon_info] deletes them after measuring the icon.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] #[non_exhaustive] pub struct IconInfo {
+    /// `true` for icons, `false` for cursors
+    pub is_icon:    bool,
+
+    /// The cursor's hotspot (the center of the icon for icons)
+    pub hotspot:    Point,
+
+    /// Width in pixels
+    pub width:      u32,
+
+    /// Height in pixels (of the image, not the doubled height of a monochrome icon's AND + XOR mask)
+    pub height:     u32,
+
+    /// Bits per pixel of the color bitmap (`1` for monochrome icons)
+    pub bit_count:  u16,
+}
@@ -0,0 +1,119 @@
+use crate::*;
+
+
+
+/// Metadata describing how a system [WM32] message treats its parameters and result.
+///
+/// Generated from `crates/xtask/src/bin/data/wm.rs`, per learn.microsoft.com's documentation of each message.
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// let info = WM::SETTEXT.info().unwrap();
+/// assert_eq!(info.name,   "WM::SETTEXT");
+/// assert_eq!(info.lparam, MessageParam::Ptr(Some("TCHAR")));
+/// assert!(info.send_only);
+/// assert!(info.marshaled);
+///
+/// assert_eq!(WM::NULL.info().unwrap().lparam, MessageParam::Unused);
+/// assert_eq!(None, WM::USER(0).info());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] #[non_exhaustive] pub struct MessageInfo {
+    /// e.g. [WM::SETTEXT]
+    pub wm:         WM32,
+
+    /// e.g. `"WM::SETTEXT"` (as [Debug]ged)
+    pub name:       &'static str,
+
+    /// How `wparam` is used
+    pub wparam:     MessageParam,
+
+    /// How `lparam` is used
+    pub lparam:     MessageParam,
+
+    /// What a window procedure should return
+    pub result:     MessageResult,
+
+    /// The message has pointer parameters, so asynchronous functions ([post_message_w], [send_notify_message_w], [send_message_callback_w], ...) fail with [ERROR::MESSAGE_SYNC_ONLY].
+    pub send_only:  bool,
+
+    /// The message can be sent to a window of another process: it either has no pointer parameters, or Windows marshals them.
+    pub marshaled:  bool,
+}
+
+/// How a message treats `wparam` or `lparam`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub enum MessageParam {
+    /// Not used: should be `0`
+    Unused,
+
+    /// An integer, flags, handle, packed coordinates, etc.
+    Value,
+
+    /// A value the receiver may dereference, which Windows neither validates nor marshals (e.g. [WM::PAINTCLIPBOARD]'s `HGLOBAL`, [WM::TIMER]'s `TIMERPROC`, or [WM::INITDIALOG]'s app data, which is usually a pointer.)
+    Opaque,
+
+    /// A pointer to the named C type (e.g. `Some("CREATESTRUCT")`, or `Some("TCHAR")` for strings), or `None` if the pointed-to type varies.
+    Ptr(Option<&'static str>),
+}
+
+/// What a window procedure should return for a message
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] pub enum MessageResult {
+    /// The result isn't meaningful
+    Ignored,
+
+    /// `0` if the message was processed
+    ZeroIfProcessed,
+
+    /// `TRUE` / `FALSE` (see the message's documentation for which is which)
+    Bool,
+
+    /// A message-specific value, such as a length, handle, or `HT*` code
+    Value,
+}
+
+impl WM32 {
+    /// [MessageInfo] for system messages (`None` for unrecognized messages, and messages at or above [WM::USER].)
+    pub fn info(self) -> Option<&'static MessageInfo> {
+        let i = crate::_messages::MESSAGES.partition_point(|m| m.wm < self);
+        crate::_messages::MESSAGES.get(i).filter(|m| m.wm == self)
+    }
+}
+
+impl MessageParam {
+    /// `true` if this parameter is a pointer
+    pub const fn is_ptr(self) -> bool { matches!(self, MessageParam::Ptr(_)) }
+}
+
+
+
+#[test] fn catalogue() {
+    let messages = crate::_messages::MESSAGES;
+    assert!(messages.windows(2).all(|w| w[0].wm <= w[1].wm), "MESSAGES must be sorted for WM32::info");
+
+    for wm in (0 .. 0x1_0000).map(WM32::from) {
+        let info = wm.info();
+        assert_eq!(wm.to_str().is_some(), info.is_some(), "{wm:?}: every named WM::* system message (and only those) should have metadata");
+        let Some(info) = info else { continue };
+
+        assert_eq!(info.wm, wm);
+        assert_eq!(info.name, format!("{wm:?}"));
+        assert_eq!(info.send_only, info.wparam.is_ptr() || info.lparam.is_ptr(), "{wm:?}: system messages are send-only iff they carry pointers");
+        assert!(info.marshaled || info.send_only, "{wm:?}: messages without pointers can always cross processes");
+    }
+
+    // aliases share metadata
+    assert_eq!(WM::SETTINGCHANGE.info(), WM::WININICHANGE.info());
+
+    // a sampling of documented semantics
+    assert_eq!(WM::CREATE.info().unwrap().lparam,           MessageParam::Ptr(Some("CREATESTRUCT")));
+    assert_eq!(WM::INITDIALOG.info().unwrap().lparam,       MessageParam::Opaque);
+    assert_eq!(WM::SIZECLIPBOARD.info().unwrap().lparam,    MessageParam::Opaque);
+    assert_eq!(WM::TIMER.info().unwrap().lparam,            MessageParam::Opaque);
+    assert_eq!(WM::GETTEXT.info().unwrap().result,          MessageResult::Value);
+    assert_eq!(WM::ERASEBKGND.info().unwrap().result,       MessageResult::Bool);
+    assert_eq!(WM::PAINT.info().unwrap().result,            MessageResult::ZeroIfProcessed);
+    assert!( WM::COPYDATA.info().unwrap().marshaled);
+    assert!(!WM::NOTIFY.info().unwrap().marshaled);
+    assert!(!WM::SETREDRAW.info().unwrap().send_only);
+    assert!( WM::SETTEXT.info().unwrap().send_only);
+}
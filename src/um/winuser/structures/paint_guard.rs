@@ -0,0 +1,99 @@
+use crate::*;
+use winapi::um::winuser::*;
+use std::fmt::{self, Debug, Formatter};
+
+
+
+/// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-paintstruct)\]
+/// PAINTSTRUCT, from [begin_paint], with EndPaint called when dropped.
+///
+/// Dropping the guard (including while unwinding) validates the painted region.
+/// Forgetting to do so (e.g. via [std::mem::forget]) leaves the window invalid, causing an endless stream of [WM::PAINT]s.
+///
+/// Like the device context it owns, a [PaintGuard] is `!Send` and `!Sync`: it must be dropped on the thread that created it.
+pub struct PaintGuard {
+    hwnd:   HWnd,
+    ps:     PAINTSTRUCT,
+}
+
+impl PaintGuard {
+    /// ### Safety
+    /// *   `ps` must have been filled out by a successful `BeginPaint(hwnd, &mut ps)` on the current thread, not yet ended.
+    pub(crate) unsafe fn from_raw(hwnd: HWnd, ps: PAINTSTRUCT) -> Self { Self { hwnd, ps } }
+
+    /// The window being painted.
+    pub fn hwnd(&self) -> HWnd { self.hwnd }
+
+    /// `hdc`: the display device context to paint with, clipped to the update region.
+    pub fn hdc(&self) -> HDC { self.ps.hdc }
+
+    /// `rcPaint`: the bounds of the update region, in client coordinates.
+    pub fn paint_rect(&self) -> Rect { self.ps.rcPaint.into() }
+
+    /// `fErase`: if `true`, the background still needs erasing (e.g. [WM::ERASEBKGND] was unhandled, or the class has no background brush.)
+    pub fn erase(&self) -> bool { self.ps.fErase != 0 }
+}
+
+impl Drop for PaintGuard {
+    fn drop(&mut self) { unsafe { EndPaint(self.hwnd.into(), &self.ps) }; } // always returns nonzero
+}
+
+impl Debug for PaintGuard {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        fmt.debug_struct("PaintGuard")
+            .field("hwnd",          &self.hwnd          )
+            .field("hdc",           &self.ps.hdc        )
+            .field("paint_rect",    &self.paint_rect()  )
+            .field("erase",         &self.erase()       )
+            .finish()
+    }
+}
+
+
+
+#[test] fn end_paint_on_drop() {
+    use std::cell::Cell;
+    use std::panic::catch_unwind;
+    use std::ptr::null_mut;
+
+    thread_local! {
+        static PANIC    : Cell<bool>  = Default::default();
+        static PAINTS   : Cell<usize> = Default::default();
+        static PANICKED : Cell<usize> = Default::default();
+    }
+
+    unsafe extern "system" fn wndproc(hwnd: HWnd, msg: WM32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+        if msg != WM::PAINT { return unsafe { def_window_proc_w(hwnd, msg, wparam, lparam) } }
+        let painted = catch_unwind(|| {
+            let _paint = begin_paint(hwnd).unwrap();
+            PAINTS.with(|p| p.set(p.get() + 1));
+            if PANIC.with(Cell::get) { panic!("panic while painting") }
+        }); // EndPaint, even while unwinding
+        if painted.is_err() { PANICKED.with(|p| p.set(p.get() + 1)) }
+        0
+    }
+
+    let hinstance = get_module_handle_entry_exe().unwrap();
+    let name = abistr::cstr16!("hwnd::PaintGuard::test");
+    let class = unsafe { register_class_w(&WndClassW { wnd_proc: Some(wndproc), hinstance, class_name: name.into(), .. Default::default() }) }.unwrap();
+    // visible: a hidden window's update region is always empty
+    let hwnd = unsafe { create_window_ex_w(0, class, name, WS::POPUP | WS::VISIBLE, 0, 0, 64, 64, HWnd::NULL, null_mut(), hinstance, null_mut()) }.unwrap();
+    update_window(hwnd).unwrap(); // flush the initial WM_PAINT
+    let paints = PAINTS.with(Cell::get);
+
+    invalidate_rect(hwnd, None, false).unwrap();
+    assert!(get_update_rect(hwnd, false).unwrap().is_some());
+    update_window(hwnd).unwrap();
+    assert_eq!(PAINTS.with(Cell::get), paints + 1);
+    assert_eq!(None, get_update_rect(hwnd, false).unwrap(), "dropping the PaintGuard should've validated the window");
+
+    PANIC.with(|p| p.set(true));
+    invalidate_rect(hwnd, None, false).unwrap();
+    update_window(hwnd).unwrap();
+    assert_eq!(PAINTS.with(Cell::get), paints + 2);
+    assert_eq!(PANICKED.with(Cell::get), 1);
+    assert_eq!(None, get_update_rect(hwnd, false).unwrap(), "unwinding through the PaintGuard should've validated the window");
+
+    unsafe { destroy_window(hwnd) }.unwrap();
+    unsafe { unregister_class_w(class, hinstance) }.unwrap();
+}
@@ -0,0 +1,141 @@
+use crate::*;
+use std::fmt::{self, Debug, Formatter};
+
+
+
+/// Owned icons and cursor for [RegisteredClass], replacing the corresponding [WndClassExW] fields.
+#[derive(Debug, Default)] pub struct ClassIcons {
+    pub hicon:      Option<OwnedIcon>,
+    pub hcursor:    Option<OwnedIcon>,
+    pub hicon_sm:   Option<OwnedIcon>,
+}
+
+/// A window class registered by [register_ex_a](Self::register_ex_a) / [register_ex_w](Self::register_ex_w),
+/// which owns the class's [OwnedIcon]s and unregisters the class when dropped.
+///
+/// The icons are only destroyed once [unregister_class_w] succeeds, when no window can use them anymore.
+/// If unregistering fails (e.g. [ERROR::CLASS_HAS_WINDOWS]), or the [RegisteredClass] is [std::mem::forget]ten, the icons are leaked instead.
+///
+/// ### Example
+/// ```
+/// # use hwnd::*;
+/// let icon = create_icon_from_rgba(16, 16, &[0xFF; 16 * 16 * 4], None).unwrap();
+/// let class = WndClassExW {
+///     wnd_proc:   Some(def_window_proc_w),
+///     class_name: Some(abistr::cstr16!("RegisteredClass.docs")),
+///     .. Default::default()
+/// };
+/// let class = unsafe { RegisteredClass::register_ex_w(&class, ClassIcons { hicon: Some(icon), .. Default::default() }) }.unwrap();
+/// // ...create windows of `class.atom()`...
+/// drop(class); // unregisters the class, then destroys the icon
+/// ```
+pub struct RegisteredClass {
+    atom:       AtomNonZero,
+    hinstance:  HInstance<'static>,
+    icons:      ClassIcons,
+}
+
+impl RegisteredClass {
+    /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexa)\]
+    /// RegisterClassExA, with `class`'s icons and cursor replaced by any `icons` provided.
+    ///
+    /// ### Safety
+    /// *   See [register_class_ex_a]
+    /// *   The class will be unregistered when the [RegisteredClass] is dropped, which must not break code expecting it to remain registered (see [unregister_class_a].)
+    ///
+    /// ### Errors
+    /// *   See [register_class_ex_a] (`icons` are destroyed.)
+    pub unsafe fn register_ex_a(class: &WndClassExA, icons: ClassIcons) -> Result<Self, Error> {
+        let mut class = *class;
+        icons.apply(&mut class.hicon, &mut class.hcursor, &mut class.hicon_sm);
+        let atom = unsafe { register_class_ex_a(&class) }?;
+        Ok(Self { atom, hinstance: class.hinstance, icons })
+    }
+
+    /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw)\]
+    /// RegisterClassExW, with `class`'s icons and cursor replaced by any `icons` provided.
+    ///
+    /// ### Safety
+    /// *   See [register_class_ex_w]
+    /// *   The class will be unregistered when the [RegisteredClass] is dropped, which must not break code expecting it to remain registered (see [unregister_class_w].)
+    ///
+    /// ### Errors
+    /// *   See [register_class_ex_w] (`icons` are destroyed.)
+    pub unsafe fn register_ex_w(class: &WndClassExW, icons: ClassIcons) -> Result<Self, Error> {
+        let mut class = *class;
+        icons.apply(&mut class.hicon, &mut class.hcursor, &mut class.hicon_sm);
+        let atom = unsafe { register_class_ex_w(&class) }?;
+        Ok(Self { atom, hinstance: class.hinstance, icons })
+    }
+
+    /// The class atom, for [create_window_ex_w] etc.
+    pub fn atom(&self) -> AtomNonZero { self.atom }
+
+    /// \[[learn.microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unregisterclassw)\]
+    /// UnregisterClassW, then destroy the class's icons.
+    ///
+    /// ### Errors
+    /// *   [ERROR::CLASS_HAS_WINDOWS]      If windows of the class still exist (the class remains registered, and its icons are leaked.)
+    pub fn unregister(mut self) -> Result<(), Error> {
+        let result = self.unregister_impl();
+        std::mem::forget(self); // don't unregister again
+        result
+    }
+
+    fn unregister_impl(&mut self) -> Result<(), Error> {
+        let icons = std::mem::take(&mut self.icons);
+        // SAFETY: per `register_ex_*`'s contract, unregistering the class is fine
+        match unsafe { unregister_class_w(self.atom, self.hinstance) } {
+            Ok(()) => { drop(icons); Ok(()) },
+            Err(err) => { std::mem::forget(icons); Err(err) }, // windows of the class might still be using them
+        }
+    }
+}
+
+impl ClassIcons {
+    fn apply(&self, hicon: &mut HIcon<'static>, hcursor: &mut HCursor<'static>, hicon_sm: &mut HIcon<'static>) {
+        // SAFETY: the class only uses these until unregistered, after which `RegisteredClass` destroys them
+        if let Some(i) = self.hicon.as_ref()    { *hicon    = unsafe { HIcon::from_unchecked(i.as_hicon().into()) }; }
+        if let Some(c) = self.hcursor.as_ref()  { *hcursor  = unsafe { HCursor::from_unchecked(c.as_hcursor().into()) }; }
+        if let Some(i) = self.hicon_sm.as_ref() { *hicon_sm = unsafe { HIcon::from_unchecked(i.as_hicon().into()) }; }
+    }
+}
+
+impl Drop for RegisteredClass {
+    fn drop(&mut self) { let _ = self.unregister_impl(); }
+}
+
+impl Debug for RegisteredClass {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        fmt.debug_struct("RegisteredClass")
+            .field("atom",      &self.atom      )
+            .field("hinstance", &self.hinstance )
+            .field("icons",     &self.icons     )
+            .finish()
+    }
+}
+
+
+
+#[test] fn registered_class() {
+    use std::ptr::null_mut;
+
+    let alive = |hicon: HICON| get_icon_info(unsafe { HIcon::from_unchecked(hicon) }).is_ok();
+    let hinstance = get_module_handle_entry_exe().unwrap();
+    let class = WndClassExW { wnd_proc: Some(def_window_proc_w), hinstance, class_name: Some(abistr::cstr16!("hwnd::RegisteredClass::test")), .. Default::default() };
+
+    let icon = create_icon_from_rgba(16, 16, &[0xFF; 16 * 16 * 4], None).unwrap();
+    let raw : HICON = icon.as_hicon().into();
+    let registered = unsafe { RegisteredClass::register_ex_w(&class, ClassIcons { hicon: Some(icon), .. Default::default() }) }.unwrap();
+    let hwnd = unsafe { create_window_ex_w(0, registered.atom(), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), hinstance, null_mut()) }.unwrap();
+    assert_eq!(ERROR::CLASS_HAS_WINDOWS, registered.unregister().unwrap_err());
+    assert!(alive(raw), "icon should be leaked while windows of the class exist");
+    unsafe { destroy_window(hwnd) }.unwrap();
+    unsafe { unregister_class_w(class.class_name.unwrap(), hinstance) }.unwrap();
+
+    let icon = create_icon_from_rgba(16, 16, &[0xFF; 16 * 16 * 4], None).unwrap();
+    let raw : HICON = icon.as_hicon().into();
+    let registered = unsafe { RegisteredClass::register_ex_w(&class, ClassIcons { hicon: Some(icon), .. Default::default() }) }.unwrap();
+    drop(registered);
+    assert!(!alive(raw), "icon should be destroyed once the class is unregistered");
+}
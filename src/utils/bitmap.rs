@@ -0,0 +1,11 @@
+use winapi::shared::windef::HBITMAP;
+use winapi::um::wingdi::DeleteObject;
+
+
+
+/// An owned HBITMAP (e.g. from CreateDIBSection or GetIconInfo), deleted with DeleteObject when dropped.
+pub(crate) struct OwnedBitmap(pub(crate) HBITMAP);
+
+impl Drop for OwnedBitmap {
+    fn drop(&mut self) { if !self.0.is_null() { unsafe { DeleteObject(self.0.cast()) }; } }
+}
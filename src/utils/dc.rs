@@ -0,0 +1,11 @@
+use winapi::shared::windef::HDC;
+use winapi::um::wingdi::DeleteDC;
+
+
+
+/// An owned memory HDC (e.g. from CreateCompatibleDC), deleted with DeleteDC when dropped.
+pub(crate) struct OwnedDC(pub(crate) HDC);
+
+impl Drop for OwnedDC {
+    fn drop(&mut self) { if !self.0.is_null() { unsafe { DeleteDC(self.0) }; } }
+}
@@ -1,13 +1,66 @@
-//use winapi::shared::winerror::*;
+use winapi::shared::winerror::ERROR_INTERNAL_ERROR;
 use winapi::um::errhandlingapi::SetLastError;
 
 pub(crate) fn clear_last_error() { set_last_error(0) }
 pub(crate) fn set_last_error(code: u32) { unsafe { SetLastError(code) } }
 
-// XXX: To avoid tripping up other code, this should probably be a scoped thing that also clears the error code
-//pub(crate) fn debug_set_last_error_invalid() {
-//    if cfg!(debug_assertions) {
-//        //set_last_error(ERROR_INTERNAL_ERROR);
-//        set_last_error(ERROR_INVALID_FUNCTION);
-//    }
-//}
+/// The last error set by [debug_poison_last_error]
+pub(crate) const POISONED_LAST_ERROR : u32 = ERROR_INTERNAL_ERROR;
+
+/// With the `"debug-poison-last-error"` feature (or in unit tests), set the last error to [POISONED_LAST_ERROR].
+///
+/// Functions that read the last error call this on entry (after `fn_context!`), so functions that forget to [clear_last_error] before
+/// calling APIs with ambiguous return values (e.g. `0` for both "failed" and "the value is 0") report
+/// `ERROR::INTERNAL_ERROR` instead of succeeding by accident, whatever the last error happened to be.
+#[inline(always)] pub(crate) fn debug_poison_last_error() {
+    if cfg!(any(test, feature = "debug-poison-last-error")) { set_last_error(POISONED_LAST_ERROR) }
+}
+
+
+
+/// Functions whose underlying APIs return `0` both on failure and for legitimate values must report both correctly,
+/// even when the last error is stale (they poison it on entry in unit tests.)
+#[test] fn ambiguous_zero() {
+    use crate::*;
+    use std::ptr::null_mut;
+
+    debug_poison_last_error();
+    assert_eq!(unsafe { winapi::um::errhandlingapi::GetLastError() }, POISONED_LAST_ERROR, "unit tests should poison the last error");
+
+    let hwnd    = unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap();
+    let invalid = !42usize as HWND;
+    let name    = abistr::cstr16!("hwnd.test.ambiguous_zero");
+
+    assert_eq!(Ok(0),                           unsafe { send_message_a(hwnd,    WM::NULL, 0, 0) });
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    unsafe { send_message_a(invalid, WM::NULL, 0, 0) }.unwrap_err());
+    assert_eq!(Ok(0),                           unsafe { send_message_w(hwnd,    WM::NULL, 0, 0) });
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    unsafe { send_message_w(invalid, WM::NULL, 0, 0) }.unwrap_err());
+    assert_eq!(Ok(0),                           unsafe { send_message_timeout_w(hwnd,    WM::NULL, 0, 0, SMTO::NORMAL, 100) });
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    unsafe { send_message_timeout_w(invalid, WM::NULL, 0, 0, SMTO::NORMAL, 100) }.unwrap_err());
+
+    assert_eq!(Ok(0),                           get_window_text_length_a(hwnd));
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    get_window_text_length_a(invalid).unwrap_err());
+    assert_eq!(Ok(0),                           get_window_text_length_w(hwnd));
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    get_window_text_length_w(invalid).unwrap_err());
+    assert!(get_window_text_a(hwnd, &mut [0; 16]).unwrap().is_empty());
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    get_window_text_a(invalid, &mut [0; 16]).unwrap_err());
+    assert!(get_window_text_w(hwnd, &mut [0; 16]).unwrap().is_empty());
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    get_window_text_w(invalid, &mut [0; 16]).unwrap_err());
+
+    assert_eq!(Ok(0),                           get_window_long_ptr_a(hwnd,    GWLP::USERDATA));
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    get_window_long_ptr_a(invalid, GWLP::USERDATA).unwrap_err());
+    assert_eq!(Ok(0),                           get_window_long_ptr_w(hwnd,    GWLP::USERDATA));
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    get_window_long_ptr_w(invalid, GWLP::USERDATA).unwrap_err());
+
+    assert!(get_prop_w(hwnd, name).unwrap().is_null());
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    get_prop_w(invalid, name).unwrap_err());
+    assert!(unsafe { remove_prop_w(hwnd, name) }.unwrap().is_null());
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    unsafe { remove_prop_w(invalid, name) }.unwrap_err());
+    assert_eq!(0,                               enum_props_ex_w(hwnd).unwrap().count());
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    enum_props_ex_w(invalid).err().unwrap());
+
+    assert!(find_window_w((), abistr::cstr16!("hwnd.test.ambiguous_zero: no such window")).unwrap().is_null());
+    assert_eq!(ERROR::INVALID_WINDOW_HANDLE,    find_window_ex_w(invalid, HWnd::NULL, (), ()).unwrap_err());
+
+    unsafe { destroy_window(hwnd) }.unwrap();
+}
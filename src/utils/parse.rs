@@ -0,0 +1,192 @@
+//! Shared [FromStr](std::str::FromStr) implementation details of flag and enum types.
+//! See [impl_from_str_for_flags] and [impl_from_str_for_enum].
+
+use crate::ParseError;
+
+
+
+/// Parse `s` as `|`-separated terms, each of which is a name from `names`, or an integer.
+pub(crate) fn flags<T: Copy + Default + TryFrom<i64> + std::ops::BitOr<Output = T>>(ty: &'static str, s: &str, names: &[(&str, T)]) -> Result<T, ParseError> {
+    let mut value = T::default();
+    for term in s.split('|') {
+        value = value | enum_(ty, term, names)?;
+    }
+    Ok(value)
+}
+
+/// Parse `s` as a single name from `names`, or an integer.
+pub(crate) fn enum_<T: Copy + TryFrom<i64>>(ty: &'static str, s: &str, names: &[(&str, T)]) -> Result<T, ParseError> {
+    let term = s.trim();
+    let err = || ParseError { ty, term: term.into() };
+    if let Some(value) = names.iter().find(|(name, _)| is_name(term, name)).map(|(_, value)| *value) { return Ok(value) }
+    integer(term).and_then(|i| T::try_from(i).ok()).ok_or_else(err)
+}
+
+/// `true` if `term` is `path` (e.g. `"WS::VISIBLE"`) or it's C spelling (e.g. `"WS_VISIBLE"`)
+pub(crate) fn is_name(term: &str, path: &str) -> bool {
+    if term == path { return true }
+    let Some((module, name)) = path.split_once("::") else { return false };
+    term.strip_prefix(module).and_then(|rest| rest.strip_prefix('_')) == Some(name)
+}
+
+/// Deserialize a string via [FromStr](std::str::FromStr)
+#[cfg(feature = "serde")] pub(crate) fn deserialize<'de, T: std::str::FromStr<Err = ParseError>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    struct Visitor<T>(std::marker::PhantomData<T>);
+    impl<T: std::str::FromStr<Err = ParseError>> serde::de::Visitor<'_> for Visitor<T> {
+        type Value = T;
+        fn expecting(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result { fmt.write_str("a string such as \"WS::OVERLAPPEDWINDOW | WS::VISIBLE\" or \"WM_USER+5\"") }
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<T, E> { v.parse().map_err(E::custom) }
+    }
+    deserializer.deserialize_str(Visitor(std::marker::PhantomData))
+}
+
+/// Parse a `"quoted"` string as formatted by [`str`]'s [Debug](std::fmt::Debug)
+pub(crate) fn debug_str(term: &str) -> Option<String> {
+    let mut chars = term.strip_prefix('"')?.strip_suffix('"')?.chars();
+    let mut s = String::new();
+    while let Some(ch) = chars.next() {
+        s.push(match ch {
+            '"' => return None,
+            '\\' => match chars.next()? {
+                '0'     => '\0',
+                'n'     => '\n',
+                'r'     => '\r',
+                't'     => '\t',
+                'u'     => {
+                    let hex = chars.as_str().strip_prefix('{')?;
+                    let (hex, rest) = hex.split_once('}')?;
+                    chars = rest.chars();
+                    char::from_u32(u32::from_str_radix(hex, 16).ok()?)?
+                },
+                ch @ ('\\' | '"' | '\'') => ch,
+                _       => return None,
+            },
+            ch => ch,
+        });
+    }
+    Some(s)
+}
+
+/// Parse `[-]123` or `[-]0x7B`
+pub(crate) fn integer(term: &str) -> Option<i64> {
+    let (negative, digits) = match term.strip_prefix('-') { Some(digits) => (true, digits), None => (false, term) };
+    let (radix, digits) = match digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) { Some(hex) => (16, hex), None => (10, digits) };
+    if !digits.starts_with(|ch: char| ch.is_digit(radix)) { return None } // from_str_radix would accept e.g. "+1" / "-1"
+    let magnitude = i64::from_str_radix(digits, radix).ok()?;
+    Some(if negative { -magnitude } else { magnitude })
+}
+
+
+
+#[test] fn integers() {
+    assert_eq!(Some(0),             integer("0"));
+    assert_eq!(Some(123),           integer("123"));
+    assert_eq!(Some(-16),           integer("-16"));
+    assert_eq!(Some(0x10000000),    integer("0x10000000"));
+    assert_eq!(Some(0xABC),         integer("0XaBc"));
+    assert_eq!(Some(-0x10),         integer("-0x10"));
+    assert_eq!(None,                integer(""));
+    assert_eq!(None,                integer("0x"));
+    assert_eq!(None,                integer("--1"));
+    assert_eq!(None,                integer("+1"));
+    assert_eq!(None,                integer("0x-1"));
+    assert_eq!(None,                integer("WS::VISIBLE"));
+}
+
+#[test] fn flags_round_trip() {
+    use crate::*;
+
+    fn check<T: Copy + PartialEq + std::fmt::Debug + std::str::FromStr<Err = ParseError> + From<u32>>(values: impl Iterator<Item = u32>) {
+        for value in values {
+            let value = T::from(value);
+            let s = format!("{value:?}");
+            assert_eq!(Ok(value), s.parse::<T>(), "{s:?}");
+        }
+    }
+
+    // every single bit, and a spread of (pseudo-random) combinations
+    let mut seed = 0x12345678_u32;
+    let values = || (0 .. 32).map(|bit| 1 << bit).chain([0, !0]).chain(std::iter::repeat_with(move || { seed ^= seed << 13; seed ^= seed >> 17; seed ^= seed << 5; seed }).take(10000));
+    check::<ISMEX::InSendMessageExFlags>(values());
+    check::<LOAD_LIBRARY::LoadLibraryFlags>(values());
+    check::<LR::LoadImageFlags>(values());
+    check::<LWA::LayeredWindowAttributesFlags>(values());
+    check::<PM::PeekMessageFlags>(values());
+    check::<RDW::RedrawWindowFlags>(values());
+    check::<SMTO::SendMessageTimeOutFlags>(values());
+    check::<SWP::SetWindowPosFlags>(values());
+    check::<WPF::WindowPlacementFlags>(values());
+    check::<WS::WindowStyle>(values());
+    check::<WS_EX::WindowStyleExtended>(values());
+}
+
+#[test] fn flags_spellings() {
+    use crate::*;
+
+    assert_eq!(Ok(WS::OVERLAPPEDWINDOW | WS::VISIBLE),  "WS::OVERLAPPEDWINDOW | WS::VISIBLE".parse());
+    assert_eq!(Ok(WS::OVERLAPPEDWINDOW | WS::VISIBLE),  "WS_OVERLAPPEDWINDOW|WS_VISIBLE".parse());
+    assert_eq!(Ok(WS::GROUP | WS::TABSTOP),             "WS::GROUP | WS_TABSTOP".parse());       // overlapping names
+    assert_eq!(Ok(WS::POPUP | WS::WindowStyle::from(0x3)),           "WS::POPUP | 0x3".parse());
+    assert_eq!(Ok(WS::OVERLAPPED),                      "0".parse());
+    assert_eq!(Ok(WS_EX::LEFT | WS_EX::LAYERED),        "WS_EX::LEFT | WS_EX_LAYERED".parse());  // zero names
+    assert_eq!(Ok(SWP::NOSIZE | SWP::NOMOVE),           " SWP_NOSIZE |SWP::NOMOVE ".parse());
+
+    assert!("WS_EX::LAYERED".parse::<WS::WindowStyle>().is_err());  // WS_EX isn't WS
+    assert!("WS_EX_LAYERED".parse::<WS::WindowStyle>().is_err());
+    assert!("WS::VISIBLE |".parse::<WS::WindowStyle>().is_err());
+    assert!("VISIBLE".parse::<WS::WindowStyle>().is_err());
+    assert!("0x100000000".parse::<WS::WindowStyle>().is_err());     // out of range
+    assert!("-1".parse::<WS::WindowStyle>().is_err());
+
+    assert_eq!("unable to parse WindowStyle: unrecognized `WS::BOGUS`", "WS::VISIBLE | WS::BOGUS".parse::<WS::WindowStyle>().unwrap_err().to_string());
+}
+
+#[test] fn enums_round_trip() {
+    use crate::*;
+
+    for value in (-1000 ..= 1000).chain([i32::MIN, i32::MAX]) {
+        assert_eq!(Ok(GWL ::GetWindowLongIndex   ::from(value)), format!("{:?}", GWL ::GetWindowLongIndex   ::from(value)).parse());
+        assert_eq!(Ok(GWLP::GetWindowLongPtrIndex::from(value)), format!("{:?}", GWLP::GetWindowLongPtrIndex::from(value)).parse());
+        assert_eq!(Ok(SW  ::ShowWindowCmd        ::from(value)), format!("{:?}", SW  ::ShowWindowCmd        ::from(value)).parse());
+    }
+
+    assert_eq!(Ok(GWL::STYLE),      "GWL_STYLE".parse());
+    assert_eq!(Ok(GWL::STYLE),      "-16".parse());
+    assert_eq!(Ok(SW::SHOWNORMAL),  "SW::NORMAL".parse());
+    assert!("SW::BOGUS".parse::<SW::ShowWindowCmd>().is_err());
+    assert!("SW::HIDE | SW::SHOW".parse::<SW::ShowWindowCmd>().is_err());
+}
+
+#[test] fn debug_strs() {
+    for s in ["", "TaskbarCreated", "quote\" backslash\\ tab\t nul\0 bell\u{7} \u{FEFF}bom é 🦀"] {
+        assert_eq!(Some(s.to_string()), debug_str(&format!("{s:?}")), "{s:?}");
+    }
+    assert_eq!(None, debug_str("unquoted"));
+    assert_eq!(None, debug_str(r#""unterminated"#));
+    assert_eq!(None, debug_str(r#""bad \q escape""#));
+}
+
+#[test] fn wm_round_trip() {
+    use crate::*;
+
+    for value in (0 ..= 0x1_0010).chain([0x7FFF_FFFF, u32::MAX]) {
+        let wm = WM32::from(value);
+        assert_eq!(Ok(wm), format!("{wm:?}").parse(), "{value:#X} formatted as {wm:?}");
+        assert_eq!(Ok(wm), format!("{value:#X}").parse());
+    }
+
+    assert_eq!(Ok(WM::CREATE),          "WM_CREATE".parse());
+    assert_eq!(Ok(WM::USERCHANGED),     "WM_USERCHANGED".parse());
+    assert_eq!(Ok(WM::APPCOMMAND),      "WM::APPCOMMAND".parse());
+    assert_eq!(Ok(WM::USER(5)),         "WM_USER + 0x5".parse());
+    assert_eq!(Ok(WM::APP(0)),          "WM_APP".parse());
+    assert!("WM_USER+0x7C00".parse::<WM32>().is_err());
+    assert!("WM::BOGUS".parse::<WM32>().is_err());
+
+    for value in (0 ..= 0xFFFF).step_by(7).chain([0xFFFF]) {
+        let wm = WM16::from(value);
+        assert_eq!(Ok(wm), format!("{wm:?}").parse());
+        assert_eq!(WM32::from(wm), WM32::from(u32::from(value)));
+    }
+    assert!("0x10000".parse::<WM16>().is_err());
+}
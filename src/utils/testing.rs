@@ -0,0 +1,9 @@
+use crate::*;
+use std::ptr::null_mut;
+
+
+
+/// A message-only window of the current thread, for tests to associate data with, destroy, etc.
+pub(crate) fn test_window() -> HWnd {
+    unsafe { create_window_ex_w(0, abistr::cstr16!("Message"), (), 0, 0, 0, 0, 0, HWnd::MESSAGE, null_mut(), None, null_mut()) }.unwrap()
+}
@@ -0,0 +1,136 @@
+//! user32 exports newer than Windows 7, resolved at runtime instead of linked statically.
+//!
+//! Statically importing e.g. `AdjustWindowRectExForDpi` would prevent any executable using this crate from even loading on Windows 7.
+//! Instead, [User32::get] resolves them once via `GetProcAddress`, and each wrapper either falls back on an older API, or fails with `ERROR::CALL_NOT_IMPLEMENTED`.
+
+#![allow(non_snake_case)]
+
+use crate::*;
+use winapi::shared::basetsd::UINT_PTR;
+use winapi::shared::minwindef::*;
+use winapi::shared::ntdef::ULONG;
+use winapi::shared::windef::{DPI_AWARENESS_CONTEXT, HWND, LPRECT, RECT};
+use winapi::um::libloaderapi::GetProcAddress;
+use winapi::um::winuser::*;
+use std::ffi::{c_void, CString};
+use std::ptr::NonNull;
+
+
+
+macro_rules! exports {
+    ( $( #[doc = $doc:literal] $name:ident : fn ( $($arg:ty),* $(,)? ) -> $ret:ty; )* ) => {
+        /// Optional user32 exports: [None] if unavailable on this version of Windows
+        pub(crate) struct User32 { $( #[doc = $doc] pub $name: Option<unsafe extern "system" fn($($arg),*) -> $ret>, )* }
+
+        impl User32 {
+            /// Resolve every export by name with `resolve` (e.g. a fake, for testing fallbacks.)
+            pub fn resolve(mut resolve: impl FnMut(&'static str) -> Option<NonNull<c_void>>) -> Self {
+                Self { $(
+                    // SAFETY: `resolve` must return the named export, whose signature matches the Windows SDK's
+                    $name: resolve(stringify!($name)).map(|f| unsafe { std::mem::transmute::<*mut c_void, unsafe extern "system" fn($($arg),*) -> $ret>(f.as_ptr()) }),
+                )* }
+            }
+        }
+    };
+}
+
+exports! {
+    /// Windows 10 1607+
+    AdjustWindowRectExForDpi        : fn(LPRECT, DWORD, BOOL, DWORD, UINT) -> BOOL;
+    /// Windows 10 1607+
+    GetDpiForWindow                 : fn(HWND) -> UINT;
+    /// Windows 8+
+    SetCoalescableTimer             : fn(HWND, UINT_PTR, UINT, TIMERPROC, ULONG) -> UINT_PTR;
+    /// Windows 10 1607+
+    SetThreadDpiAwarenessContext    : fn(DPI_AWARENESS_CONTEXT) -> DPI_AWARENESS_CONTEXT;
+}
+
+impl User32 {
+    /// The process-wide table, resolved from the real user32.dll on first use.
+    pub fn get() -> &'static Self {
+        lazy_static::lazy_static! { static ref USER32 : User32 = User32::resolve(system); }
+        &USER32
+    }
+
+    /// AdjustWindowRectExForDpi, falling back on AdjustWindowRectEx (which uses the system DPI, ignoring `dpi`) before Windows 10 1607.
+    pub fn adjust_window_rect_ex_for_dpi(&self, rect: &mut RECT, style: u32, menu: bool, ex_style: u32, dpi: u32) -> Result<(), Error> {
+        let ok = match self.AdjustWindowRectExForDpi {
+            Some(f) => unsafe { f(rect, style, menu.into(), ex_style, dpi) },
+            None    => unsafe { AdjustWindowRectEx(rect, style, menu.into(), ex_style) },
+        };
+        if ok == FALSE { return Err(Error::new_gle()) }
+        Ok(())
+    }
+
+    /// GetDpiForWindow, or `ERROR::CALL_NOT_IMPLEMENTED` before Windows 10 1607.
+    pub fn get_dpi_for_window(&self, hwnd: HWND) -> Result<u32, Error> {
+        let f = self.GetDpiForWindow.ok_or(Error::new(ERROR::CALL_NOT_IMPLEMENTED.into()))?;
+        match unsafe { f(hwnd) } {
+            0   => Err(Error::new(ERROR::INVALID_WINDOW_HANDLE.into())),
+            dpi => Ok(dpi),
+        }
+    }
+
+    /// SetCoalescableTimer, falling back on SetTimer (which ignores `tolerance_delay_ms`, after validating it) before Windows 8.
+    ///
+    /// ### Safety
+    /// *   As per [set_coalescable_timer]
+    pub unsafe fn set_coalescable_timer(&self, hwnd: HWND, id_event: usize, elapse_ms: u32, timer_func: TIMERPROC, tolerance_delay_ms: u32) -> Result<usize, Error> {
+        let tid = match self.SetCoalescableTimer {
+            Some(f) => unsafe { f(hwnd, id_event, elapse_ms, timer_func, tolerance_delay_ms) },
+            None if (0x7FFF_FFF6 ..= 0xFFFF_FFFE).contains(&tolerance_delay_ms) => return Err(Error::new(ERROR::INVALID_PARAMETER.into())),
+            None    => unsafe { SetTimer(hwnd, id_event, elapse_ms, timer_func) },
+        };
+        if tid == 0 { return Err(Error::new_gle()) }
+        Ok(tid)
+    }
+
+    /// SetThreadDpiAwarenessContext, or `ERROR::CALL_NOT_IMPLEMENTED` before Windows 10 1607.
+    pub fn set_thread_dpi_awareness_context(&self, context: DpiAwarenessContext) -> Result<DpiAwarenessContext, Error> {
+        let f = self.SetThreadDpiAwarenessContext.ok_or(Error::new(ERROR::CALL_NOT_IMPLEMENTED.into()))?;
+        let prev = unsafe { f(context.into()) };
+        if prev.is_null() { return Err(Error::new(ERROR::INVALID_PARAMETER.into())) }
+        Ok(prev.into())
+    }
+}
+
+/// Resolve `name` from the real user32.dll
+fn system(name: &'static str) -> Option<NonNull<c_void>> {
+    let user32 = get_module_handle_ex_w_pin(abistr::cstr16!("user32")).ok()?;
+    let name = CString::new(name).ok()?;
+    NonNull::new(unsafe { GetProcAddress(user32.into(), name.as_ptr()) }.cast())
+}
+
+
+
+#[test] fn fake_resolver() {
+    use std::ptr::null_mut;
+
+    let mut requested = Vec::new();
+    let win7 = User32::resolve(|name| { requested.push(name); None });
+    assert_eq!(requested, ["AdjustWindowRectExForDpi", "GetDpiForWindow", "SetCoalescableTimer", "SetThreadDpiAwarenessContext"]);
+    assert_eq!(win7.get_dpi_for_window(null_mut()).unwrap_err(), ERROR::CALL_NOT_IMPLEMENTED);
+    assert_eq!(win7.set_thread_dpi_awareness_context(crate::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2).unwrap_err(), ERROR::CALL_NOT_IMPLEMENTED);
+    assert_eq!(unsafe { win7.set_coalescable_timer(null_mut(), 0, 100, None, 0x7FFF_FFF6) }.unwrap_err(), ERROR::INVALID_PARAMETER, "fallback should still validate tolerance_delay_ms");
+
+    unsafe extern "system" fn adjust(rect: LPRECT, _style: DWORD, _menu: BOOL, _ex_style: DWORD, dpi: UINT) -> BOOL { unsafe { (*rect).right += dpi as i32 }; TRUE }
+    unsafe extern "system" fn dpi_for(hwnd: HWND) -> UINT { if hwnd.is_null() { 0 } else { 144 } }
+    unsafe extern "system" fn timer(_: HWND, id_event: UINT_PTR, _: UINT, _: TIMERPROC, tolerance: ULONG) -> UINT_PTR { id_event + tolerance as usize }
+    unsafe extern "system" fn set_context(context: DPI_AWARENESS_CONTEXT) -> DPI_AWARENESS_CONTEXT { if context as isize == -4 { -1isize as _ } else { null_mut() } }
+
+    let win10 = User32::resolve(|name| NonNull::new(match name {
+        "AdjustWindowRectExForDpi"      => adjust       as *mut c_void,
+        "GetDpiForWindow"               => dpi_for      as *mut c_void,
+        "SetCoalescableTimer"           => timer        as *mut c_void,
+        "SetThreadDpiAwarenessContext"  => set_context  as *mut c_void,
+        _                               => null_mut(),
+    }));
+    let mut rect = RECT { left: 0, top: 0, right: 800, bottom: 600 };
+    win10.adjust_window_rect_ex_for_dpi(&mut rect, 0, false, 0, 144).unwrap();
+    assert_eq!(rect.right, 944);
+    assert_eq!(win10.get_dpi_for_window(1 as HWND), Ok(144));
+    assert_eq!(win10.get_dpi_for_window(null_mut()).unwrap_err(), ERROR::INVALID_WINDOW_HANDLE);
+    assert_eq!(unsafe { win10.set_coalescable_timer(null_mut(), 40, 100, None, 2) }, Ok(42));
+    assert_eq!(win10.set_thread_dpi_awareness_context(crate::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2), Ok(crate::DPI_AWARENESS_CONTEXT::UNAWARE));
+    assert_eq!(win10.set_thread_dpi_awareness_context(DpiAwarenessContext::from_constant(-42)).unwrap_err(), ERROR::INVALID_PARAMETER);
+}